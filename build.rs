@@ -76,9 +76,220 @@ fn write_info(path: Path, info: (String, Vec<String>)) {
     file.write_str("];").unwrap();
 }
 
+// ==============================================
+// Opcode table
+// ==============================================
+
+/// Just enough JSON to read `scripts/opcodes*.json`.
+enum Json {
+    Number(u32),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&mut self) -> u8 {
+        //! Skip whitespace and return the next byte
+        while self.bytes[self.index] == b' ' || self.bytes[self.index] == b'\n'
+            || self.bytes[self.index] == b'\r' || self.bytes[self.index] == b'\t' {
+            self.index += 1;
+        }
+        self.bytes[self.index]
+    }
+
+    fn expect(&mut self, byte: u8) {
+        if self.peek() != byte {
+            panic!("error: expected '{}' at {} in opcode table", byte as char, self.index);
+        }
+        self.index += 1;
+    }
+
+    fn value(&mut self) -> Json {
+        match self.peek() {
+            b'{' => {
+                let mut fields = Vec::new();
+                self.index += 1;
+                while self.peek() != b'}' {
+                    let key = match self.value() {
+                        Json::String(s) => s,
+                        _ => panic!("error: expected key at {} in opcode table", self.index),
+                    };
+                    self.expect(b':');
+                    fields.push((key, self.value()));
+                    if self.peek() == b',' { self.index += 1; }
+                }
+                self.index += 1;
+                Json::Object(fields)
+            },
+            b'[' => {
+                let mut values = Vec::new();
+                self.index += 1;
+                while self.peek() != b']' {
+                    values.push(self.value());
+                    if self.peek() == b',' { self.index += 1; }
+                }
+                self.index += 1;
+                Json::Array(values)
+            },
+            b'"' => {
+                let mut s = String::new();
+                self.index += 1;
+                while self.bytes[self.index] != b'"' {
+                    s.push(self.bytes[self.index] as char);
+                    self.index += 1;
+                }
+                self.index += 1;
+                Json::String(s)
+            },
+            b'0'...b'9' => {
+                let mut n: u32 = 0;
+                while self.index < self.bytes.len()
+                    && self.bytes[self.index] >= b'0' && self.bytes[self.index] <= b'9' {
+                    n = n * 10 + (self.bytes[self.index] - b'0') as u32;
+                    self.index += 1;
+                }
+                Json::Number(n)
+            },
+            b => panic!("error: unexpected '{}' at {} in opcode table", b as char, self.index),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> String {
+    match File::open(path).read_to_string() {
+        Ok(s)  => s,
+        Err(e) => panic!("error: cannot read {}: {}", path.display(), e.desc),
+    }
+}
+
+fn field<'a>(fields: &'a Vec<(String, Json)>, key: &str) -> &'a Json {
+    match fields.iter().find(|&&(ref k, _)| k.as_slice() == key) {
+        Some(&(_, ref v)) => v,
+        None => panic!("error: missing \"{}\" in opcode table", key),
+    }
+}
+
+fn strings(json: &Json) -> Vec<String> {
+    match *json {
+        Json::Array(ref values) => values.iter().map(|v| match *v {
+            Json::String(ref s) => s.clone(),
+            _ => panic!("error: expected string in opcode table"),
+        }).collect(),
+        _ => panic!("error: expected array in opcode table"),
+    }
+}
+
+fn opcode_entry(opcode: u16, json: &Json, description: &str) -> String {
+    //! Return the `Opcode` literal for a single JSON entry
+    let fields = match *json {
+        Json::Object(ref fields) => fields,
+        _ => panic!("error: expected object for opcode 0x{:X}", opcode),
+    };
+    let mnemonic = match *field(fields, "command") {
+        Json::String(ref s) => s.clone(),
+        _ => panic!("error: expected command for opcode 0x{:X}", opcode),
+    };
+    let args = strings(field(fields, "args"));
+    let flags = strings(field(fields, "flags"));
+    let cycles: Vec<u32> = match *field(fields, "cycles") {
+        Json::Array(ref values) => values.iter().map(|v| match *v {
+            Json::Number(n) => n,
+            _ => panic!("error: expected cycles for opcode 0x{:X}", opcode),
+        }).collect(),
+        _ => panic!("error: expected cycles for opcode 0x{:X}", opcode),
+    };
+
+    // Opcode, prefix included, then immediate operands
+    let mut length = if opcode > 0xFF { 2 } else { 1 };
+    for arg in args.iter() {
+        length += match arg.as_slice() {
+            "d8" | "r8" | "(a8)" | "SP+r8" => 1,
+            "d16" | "a16" | "(a16)"        => 2,
+            _                              => 0,
+        };
+    }
+
+    // Conditional instructions list not taken, then taken cycles
+    let (cycles, branch_cycles) = match cycles.len() {
+        1 => (cycles[0], String::from_str("None")),
+        2 => (cycles[0], format!("Some({})", cycles[1])),
+        _ => panic!("error: bad cycles for opcode 0x{:X}", opcode),
+    };
+
+    let flags: Vec<&str> = flags.iter().map(|f| match f.as_slice() {
+        "-" => "Preserved",
+        "0" => "Reset",
+        "1" => "Set",
+        _   => "Affected",
+    }).collect();
+    let args: Vec<String> = args.iter().map(|a| format!("\"{}\"", a)).collect();
+
+    format!("Opcode {{ opcode: 0x{:02X}, mnemonic: \"{}\", operands: &[{}], length: {}, \
+             cycles: {}, branch_cycles: {}, flags: [{}], description: \"{}\" }}",
+            opcode, mnemonic, args.connect(", "), length, cycles, branch_cycles,
+            flags.connect(", "), description.trim().replace("\\", "\\\\").replace("\"", "\\\""))
+}
+
+fn opcode_table(name: &str, json: &Path, doc: &Path, prefix: u16, optional: bool) -> String {
+    //! Return the `static` declaration of a 256 entries opcode table.
+    //! Descriptions come from the `.doc.txt` file next to the JSON,
+    //! laid out as 16 rows of a header line and 16 descriptions.
+    let text = read_file(json);
+    let root = Parser { bytes: text.as_bytes(), index: 0 }.value();
+    let fields = match root {
+        Json::Object(fields) => fields,
+        _ => panic!("error: expected object in {}", json.display()),
+    };
+    let docs = read_file(doc);
+    let docs: Vec<&str> = docs.lines().collect();
+
+    let kind = if optional { "Option<Opcode>" } else { "Opcode" };
+    let mut table = format!("pub static {}: [{}; 256] = [\n", name, kind);
+    for i in 0..256us {
+        let key = format!("0x{:02X}", i);
+        let description = docs[(i / 16) * 17 + i % 16 + 1];
+        let entry = match fields.iter().find(|&&(ref k, _)| k.as_slice() == key.as_slice()) {
+            Some(&(_, ref v)) => opcode_entry(prefix + i as u16, v, description),
+            None if optional  => String::from_str("None"),
+            None => panic!("error: missing opcode {} in {}", key, json.display()),
+        };
+        if optional && entry.as_slice() != "None" {
+            table.push_str(format!("    Some({}),\n", entry).as_slice());
+        } else {
+            table.push_str(format!("    {},\n", entry).as_slice());
+        }
+    }
+    table.push_str("];\n");
+    table
+}
+
+fn write_opcodes(path: Path, scripts: Path) {
+    //! Write the opcode tables to a file that will be included in the
+    //! `opcodes` module
+    let mut file = File::create(&path).unwrap();
+
+    file.write_str(opcode_table("OPCODES",
+                                &scripts.join("opcodes.json"),
+                                &scripts.join("opcodes.doc.txt"),
+                                0x0000, true).as_slice()).unwrap();
+    file.write_str(opcode_table("OPCODES_CB",
+                                &scripts.join("opcodes_cb.json"),
+                                &scripts.join("opcodes_cb.doc.txt"),
+                                0xCB00, false).as_slice()).unwrap();
+}
+
 fn main() {
     let path_config = Path::new(os::getenv("OUT_DIR").unwrap()).join("config.rs");
+    let path_opcodes = Path::new(os::getenv("OUT_DIR").unwrap()).join("opcodes.rs");
     let path_cargo = Path::new(os::getenv("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.toml");
+    let path_scripts = Path::new(os::getenv("CARGO_MANIFEST_DIR").unwrap()).join("scripts");
 
     write_info(path_config, find_info(path_cargo));
+    write_opcodes(path_opcodes, path_scripts);
 }
@@ -98,7 +98,7 @@
         //!   - C: Preserved
         //! Description:
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
         unimplemented!();
 
@@ -204,7 +204,7 @@
         //!   - C: Preserved
         //! Description:
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
         unimplemented!();
 
@@ -3865,7 +3865,7 @@
         //!   - C: Preserved
         //! Description:
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
         unimplemented!();
 
@@ -3971,7 +3971,7 @@
         //!   - C: Preserved
         //! Description:
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
         unimplemented!();
 
@@ -4396,8 +4396,8 @@
         //!   - H: Preserved
         //!   - C: Preserved
         //! Description:
-        //!   Exchanges the 16-bit contents of bc, de, and hl with bc', de', and
-        //!   hl'.
+        //!   The top stack entry is popped into pc, and interrupts are
+        //!   enabled. Unlike EI, ime is set right away.
 
         unimplemented!();
 
@@ -6303,7 +6303,8 @@
         //!   - H: Preserved
         //!   - C: Preserved
         //! Description:
-        //!   Exchanges the 16-bit contents of af and af'.
+        //!   Stores sp into the memory location pointed to by **, low byte
+        //!   first.
 
         unimplemented!();
 
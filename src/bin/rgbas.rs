@@ -45,8 +45,12 @@ fn disassemble<'a>(input: &'a String, output: Option<String>) {
             },
         };
 
-        size = match file.read(&mut cpu.mmu.memory) {
-            Ok(s)  => s,
+        size = match file.read_to_end() {
+            Ok(rom) => {
+                cpu.mmu.insert(rgb::Cartridge::from_bytes(&rom));
+                // Only the first 32K are mapped
+                if rom.len() < 0x8000 { rom.len() } else { 0x8000 }
+            },
             Err(e) => {
                 let _ = writeln!(&mut stderr(),
                     "error: Failed to load input file ({})", e.desc);
@@ -75,7 +79,11 @@ fn disassemble<'a>(input: &'a String, output: Option<String>) {
 
     // Write to output file
     while cpu.pc < size as u16 {
-        let (instruction, size) = cpu.state();
+        // Illegal opcodes are emitted as raw data bytes
+        let (instruction, size) = match cpu.state() {
+            Ok(state) => state,
+            Err(_)    => (format!("DB 0x{:0>2.2X}", cpu.mmu.peek8(cpu.pc)), 1),
+        };
         let _ = out.write_line(instruction.as_slice());
         cpu.pc += size as u16;
     }
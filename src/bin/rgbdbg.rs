@@ -49,8 +49,10 @@ fn repl_eval<'a>(command: String, arguments: String, cpu: &'a mut rgb::Cpu) {
         "q" | "quit"  => command_quit(),
         "n" | "next"  => command_next(cpu),
         "r" | "run"   => command_run(cpu),
+        "t" | "trace" => command_trace(cpu),
         "l" | "list"  => command_list(cpu),
         "d" | "dump"  => command_dump(cpu),
+        "w" | "watch" => command_watch(arguments, cpu),
         "p" | "print" => command_print(cpu),
         "f" | "file"  => command_file(arguments, cpu),
         c => command_unknown(String::from_str(c)),
@@ -101,8 +103,10 @@ fn completion_callback(input: &str) -> Vec<String> {
             "q" | "qu" | "qui" | "quit"           => vec![String::from_str("quit" )],
             "n" | "ne" | "nex" | "next"           => vec![String::from_str("next" )],
             "r" | "ru" | "run"                    => vec![String::from_str("run"  )],
+            "t" | "tr" | "tra" | "trac" | "trace" => vec![String::from_str("trace")],
             "l" | "li" | "lis" | "list"           => vec![String::from_str("list" )],
             "d" | "du" | "dum" | "dump"           => vec![String::from_str("dump" )],
+            "w" | "wa" | "wat" | "watc" | "watch" => vec![String::from_str("watch ")],
             "p" | "pr" | "pri" | "prin" | "print" => vec![String::from_str("print")],
             "f" | "fi" | "fil" | "file"           => vec![String::from_str("file ")],
             // Unknown command
@@ -133,8 +137,10 @@ fn command_help() {
     println!("  file |f    Load Z80 executable binary");
     println!("  next |n    Step execute the next instruction");
     println!("  run  |r    Run the program until Cpu is stopped");
+    println!("  trace|t    Run the program, printing Gameboy Doctor logs");
     println!("  list |l    List upcoming instructions");
     println!("  dump |d    Dump memory at location");
+    println!("  watch|w    Print writes to an address, such as \"watch 0xC000\"");
     println!("  print|p    Print the current CPU state");
     println!("  quit |q    Quit");
 }
@@ -155,8 +161,11 @@ fn command_file<'a>(arguments: String, cpu: &'a mut rgb::Cpu) {
             return;
         },
     };
-    let size = match file.read(&mut cpu.mmu.memory) {
-        Ok(s) => s,
+    let size = match file.read_to_end() {
+        Ok(rom) => {
+            cpu.mmu.insert(rgb::Cartridge::from_bytes(&rom));
+            rom.len()
+        },
         Err(e) => {
             println!("error: failed to load ({})", e.desc);
             cpu.reset();
@@ -174,9 +183,13 @@ fn command_print<'a>(cpu: &'a rgb::Cpu) {
 
 fn command_next<'a>(cpu: &'a mut rgb::Cpu) {
     //! Execute the next instruction, and print it
-    let (instruction, _) = cpu.state();
-    println!("{}", instruction);
-    cpu.step();
+    match cpu.state() {
+        Ok((instruction, _)) => println!("{}", instruction),
+        Err(e)               => { println!("error: {}", e); return; },
+    }
+    if let Err(e) = cpu.step() {
+        println!("error: {}", e);
+    }
 }
 
 fn command_run<'a>(cpu: &'a mut rgb::Cpu) {
@@ -185,10 +198,29 @@ fn command_run<'a>(cpu: &'a mut rgb::Cpu) {
     cpu.stop = false;
     while !cpu.stopped() {
         // Retrieve current instruction and print it
-        let (instruction, _) = cpu.state();
-        println!("{}", instruction);
+        match cpu.state() {
+            Ok((instruction, _)) => println!("{}", instruction),
+            Err(e)               => { println!("error: {}", e); return; },
+        }
         // Execute current instruction
-        cpu.step();
+        if let Err(e) = cpu.step() {
+            println!("error: {}", e);
+            return;
+        }
+    }
+}
+
+fn command_trace<'a>(cpu: &'a mut rgb::Cpu) {
+    //! Run the program until the process is stopped, printing
+    //! the processor state before every instruction
+    cpu.reset();
+    cpu.stop = false;
+    while !cpu.stopped() {
+        println!("{}", cpu.trace_line());
+        if let Err(e) = cpu.step() {
+            println!("error: {}", e);
+            return;
+        }
     }
 }
 
@@ -197,13 +229,16 @@ fn command_list<'a>(cpu: &'a mut rgb::Cpu) {
     let backup_pc = cpu.pc;
     let mut count = 10;
     while count>0 {
-        let (instruction, size) = cpu.state();
+        let (instruction, size) = match cpu.state() {
+            Ok(state) => state,
+            Err(_)    => (format!("DB 0x{:0>2.2X}", cpu.mmu.peek8(cpu.pc)), 1),
+        };
         if cpu.pc==backup_pc
             { print!("-> "); }
         else
             { print!("   "); }
         println!("{}", instruction);
-        cpu.pc += size as u16;
+        cpu.pc = cpu.pc.wrapping_add(size as u16);
         count -= 1;
     }
     cpu.pc = backup_pc;
@@ -214,19 +249,47 @@ fn command_dump<'a>(cpu: &'a mut rgb::Cpu) {
     let mut address = 0;
     while address<=20 {
         print!("@x{:0>4.4X} ", address);
-        let b0: u8 = cpu.mmu.read8(address);
+        let b0: u8 = cpu.mmu.peek8(address);
         address += 1;
-        let b1: u8 = cpu.mmu.read8(address);
+        let b1: u8 = cpu.mmu.peek8(address);
         address += 1;
-        let b2: u8 = cpu.mmu.read8(address);
+        let b2: u8 = cpu.mmu.peek8(address);
         address += 1;
-        let b3: u8 = cpu.mmu.read8(address);
+        let b3: u8 = cpu.mmu.peek8(address);
         address += 1;
         println!("| x{:0>2.2X} x{:0>2.2X} x{:0>2.2X} x{:0>2.2X} |",
                  b0, b1, b2, b3);
     }
 }
 
+fn parse_address(input: &str) -> Option<u16> {
+    //! Parse an hexadecimal address, with an optional 0x prefix
+    let digits = if input.starts_with("0x") { &input[2..] } else { input };
+    if digits.is_empty() || digits.len() > 4 {
+        return None;
+    }
+    let mut address: u16 = 0;
+    for c in digits.chars() {
+        match c.to_digit(16) {
+            Some(d) => address = (address << 4) + d as u16,
+            None    => return None,
+        }
+    }
+    Some(address)
+}
+
+fn command_watch<'a>(arguments: String, cpu: &'a mut rgb::Cpu) {
+    //! Print every write of the program to an address
+    let address = match parse_address(arguments.as_slice().trim()) {
+        Some(a) => a,
+        None    => { println!("error: invalid address \"{}\"", arguments); return; },
+    };
+    cpu.mmu.add_hook(rgb::Access::Write, address, address, Box::new(|address, data, pc| {
+        println!("watch: 0x{:0>2.2X} written to 0x{:0>4.4X} at 0x{:0>4.4X}", data, address, pc);
+    }));
+    println!("Watching writes to 0x{:0>4.4X}.", address);
+}
+
 fn main() {
     //! Entry point of the debugger, print the disclaimer then
     //! start the REPL
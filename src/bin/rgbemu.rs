@@ -26,9 +26,21 @@ fn usage(opts: Options) {
 
 fn run<'a>(filename: &'a String) {
     println!("Running {}", filename);
-    let c = rgb::Cartridge::from_file(&Path::new(filename));
+    let c = match rgb::Cartridge::from_file(&Path::new(filename)) {
+        Ok(c)  => c,
+        Err(e) => {
+            let _ = writeln!(&mut stderr(), "error: {}", e);
+            os::set_exit_status(1);
+            return;
+        },
+    };
 
-    println!("Title: {}", c.title());
+    let header = c.header();
+    println!("Title: {}", header.title);
+    println!("Type:  0x{:0>2.2X}", header.cartridge_type);
+    for warning in header.warnings.iter() {
+        println!("warning: {}", warning);
+    }
 }
 
 fn main() {
@@ -0,0 +1,65 @@
+#![allow(dead_code)]
+
+#[cfg(test)]
+pub mod tests;
+
+/// This trait models everything the processor is wired to: memory,
+/// and memory mapped hardware. `Mmu` is the default implementation,
+/// but any memory map can be plugged into a `Cpu` through
+/// `Cpu::with_bus`.
+pub trait Bus {
+    /// Read a single byte
+    fn read8(&self, address: u16) -> u8;
+
+    /// Write a single byte
+    fn write8(&mut self, address: u16, data: u8);
+
+    fn peek8(&self, address: u16) -> u8 {
+        //! Read a single byte on behalf of the processor itself or of
+        //! a debugger, rather than of the program, such as to decode
+        //! instructions or poll interrupts.
+        self.read8(address)
+    }
+
+    fn fetch8(&mut self, address: u16) -> u8 {
+        //! Read the opcode of the instruction starting at `address`
+        self.read8(address)
+    }
+
+    fn read16(&self, address: u16) -> u16 {
+        //! Read a single little-endian word
+        ((self.read8(address.wrapping_add(1)) as u16) << 8) + self.read8(address) as u16
+    }
+
+    fn write16(&mut self, address: u16, data: u16) {
+        //! Write a single little-endian word
+        self.write8(address.wrapping_add(1), (data >> 8) as u8);
+        self.write8(address, data as u8);
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        //! Advance hardware attached to the bus by `cycles` clock
+        //! cycles. Called by the processor with the cycles of the
+        //! whole instruction after every step, or, in cycle accurate
+        //! mode, with 4 cycles after every M-cycle.
+        let _ = cycles;
+    }
+}
+
+/// This trait models a peripheral, such as the timer or the joypad,
+/// wired to some of the IO registers in 0xFF00-0xFF7F. Devices are
+/// plugged into a `Mmu` through `Mmu::attach`.
+pub trait IoDevice {
+    /// Read the register at `address`
+    fn read(&self, address: u16) -> u8;
+
+    /// Write the register at `address`, with any side effect
+    fn write(&mut self, address: u16, data: u8);
+
+    fn tick(&mut self, cycles: u8) -> u8 {
+        //! Advance the device by `cycles` clock cycles. Return the
+        //! interrupts it requests, as bits of IF.
+        let _ = cycles;
+        0
+    }
+}
@@ -0,0 +1,45 @@
+use super::super::*;
+use super::FlatBus;
+use cpu::Cpu;
+
+#[test]
+fn read16() {
+    let mut b = FlatBus::new();
+    b.memory[0x10] = 0x34;
+    b.memory[0x11] = 0x12;
+    assert_eq!(b.read16(0x10), 0x1234);
+    // Word reads wrap around the address space
+    b.memory[0xFFFF] = 0x78;
+    b.memory[0x0000] = 0x56;
+    assert_eq!(b.read16(0xFFFF), 0x5678);
+}
+
+#[test]
+fn write16() {
+    let mut b = FlatBus::new();
+    b.write16(0x10, 0x1234);
+    assert_eq!(b.memory[0x10], 0x34);
+    assert_eq!(b.memory[0x11], 0x12);
+    assert_eq!(b.writes, 2);
+    b.write16(0xFFFF, 0x5678);
+    assert_eq!(b.memory[0xFFFF], 0x78);
+    assert_eq!(b.memory[0x0000], 0x56);
+}
+
+#[test]
+fn cpu_with_bus() {
+    let mut b = FlatBus::new();
+    b.memory[0] = 0x31;             // 00 LD SP, 0xFFFE
+    b.memory[1] = 0xFE;             // 01 ^0xFE
+    b.memory[2] = 0xFF;             // 02 ^0xFF
+    b.memory[3] = 0xC5;             // 03 PUSH BC
+    b.memory[4] = 0x10;             // 04 STOP
+    let mut c = Cpu::with_bus(b);
+    c.reset();
+    c.run().unwrap();
+    assert_eq!(c.pc, 5);
+    assert_eq!(c.mmu.writes, 2);
+    // The bus is ticked with every elapsed cycle
+    assert_eq!(c.mmu.cycles, 12 + 16 + 4);
+    assert_eq!(c.mmu.cycles, c.cycles());
+}
@@ -0,0 +1,76 @@
+use super::*;
+use std::cell::RefCell;
+
+mod bus;
+
+/// Bus access, as logged by `FlatBus`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Access {
+    Read(u16, u8),
+    Write(u16, u8),
+    Tick(u8),
+}
+
+/// Flat 64K memory shared by the bus and processor tests, so that code,
+/// data and stack can be put anywhere regardless of the `Mmu` memory
+/// map. It counts elapsed cycles and writes, and, when created with
+/// `logging`, records every access.
+pub struct FlatBus {
+    pub memory: Vec<u8>,
+    pub cycles: u64,
+    pub writes: u32,
+    log:        Option<RefCell<Vec<Access>>>,
+}
+
+impl FlatBus {
+    pub fn new() -> FlatBus {
+        FlatBus {
+            memory: vec![0; 0x10000],
+            cycles: 0,
+            writes: 0,
+            log:    None,
+        }
+    }
+
+    pub fn logging() -> FlatBus {
+        //! Create a `FlatBus` recording every access.
+        FlatBus { log: Some(RefCell::new(Vec::new())), ..FlatBus::new() }
+    }
+
+    pub fn take(&self) -> Vec<Access> {
+        //! Return the accesses recorded so far, and clear the log.
+        match self.log {
+            Some(ref log) => {
+                let accesses = log.borrow().clone();
+                log.borrow_mut().clear();
+                accesses
+            },
+            None => Vec::new(),
+        }
+    }
+
+    fn record(&self, access: Access) {
+        if let Some(ref log) = self.log {
+            log.borrow_mut().push(access);
+        }
+    }
+}
+
+impl Bus for FlatBus {
+    fn read8(&self, address: u16) -> u8 {
+        let data = self.memory[address as usize];
+        self.record(Access::Read(address, data));
+        data
+    }
+
+    fn write8(&mut self, address: u16, data: u8) {
+        self.record(Access::Write(address, data));
+        self.memory[address as usize] = data;
+        self.writes += 1;
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        self.record(Access::Tick(cycles));
+        self.cycles += cycles as u64;
+    }
+}
@@ -4,66 +4,206 @@
 #![allow(unreachable_code)]
 #![allow(non_snake_case)]
 
+use std::error::Error;
+use std::fmt;
 use std::old_io::File;
-use std::str;
+use super::header::CartridgeHeader;
+use super::mapper;
+use super::mapper::{Mapper, RomOnly};
 
 #[cfg(test)]
 mod tests;
 
-/// This struct represents a cartridge.
-pub struct Cartridge<'a> {
-    rom0: [u8; 0x4000],
-    romN: [u8; 0x4000],
-    ram:  [u8; 0x2000],
+/// Size of a ROM bank
+pub const ROM_BANK: usize = 0x4000;
+/// Largest number of ROM banks, for 8M of ROM
+pub const MAX_ROM_BANKS: usize = 512;
+
+/// Errors raised while loading a ROM image.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CartridgeError {
+    /// Image could not be read
+    Io(String),
+    /// ROM size code of the header is unknown
+    UnknownRomSize(u8),
+    /// Image is smaller than the ROM size given by its header
+    Truncated { size: usize, expected: usize },
+    /// Image is larger than the ROM size given by its header
+    Oversized { size: usize, expected: usize },
+    /// Cartridge type of the header has no supported mapper
+    UnsupportedType(u8),
+}
+
+/// This struct represents a cartridge: its ROM, RAM and the `Mapper`
+/// banking them.
+pub struct Cartridge {
+    mapper: Box<Mapper>,
 }
 
 // ==============================================
 // Implementation
 // ==============================================
 
-impl<'a> Cartridge<'a> {
-    pub fn new() -> Cartridge<'a> {
+impl Cartridge {
+    pub fn new() -> Cartridge {
+        //! Create a blank cartridge without mapper, with 32K of ROM and
+        //! 8K of RAM
+        Cartridge::with_mapper(Box::new(RomOnly::new(vec![0; 2 * ROM_BANK], vec![0; 0x2000])))
+    }
+
+    pub fn with_mapper(mapper: Box<Mapper>) -> Cartridge {
+        //! Create a cartridge from its mapper, owning ROM and RAM
         Cartridge {
-            rom0: [0; 0x4000],
-            romN: [0; 0x4000],
-            ram:  [0; 0x2000],
+            mapper: mapper,
         }
     }
 
-    pub fn from_file<'b>(path: &'b Path) -> Cartridge<'a> {
+    pub fn from_file(path: &Path) -> Result<Cartridge, CartridgeError> {
+        //! Load a ROM image from a file, see `load`.
         let mut file = match File::open(path) {
-            Ok(f) => f,
-            Err(e) => panic!("error: {}", e.desc),
+            Ok(f)  => f,
+            Err(e) => return Err(CartridgeError::Io(e.to_string())),
         };
+        match file.read_to_end() {
+            Ok(rom) => Cartridge::load(&rom),
+            Err(e)  => Err(CartridgeError::Io(e.to_string())),
+        }
+    }
 
-        let stat = match file.stat() {
-            Ok(s) => s,
-            Err(e) => panic!("error: {}", e.desc),
+    pub fn load(rom: &[u8]) -> Result<Cartridge, CartridgeError> {
+        //! Create a cartridge from a complete ROM image, whose size must
+        //! match the ROM size given by its header. RAM is sized, and the
+        //! mapper picked, from the header.
+        let header = CartridgeHeader::parse(rom);
+        let expected: usize = match header.rom_banks() {
+            Some(banks) => banks * ROM_BANK,
+            None        => return Err(CartridgeError::UnknownRomSize(header.rom_size)),
         };
+        if rom.len() < expected {
+            return Err(CartridgeError::Truncated { size: rom.len(), expected: expected });
+        }
+        if rom.len() > expected {
+            return Err(CartridgeError::Oversized { size: rom.len(), expected: expected });
+        }
+        if !mapper::supported(header.cartridge_type) {
+            return Err(CartridgeError::UnsupportedType(header.cartridge_type));
+        }
+        let ram: Vec<u8> = vec![0; header.ram_bytes().unwrap_or(0)];
+        Ok(Cartridge::with_mapper(mapper::for_type(header.cartridge_type, rom.to_vec(), ram)))
+    }
 
-        let mut cart = Cartridge::new();
+    pub fn from_bytes(rom: &[u8]) -> Cartridge {
+        //! Create a cartridge from a ROM image, or a bare program,
+        //! without checking its size. It is padded with 0 to whole
+        //! banks, 2 at least, and cut past 8M. RAM is sized, and the
+        //! mapper picked, from the header, unsupported cartridge types
+        //! getting `RomOnly`.
+        let banks: usize = (rom.len() + ROM_BANK - 1) / ROM_BANK;
+        let banks: usize = if banks < 2 { 2 } else if banks > MAX_ROM_BANKS { MAX_ROM_BANKS } else { banks };
+        let mut image: Vec<u8> = vec![0; banks * ROM_BANK];
+        for (i, byte) in rom.iter().take(image.len()).enumerate() {
+            image[i] = *byte;
+        }
+        let header = CartridgeHeader::parse(rom);
+        let ram: Vec<u8> = vec![0; header.ram_bytes().unwrap_or(0)];
+        Cartridge::with_mapper(mapper::for_type(header.cartridge_type, image, ram))
+    }
 
-        // Loading bank 0
-        match file.read(&mut cart.rom0) {
-            Ok(s) => s,
-            Err(e) => panic!("error: {}", e.desc),
-        };
+    pub fn rom_banks(&self) -> usize {
+        //! Number of 16K ROM banks
+        self.mapper.rom().len() / ROM_BANK
+    }
 
-        // Loading bank 1-N
-        if stat.size >= 0x4000 {
-            match file.read(&mut cart.romN) {
-                Ok(s)  => s,
-                Err(e) => panic!("error: {}", e.desc),
-            };
-        }
+    pub fn ram_size(&self) -> usize {
+        //! Size of external RAM, in bytes
+        self.mapper.ram().len()
+    }
+
+    pub fn read_rom(&self, address: u16) -> u8 {
+        //! Read a byte of ROM, mapped at 0x0000-0x7FFF
+        self.mapper.read_rom(address)
+    }
+
+    pub fn write_rom(&mut self, address: u16, data: u8) {
+        //! Handle a write to 0x0000-0x7FFF. ROM is read-only, such
+        //! writes set the registers of the mapper.
+        self.mapper.write_rom(address, data)
+    }
+
+    pub fn poke_rom(&mut self, address: u16, data: u8) {
+        //! Patch the byte of ROM mapped at 0x0000-0x7FFF
+        self.mapper.poke_rom(address, data)
+    }
 
-        return cart;
+    pub fn read_ram(&self, address: u16) -> u8 {
+        //! Read a byte of external RAM, mapped at 0xA000-0xBFFF
+        self.mapper.read_ram(address)
     }
 
-    pub fn title(&self) -> & str {
-        match str::from_utf8(&self.rom0[0x134..0x143]) {
-            Ok(s)  => s,
-            Err(e) => panic!("error: failed to retrieve cartridge title {:?}", e),
+    pub fn write_ram(&mut self, address: u16, data: u8) {
+        //! Write a byte of external RAM, mapped at 0xA000-0xBFFF
+        self.mapper.write_ram(address, data)
+    }
+
+    pub fn ram(&self) -> &[u8] {
+        //! Whole external RAM, to write save files
+        self.mapper.ram()
+    }
+
+    pub fn load_ram(&mut self, save: &[u8]) {
+        //! Restore external RAM from a save file. Extra bytes are
+        //! ignored, missing ones left untouched.
+        self.mapper.load_ram(save);
+    }
+
+    pub fn mapper(&self) -> &Mapper {
+        &*self.mapper
+    }
+
+    pub fn mapper_mut(&mut self) -> &mut Mapper {
+        &mut *self.mapper
+    }
+
+    pub fn header(&self) -> CartridgeHeader {
+        //! Parse the cartridge header
+        CartridgeHeader::parse(self.mapper.rom())
+    }
+
+    pub fn title(&self) -> String {
+        //! Title from the cartridge header, without its padding
+        self.header().title
+    }
+}
+
+// ==============================================
+// Traits
+// ==============================================
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CartridgeError::Io(ref e) =>
+                write!(f, "{}", e),
+            CartridgeError::UnknownRomSize(code) =>
+                write!(f, "unknown ROM size code 0x{:0>2.2X}", code),
+            CartridgeError::Truncated { size, expected } =>
+                write!(f, "image is truncated, {} bytes instead of {}", size, expected),
+            CartridgeError::Oversized { size, expected } =>
+                write!(f, "image is larger than its header says, {} bytes instead of {}", size, expected),
+            CartridgeError::UnsupportedType(code) =>
+                write!(f, "unsupported cartridge type 0x{:0>2.2X}", code),
+        }
+    }
+}
+
+impl Error for CartridgeError {
+    fn description(&self) -> &str {
+        match *self {
+            CartridgeError::Io(..)              => "failed to read image",
+            CartridgeError::UnknownRomSize(..)  => "unknown ROM size",
+            CartridgeError::Truncated { .. }    => "truncated image",
+            CartridgeError::Oversized { .. }    => "oversized image",
+            CartridgeError::UnsupportedType(..) => "unsupported cartridge type",
         }
     }
 }
@@ -1,6 +1,192 @@
-//use super::super::*;
+use super::super::*;
+use mapper::Mapper;
 
 #[test]
 fn new() {
-    //let c = Cartridge::new();
+    let c = Cartridge::new();
+    assert_eq!(c.read_rom(0x0000), 0);
+    assert_eq!(c.read_rom(0x7FFF), 0);
+    assert_eq!(c.read_ram(0xA000), 0);
+}
+
+#[test]
+fn from_bytes() {
+    let mut rom = vec![0; 0x8000];
+    rom[0x0100] = 0x00;
+    rom[0x0101] = 0xC3;
+    rom[0x4000] = 0x42;
+    rom[0x7FFF] = 0x24;
+    let c = Cartridge::from_bytes(&rom);
+    assert_eq!(c.read_rom(0x0101), 0xC3);
+    assert_eq!(c.read_rom(0x4000), 0x42);
+    assert_eq!(c.read_rom(0x7FFF), 0x24);
+
+    // Short images are padded with 0
+    let c = Cartridge::from_bytes(&[0x31, 0xFE, 0xFF]);
+    assert_eq!(c.read_rom(0x0001), 0xFE);
+    assert_eq!(c.read_rom(0x0003), 0x00);
+}
+
+#[test]
+fn rom_read_only() {
+    let mut c = Cartridge::new();
+    c.write_rom(0x0100, 0x42);
+    assert_eq!(c.read_rom(0x0100), 0x00);
+    c.poke_rom(0x0100, 0x42);
+    assert_eq!(c.read_rom(0x0100), 0x42);
+}
+
+#[test]
+fn ram() {
+    let mut c = Cartridge::new();
+    c.write_ram(0xA000, 0x12);
+    c.write_ram(0xBFFF, 0x34);
+    assert_eq!(c.read_ram(0xA000), 0x12);
+    assert_eq!(c.read_ram(0xBFFF), 0x34);
+}
+
+#[test]
+fn title() {
+    // Title is read from the header, without padding
+    let c = Cartridge::from_bytes(include_bytes!("../../../tetris.gb"));
+    assert_eq!(c.title(), "TETRIS");
+    assert_eq!(Cartridge::new().title(), "");
+}
+
+#[test]
+fn load() {
+    let tetris = include_bytes!("../../../tetris.gb");
+    let c = Cartridge::load(tetris).unwrap();
+    assert_eq!(c.rom_banks(), 2);
+    assert_eq!(c.ram_size(), 0);
+    assert_eq!(c.read_rom(0x7FFF), tetris[0x7FFF]);
+
+    // Image size must match the header
+    assert_eq!(Cartridge::load(&tetris[..0x4000]).err(),
+               Some(CartridgeError::Truncated { size: 0x4000, expected: 0x8000 }));
+    assert_eq!(Cartridge::load(&[0x00, 0xC3]).err(),
+               Some(CartridgeError::Truncated { size: 2, expected: 0x8000 }));
+    let mut rom = tetris.to_vec();
+    rom.push(0x00);
+    assert_eq!(Cartridge::load(&rom).err(),
+               Some(CartridgeError::Oversized { size: 0x8001, expected: 0x8000 }));
+    rom.pop();
+    rom[0x0148] = 0x09;
+    assert_eq!(Cartridge::load(&rom).err(), Some(CartridgeError::UnknownRomSize(0x09)));
+
+    // 8M of ROM, and 32K of RAM
+    let mut rom = vec![0; 0x800000];
+    rom[0x0148] = 0x08;
+    rom[0x0149] = 0x03;
+    let c = Cartridge::load(&rom).unwrap();
+    assert_eq!(c.rom_banks(), 512);
+    assert_eq!(c.ram_size(), 0x8000);
+}
+
+#[test]
+fn from_bytes_banks() {
+    // Images are padded to whole banks
+    let mut rom = vec![0; 0x10001];
+    rom[0x4000] = 0x11;
+    let c = Cartridge::from_bytes(&rom);
+    assert_eq!(c.rom_banks(), 5);
+    assert_eq!(c.read_rom(0x4000), 0x11);
+    assert_eq!(c.ram_size(), 0);
+}
+
+#[test]
+fn ram_size() {
+    // No RAM reads 0xFF, smaller RAM is mirrored
+    let mut rom = vec![0; 0x8000];
+    let mut c = Cartridge::from_bytes(&rom);
+    c.write_ram(0xA000, 0x12);
+    assert_eq!(c.read_ram(0xA000), 0xFF);
+
+    rom[0x0149] = 0x01;
+    let mut c = Cartridge::from_bytes(&rom);
+    assert_eq!(c.ram_size(), 0x0800);
+    c.write_ram(0xA000, 0x12);
+    assert_eq!(c.read_ram(0xA800), 0x12);
+    assert_eq!(c.read_ram(0xB800), 0x12);
+}
+
+/// Mapper returning the same byte everywhere.
+struct Constant {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+}
+
+impl Mapper for Constant {
+    fn read_rom(&self, _: u16) -> u8 { 0x42 }
+    fn write_rom(&mut self, _: u16, _: u8) {}
+    fn poke_rom(&mut self, _: u16, _: u8) {}
+    fn read_ram(&self, _: u16) -> u8 { 0x42 }
+    fn write_ram(&mut self, _: u16, _: u8) {}
+    fn rom(&self) -> &[u8] { &self.rom[..] }
+    fn ram(&self) -> &[u8] { &self.ram[..] }
+    fn ram_mut(&mut self) -> &mut [u8] { &mut self.ram[..] }
+}
+
+#[test]
+fn with_mapper() {
+    // Accesses go through the mapper
+    let mut c = Cartridge::with_mapper(Box::new(Constant { rom: vec![0; 0x10000], ram: Vec::new() }));
+    assert_eq!(c.read_rom(0x0000), 0x42);
+    c.write_ram(0xA000, 0x00);
+    assert_eq!(c.read_ram(0xA000), 0x42);
+    assert_eq!(c.rom_banks(), 4);
+    assert_eq!(c.mapper().rom().len(), 0x10000);
+}
+
+#[test]
+fn unsupported_type() {
+    let mut rom = include_bytes!("../../../tetris.gb").to_vec();
+    rom[0x0147] = 0xFC;
+    assert_eq!(Cartridge::load(&rom).err(), Some(CartridgeError::UnsupportedType(0xFC)));
+
+    // Unless not checked
+    let c = Cartridge::from_bytes(&rom);
+    assert_eq!(c.read_rom(0x0147), 0xFC);
+}
+
+#[test]
+fn mbc1() {
+    // MBC1 cartridges switch banks at 0x4000-0x7FFF
+    let mut rom = vec![0; 0x10000];
+    rom[0x0147] = 0x03;
+    rom[0x0148] = 0x01;
+    rom[0x0149] = 0x02;
+    rom[0xC000] = 0x42;
+    let mut c = Cartridge::load(&rom).unwrap();
+    c.write_rom(0x2000, 0x03);
+    assert_eq!(c.read_rom(0x4000), 0x42);
+    c.write_rom(0x0000, 0x0A);
+    c.write_ram(0xA000, 0x24);
+    assert_eq!(c.read_ram(0xA000), 0x24);
+}
+
+#[test]
+fn mbc2_save() {
+    // Built in RAM round-trips through save files
+    let mut rom = vec![0; 0x40000];
+    rom[0x0147] = 0x06;
+    rom[0x0148] = 0x03;
+    let mut c = Cartridge::load(&rom).unwrap();
+    assert_eq!(c.ram_size(), 0x0200);
+    c.write_rom(0x0000, 0x0A);
+    c.write_ram(0xA001, 0xAB);
+    let save: Vec<u8> = c.ram().to_vec();
+    assert_eq!(save[0x0001], 0x0B);
+
+    let mut c = Cartridge::load(&rom).unwrap();
+    c.load_ram(&save);
+    c.write_rom(0x0000, 0x0A);
+    assert_eq!(c.read_ram(0xA001), 0xFB);
+    assert_eq!(c.read_ram(0xA201), 0xFB);
+    assert_eq!(c.ram(), &save[..]);
+
+    // Upper nibbles of foreign save files are dropped
+    c.load_ram(&[0xFF]);
+    assert_eq!(c.ram()[0x0000], 0x0F);
+    assert_eq!(c.read_ram(0xA000), 0xFF);
 }
@@ -7,14 +7,21 @@
 use std::u8;
 use std::u16;
 use std::fmt;
-use std::collections::BTreeMap;
+use std::error::Error;
+use std::io::Write;
+use super::bus::Bus;
 use super::mmu::Mmu;
+use super::mmu::{IE, IF};
+use super::instruction;
+use super::instruction::Instruction;
+use super::registers::Registers;
 
 #[cfg(test)]
 mod tests;
 
 /// All different values of flags that could
 /// end up in `Cpu` register `Cpu::f`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Flag {
     /// Previous operation resulted in zero
     Zero      = 0x80,
@@ -28,8 +35,34 @@ pub enum Flag {
     None      = 0x00,
 }
 
-/// This struct models a GameBoy Z80 processor.
-pub struct Cpu {
+/// All different interrupt sources, as laid out in the
+/// IE (0xFFFF) and IF (0xFF0F) registers. Lower bits have
+/// higher priority.
+pub enum Interrupt {
+    /// LCD entered the vertical blanking period
+    VBlank  = 0x01,
+    /// LCD STAT condition met
+    LcdStat = 0x02,
+    /// Timer counter overflowed
+    Timer   = 0x04,
+    /// Serial transfer completed
+    Serial  = 0x08,
+    /// Joypad button pressed
+    Joypad  = 0x10,
+}
+
+/// All different errors that could be raised while executing
+/// instructions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CpuError {
+    /// Opcode is not part of the instruction set. `pc` is the address
+    /// of the opcode, which is left unexecuted.
+    IllegalOpcode { pc: u16, opcode: u8 },
+}
+
+/// This struct models a GameBoy Z80 processor, wired to memory
+/// through a `Bus`.
+pub struct Cpu<B: Bus = Mmu> {
     /// Accumulator register
     a:  u8,
     /// General purpose register
@@ -50,14 +83,38 @@ pub struct Cpu {
     pub pc: u16,
     /// Stack pointer register
     sp: u16,
-    /// Clock
-    m: u8,
-    /// Clock
-    t: u8,
-    /// Memory management unit
-    pub mmu: Mmu,
+    /// Machine cycles elapsed since reset
+    m: u64,
+    /// Clock cycles elapsed since reset
+    t: u64,
+    /// Memory management unit, or any other bus
+    pub mmu: B,
     /// Boolean indicating whether processor is stopped
     pub stop: bool,
+    /// Boolean indicating whether processor is halted
+    halt: bool,
+    /// Boolean indicating whether `HALT` was executed with pending
+    /// interrupts and `ime` unset, in which case the next opcode is
+    /// read twice
+    halt_bug: bool,
+    /// Interrupt master enable
+    ime: bool,
+    /// Boolean indicating whether `EI` was just executed, in which case
+    /// `ime` is set after the next instruction
+    ime_pending: bool,
+    /// Boolean indicating whether illegal opcodes lock the processor
+    /// up, as on real hardware, instead of raising an error
+    pub lock_on_illegal: bool,
+    /// Boolean indicating whether processor is locked up
+    locked: bool,
+    /// Boolean indicating whether the bus is ticked on every memory
+    /// access and internal delay, one M-cycle at a time, instead of
+    /// once per instruction
+    pub cycle_accurate: bool,
+    /// Clock cycles of the current step already ticked on the bus
+    ticked: u8,
+    /// Sink receiving a trace line before every executed instruction
+    trace: Option<Box<Write>>,
 }
 
 // ==============================================
@@ -66,7 +123,16 @@ pub struct Cpu {
 
 impl Cpu {
     pub fn new() -> Cpu {
-        //! Create a new `Cpu`. All registers and clocks should be set to 0.
+        //! Create a new `Cpu`, wired to a new `Mmu`. All registers
+        //! and clocks should be set to 0.
+        Cpu::with_bus(Mmu::new())
+    }
+}
+
+impl<B: Bus> Cpu<B> {
+    pub fn with_bus(bus: B) -> Cpu<B> {
+        //! Create a new `Cpu`, wired to `bus`. All registers and
+        //! clocks should be set to 0.
         Cpu {
             a:    0,
             b:    0,
@@ -80,8 +146,17 @@ impl Cpu {
             sp:   0,
             m:    0,
             t:    0,
-            mmu:  Mmu::new(),
+            mmu:  bus,
             stop: true,
+            halt: false,
+            halt_bug: false,
+            ime:  false,
+            ime_pending: false,
+            lock_on_illegal: false,
+            locked: false,
+            cycle_accurate: false,
+            ticked: 0,
+            trace: None,
         }
     }
 
@@ -100,39 +175,271 @@ impl Cpu {
         self.m =    0;
         self.t =    0;
         self.stop = true;
+        self.halt = false;
+        self.halt_bug = false;
+        self.ime =  false;
+        self.ime_pending = false;
+        self.locked = false;
     }
 
     pub fn stopped(&self) -> bool {
         self.stop
     }
 
-    pub fn step(&mut self) {
-        let mut opcode: u16 = self.mmu.read8(self.pc) as u16;
+    pub fn locked(&self) -> bool {
+        //! Return whether an illegal opcode locked the processor up.
+        //! Only a reset recovers from this state.
+        self.locked
+    }
+
+    pub fn registers(&self) -> Registers {
+        //! Return a snapshot of all registers.
+        let mut r = Registers::new();
+        r.set_a(self.a);
+        r.set_b(self.b);
+        r.set_c(self.c);
+        r.set_d(self.d);
+        r.set_e(self.e);
+        r.set_h(self.h);
+        r.set_l(self.l);
+        r.set_f(self.f);
+        r.set_sp(self.sp);
+        r.set_pc(self.pc);
+        r
+    }
+
+    pub fn set_registers(&mut self, r: &Registers) {
+        //! Overwrite all registers with `r`.
+        self.a  = r.a();
+        self.b  = r.b();
+        self.c  = r.c();
+        self.d  = r.d();
+        self.e  = r.e();
+        self.h  = r.h();
+        self.l  = r.l();
+        self.f  = r.f();
+        self.sp = r.sp();
+        self.pc = r.pc();
+    }
+
+    pub fn cycles(&self) -> u64 {
+        //! Return the number of clock cycles elapsed since reset.
+        self.t
+    }
+
+    pub fn step(&mut self) -> Result<u8, CpuError> {
+        //! Execute a single instruction, or dispatch a pending interrupt,
+        //! and return the number of clock cycles it took. On error, the
+        //! processor is left untouched.
+        let t: u64 = self.t;
+        self.ticked = 0;
+        try!(self.execute());
+        let cycles: u8 = (self.t - t) as u8;
+
+        // Tick the bus with cycles not ticked by accesses already
+        if cycles > self.ticked {
+            self.mmu.tick(cycles - self.ticked);
+        }
+        Ok(cycles)
+    }
+
+    fn execute(&mut self) -> Result<(), CpuError> {
+        if self.interrupt() {
+            return Ok(());
+        }
+
+        if self.halt {
+            // Nothing to do until an interrupt wakes the processor up,
+            // which never happens once locked up
+            self.m += 1;
+            self.t += 4;
+            return Ok(());
+        }
+
+        if self.trace.is_some() {
+            let line: String = self.trace_line();
+            if let Some(ref mut sink) = self.trace {
+                let _ = writeln!(sink, "{}", line);
+            }
+        }
+
+        // `EI` takes effect after the instruction following it
+        let ime_pending: bool = self.ime_pending;
+
+        let opcode: u8 = self.mmu.fetch8(self.pc);
+        if illegal(opcode) {
+            if !self.lock_on_illegal {
+                return Err(CpuError::IllegalOpcode { pc: self.pc, opcode: opcode });
+            }
+            self.locked = true;
+            self.halt = true;
+            self.m += 1;
+            self.t += 4;
+            return Ok(());
+        }
+        if self.halt_bug {
+            // pc fails to increment past the opcode, so that its
+            // operands are read starting from the opcode itself
+            self.halt_bug = false;
+            self.pc = self.pc.wrapping_sub(1);
+        }
+        // Opcode fetch
+        self.tick();
         if opcode==0xCB {
-            opcode = (opcode << 8) + self.mmu.read8(self.pc+1) as u16;
+            let opcode: u8 = self.mmu.read8(self.pc.wrapping_add(1));
+            self.tick();
+            self.dispatch_cb(opcode);
+        } else {
+            self.dispatch(opcode);
         }
-        match DECODER.get(&opcode) {
-            Some(&(instr, _)) => instr(self),
-            _ => panic!("error: unknown opcode {0:X}", self.pc),
+
+        if ime_pending && self.ime_pending {
+            self.ime = true;
+            self.ime_pending = false;
         }
+
+        Ok(())
     }
 
-    pub fn state(&self) -> (String, u8) {
-        let mut opcode: u16 = self.mmu.read8(self.pc) as u16;
-        if opcode==0xCB {
-            opcode = (opcode << 8) + self.mmu.read8(self.pc+1) as u16;
+    pub fn request_interrupt(&mut self, interrupt: Interrupt) {
+        //! Raise an interrupt request by setting its bit in IF.
+        let iflag: u8 = self.mmu.peek8(IF);
+        self.mmu.write8(IF, iflag | interrupt as u8);
+    }
+
+    fn interrupt(&mut self) -> bool {
+        //! Check for interrupts both enabled in IE and requested in IF.
+        //! Any of them wakes the processor up from `HALT`, and the one
+        //! with the highest priority is serviced if `ime` is set.
+        //! Return whether an interrupt was dispatched.
+        let pending: u8 = self.mmu.peek8(IE) & self.mmu.peek8(IF) & 0x1F;
+        if pending == 0 || self.locked {
+            return false;
+        }
+
+        self.halt = false;
+        if !self.ime {
+            return false;
+        }
+
+        // Acknowledge the interrupt with the lowest bit
+        let bit: u8 = pending.trailing_zeros() as u8;
+        let iflag: u8 = self.mmu.peek8(IF);
+        self.mmu.write8(IF, iflag & !(1 << bit));
+        self.ime = false;
+        self.ime_pending = false;
+
+        // Internal delays
+        self.tick();
+        self.tick();
+
+        // Push current instruction address on the stack
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc);
+
+        // Jump to the interrupt vector
+        self.pc = 0x40 + 8 * bit as u16;
+        self.tick();
+
+        // Update clocks
+        self.m += 5;
+        self.t += 20;
+
+        true
+    }
+
+    pub fn decode(&self, address: u16) -> Option<Instruction> {
+        //! Decode the instruction stored at `address`, without
+        //! executing it.
+        let bytes: [u8; 3] = [
+            self.mmu.peek8(address),
+            self.mmu.peek8(address.wrapping_add(1)),
+            self.mmu.peek8(address.wrapping_add(2)),
+        ];
+        instruction::decode(&bytes)
+    }
+
+    pub fn state(&self) -> Result<(String, u8), CpuError> {
+        //! Return the disassembly of the instruction at the program
+        //! counter, along with its size in bytes.
+        match self.decode(self.pc) {
+            Some(i) => Ok((format!("{}", i), i.length)),
+            None    => Err(CpuError::IllegalOpcode {
+                pc:     self.pc,
+                opcode: self.mmu.peek8(self.pc),
+            }),
         }
-        match DECODER.get(&opcode) {
-            Some(&(_, disp)) => disp(self),
-            _ => panic!("error: unknown opcode {0:X}", self.pc),
+    }
+
+    fn tick(&mut self) {
+        //! Account for a single M-cycle of the current instruction. In
+        //! cycle accurate mode, the bus is ticked right away.
+        if self.cycle_accurate {
+            self.mmu.tick(4);
+            self.ticked += 4;
         }
     }
 
-    pub fn run(&mut self) {
+    fn read8(&mut self, address: u16) -> u8 {
+        //! Read a single byte from the bus, in one M-cycle
+        let data: u8 = self.mmu.read8(address);
+        self.tick();
+        data
+    }
+
+    fn write8(&mut self, address: u16, data: u8) {
+        //! Write a single byte to the bus, in one M-cycle
+        self.mmu.write8(address, data);
+        self.tick();
+    }
+
+    fn read16(&mut self, address: u16) -> u16 {
+        //! Read a single word from the bus, low byte first, in two
+        //! M-cycles
+        let low: u8 = self.read8(address);
+        let high: u8 = self.read8(address.wrapping_add(1));
+        ((high as u16) << 8) + low as u16
+    }
+
+    fn write16(&mut self, address: u16, data: u16) {
+        //! Write a single word to the bus, high byte first as when
+        //! pushing on the stack, in two M-cycles
+        self.write8(address.wrapping_add(1), (data >> 8) as u8);
+        self.write8(address, data as u8);
+    }
+
+    pub fn set_trace(&mut self, sink: Option<Box<Write>>) {
+        //! Write a `trace_line` to `sink` before every executed
+        //! instruction, or stop tracing if `sink` is `None`. Write
+        //! errors are ignored.
+        self.trace = sink;
+    }
+
+    pub fn trace_line(&self) -> String {
+        //! Return the current state of the processor, in the format
+        //! of Gameboy Doctor logs:
+        //! `A:00 F:00 B:00 C:00 D:00 E:00 H:00 L:00 SP:0000 PC:0000 PCMEM:00,00,00,00`
+        format!("A:{:0>2.2X} F:{:0>2.2X} B:{:0>2.2X} C:{:0>2.2X} \
+                 D:{:0>2.2X} E:{:0>2.2X} H:{:0>2.2X} L:{:0>2.2X} \
+                 SP:{:0>4.4X} PC:{:0>4.4X} \
+                 PCMEM:{:0>2.2X},{:0>2.2X},{:0>2.2X},{:0>2.2X}",
+                self.a, self.f, self.b, self.c,
+                self.d, self.e, self.h, self.l,
+                self.sp, self.pc,
+                self.mmu.peek8(self.pc),
+                self.mmu.peek8(self.pc.wrapping_add(1)),
+                self.mmu.peek8(self.pc.wrapping_add(2)),
+                self.mmu.peek8(self.pc.wrapping_add(3)))
+    }
+
+    pub fn run(&mut self) -> Result<(), CpuError> {
+        //! Execute instructions until the processor is stopped or
+        //! locked up.
         self.stop = false;
-        while !self.stop {
-            self.step();
+        while !self.stop && !self.locked {
+            try!(self.step());
         }
+        Ok(())
     }
 
     pub fn instr_ADD_0x85(&mut self) {
@@ -152,13 +459,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.l & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.l) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.l;
+        self.a = self.a.wrapping_add(self.l);
 
         // Check for zero
         if self.a == 0 {
@@ -170,7 +480,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RST_0xE7(&mut self) {
@@ -188,9 +498,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 20h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x20;
@@ -214,7 +527,7 @@ impl Cpu {
         //! - Description
         //!   Bitwise AND on a with *.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.a &= d8;
 
         // Update flags
@@ -232,7 +545,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_JR_0x28(&mut self) {
@@ -248,25 +561,24 @@ impl Cpu {
         //!   - `C`:  Preserved
         //! - Description
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
         if self.f & (Flag::Zero as u8) != 0 {
+            // Internal delay
+            self.tick();
+
             // Update clocks
             self.m += 3;
             self.t += 12;
             // Update program counter
-            if r8 < 0 {
-                self.pc -= -r8 as u16;
-            } else {
-                self.pc += r8 as u16;
-            }
+            self.pc = self.pc.wrapping_add(2).wrapping_add(r8 as u16);
         } else {
             // Update clocks
             self.m += 2;
             self.t += 8;
             // Update program counter
-            self.pc += 2;
+            self.pc = self.pc.wrapping_add(2);
         }
     }
 
@@ -287,22 +599,32 @@ impl Cpu {
         // Update registers h and l
         let lhs: u16 = ((self.h as u16) << 8) + self.l as u16;
         let rhs: u16 = lhs;
-        let res: u16 = rhs + lhs;
+        let res: u16 = rhs.wrapping_add(lhs);
         self.h = (res >> 8) as u8;
         self.l = res as u8;
 
         // Update flags
         self.f &= !(Flag::Operation as u8);
+        if (lhs & 0x0FFF) + (rhs & 0x0FFF) > 0x0FFF {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
         if lhs > (u16::MAX - rhs) {
             self.f |= Flag::Carry as u8;
+        } else {
+            self.f &= !(Flag::Carry as u8);
         }
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x22(&mut self) {
@@ -321,9 +643,9 @@ impl Cpu {
 
         // Store a in (hl)
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        self.mmu.write8(hl, self.a);
+        self.write8(hl, self.a);
         // Increment hl
-        hl += 1;
+        hl = hl.wrapping_add(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
@@ -332,7 +654,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x23(&mut self) {
@@ -350,16 +672,19 @@ impl Cpu {
         //!   Adds one to hl.
 
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        hl += 1;
+        hl = hl.wrapping_add(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JR_0x20(&mut self) {
@@ -375,25 +700,24 @@ impl Cpu {
         //!   - `C`:  Preserved
         //! - Description
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
         if self.f & (Flag::Zero as u8) == 0 {
+            // Internal delay
+            self.tick();
+
             // Update clocks
             self.m += 3;
             self.t += 12;
             // Update program counter
-            if r8 < 0 {
-                self.pc -= -r8 as u16;
-            } else {
-                self.pc += r8 as u16;
-            }
+            self.pc = self.pc.wrapping_add(2).wrapping_add(r8 as u16);
         } else {
             // Update clocks
             self.m += 2;
             self.t += 8;
             // Update program counter
-            self.pc += 2;
+            self.pc = self.pc.wrapping_add(2);
         }
     }
 
@@ -411,7 +735,7 @@ impl Cpu {
         //! - Description
         //!   Loads ** into hl.
 
-        let d16: u16 = self.mmu.read16(self.pc + 1);
+        let d16: u16 = self.read16(self.pc.wrapping_add(1));
         self.h = (d16 >> 8) as u8;
         self.l = d16 as u8;
 
@@ -420,7 +744,7 @@ impl Cpu {
         self.t += 12;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_LD_0x26(&mut self) {
@@ -437,7 +761,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into h.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.h = d8;
 
         // Update clocks
@@ -445,7 +769,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DAA_0x27(&mut self) {
@@ -462,17 +786,41 @@ impl Cpu {
         //! - Description
         //!   Adjusts a for BCD addition and subtraction operations.
 
-        unimplemented!();
+        let mut adjust: u8 = 0;
+        let mut carry: bool = false;
+        let subtract: bool = self.f & (Flag::Operation as u8) != 0;
+
+        // Compute the correction from the flags of the previous operation
+        if self.f & (Flag::HalfCarry as u8) != 0 || (!subtract && (self.a & 0x0F) > 0x09) {
+            adjust |= 0x06;
+        }
+        if self.f & (Flag::Carry as u8) != 0 || (!subtract && self.a > 0x99) {
+            adjust |= 0x60;
+            carry = true;
+        }
+
+        // Apply it to a
+        if subtract {
+            self.a = self.a.wrapping_sub(adjust);
+        } else {
+            self.a = self.a.wrapping_add(adjust);
+        }
 
         // Update flags
-        self.f &= !(Flag::HalfCarry as u8);
+        self.f &= Flag::Operation as u8;
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
+        if carry {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x24(&mut self) {
@@ -489,7 +837,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to h.
 
-        self.h += 1;
+        self.h = self.h.wrapping_add(1);
 
         // Update flags
         if self.h==0 {
@@ -498,13 +846,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.h & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x25(&mut self) {
@@ -521,7 +874,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from h.
 
-        self.h -= 1;
+        self.h = self.h.wrapping_sub(1);
 
         // Update flags
         if self.h==0 {
@@ -530,13 +883,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.h & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JP_0xE9(&mut self) {
@@ -553,16 +911,11 @@ impl Cpu {
         //! - Description
         //!   Loads the value of hl into pc.
 
-        unimplemented!();
-
-        // Update flags
+        self.pc = ((self.h as u16) << 8) + self.l as u16;
 
         // Update clocks
         self.m += 1;
         self.t += 4;
-
-        // Update program counter
-        self.pc += 1;
     }
 
     pub fn instr_ADD_0xE8(&mut self) {
@@ -579,26 +932,31 @@ impl Cpu {
         //! - Description
         //!   Add signed offset r8 to sp.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
 
-        // Update flags
-        self.f &= !(Flag::Zero as u8);
-        self.f &= !(Flag::Operation as u8);
-        if self.sp > (u16::MAX - r8 as u16) {
+        // Update flags, from the carries of the unsigned addition of the
+        // offset to the low byte of sp
+        self.f = Flag::None as u8;
+        if (self.sp & 0x000F) + ((r8 as u8 as u16) & 0x000F) > 0x000F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.sp & 0x00FF) + (r8 as u8 as u16) > 0x00FF {
             self.f |= Flag::Carry as u8;
-        } else  {
-            self.f &= !(Flag::Carry as u8);
         }
 
         // Update register sp
-        self.sp += r8 as u16;
+        self.sp = self.sp.wrapping_add(r8 as u16);
+
+        // Internal delays
+        self.tick();
+        self.tick();
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB18(&mut self) {
@@ -617,18 +975,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.b & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate b right, previous carry goes to bit 7
+        self.b = (self.b >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DEC_0x2B(&mut self) {
@@ -646,16 +1016,19 @@ impl Cpu {
         //!   Subtracts one from hl.
 
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        hl -= 1;
+        hl = hl.wrapping_sub(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x2C(&mut self) {
@@ -672,7 +1045,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to l.
 
-        self.l += 1;
+        self.l = self.l.wrapping_add(1);
 
         // Update flags
         if self.l==0 {
@@ -681,13 +1054,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.l & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x2A(&mut self) {
@@ -706,9 +1084,9 @@ impl Cpu {
 
         // Load (hl) in a
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        self.a = self.mmu.read8(hl);
+        self.a = self.read8(hl);
         // Increment hl
-        hl += 1;
+        hl = hl.wrapping_add(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
@@ -717,7 +1095,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CPL_0x2F(&mut self) {
@@ -745,7 +1123,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x2D(&mut self) {
@@ -762,7 +1140,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from l.
 
-        self.l -= 1;
+        self.l = self.l.wrapping_sub(1);
 
         // Update flags
         if self.l==0 {
@@ -771,13 +1149,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.l & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x2E(&mut self) {
@@ -794,7 +1177,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into l.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.l = d8;
 
         // Update clocks
@@ -802,7 +1185,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB19(&mut self) {
@@ -821,18 +1204,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.c & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate c right, previous carry goes to bit 7
+        self.c = (self.c >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2F(&mut self) {
@@ -845,25 +1240,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of a are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift a right, bit 7 is preserved
+        self.a = (self.a >> 1) | (self.a & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2E(&mut self) {
@@ -876,25 +1280,38 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of (hl) are shifted right one bit position. The
         //!   contents of bit 0 are copied to the carry flag and the previous
         //!   contents of bit 7 are unchanged.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if phl & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift (hl) right, bit 7 is preserved
+        phl = (phl >> 1) | (phl & 0b10000000);
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2D(&mut self) {
@@ -907,25 +1324,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of l are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.l & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift l right, bit 7 is preserved
+        self.l = (self.l >> 1) | (self.l & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2C(&mut self) {
@@ -938,25 +1364,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of h are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.h & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift h right, bit 7 is preserved
+        self.h = (self.h >> 1) | (self.h & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2B(&mut self) {
@@ -969,25 +1404,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of e are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.e & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift e right, bit 7 is preserved
+        self.e = (self.e >> 1) | (self.e & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB2A(&mut self) {
@@ -1000,25 +1444,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of d are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.d & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift d right, bit 7 is preserved
+        self.d = (self.d >> 1) | (self.d & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB27(&mut self) {
@@ -1037,18 +1490,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.a & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift a left, bit 0 is reset
+        self.a <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB26(&mut self) {
@@ -1067,18 +1530,32 @@ impl Cpu {
         //!   contents of bit 7 are copied to the carry flag and a zero is put
         //!   into bit 0.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if phl & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift (hl) left, bit 0 is reset
+        phl <<= 1;
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB25(&mut self) {
@@ -1097,18 +1574,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.l & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift l left, bit 0 is reset
+        self.l <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB24(&mut self) {
@@ -1127,18 +1614,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.h & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift h left, bit 0 is reset
+        self.h <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB23(&mut self) {
@@ -1157,18 +1654,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
-        // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        // Bit 7 goes to the carry flag
+        if self.e & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift e left, bit 0 is reset
+        self.e <<= 1;
+
+        // Update flags
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB22(&mut self) {
@@ -1187,18 +1694,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.d & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift d left, bit 0 is reset
+        self.d <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB21(&mut self) {
@@ -1217,18 +1734,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.c & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift c left, bit 0 is reset
+        self.c <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SLA_0xCB20(&mut self) {
@@ -1247,18 +1774,28 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and a zero is put into bit
         //!   0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.b & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift b left, bit 0 is reset
+        self.b <<= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB29(&mut self) {
@@ -1271,25 +1808,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of c are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.c & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift c right, bit 7 is preserved
+        self.c = (self.c >> 1) | (self.c & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRA_0xCB28(&mut self) {
@@ -1302,25 +1848,34 @@ impl Cpu {
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
         //!   - `H`:  Force unset (0)
-        //!   - `C`:  Force unset (0)
+        //!   - `C`:  Set if appropriate
         //! - Description
         //!   The contents of b are shifted right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   bit 7 are unchanged.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.b & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift b right, bit 7 is preserved
+        self.b = (self.b >> 1) | (self.b & 0b10000000);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x68(&mut self) {
@@ -1344,7 +1899,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x69(&mut self) {
@@ -1368,7 +1923,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x66(&mut self) {
@@ -1385,7 +1940,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into h.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.h = hl;
 
         // Update clocks
@@ -1393,7 +1948,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x67(&mut self) {
@@ -1417,7 +1972,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x64(&mut self) {
@@ -1439,7 +1994,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x65(&mut self) {
@@ -1463,7 +2018,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x62(&mut self) {
@@ -1487,7 +2042,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x63(&mut self) {
@@ -1511,7 +2066,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x60(&mut self) {
@@ -1535,7 +2090,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x61(&mut self) {
@@ -1559,7 +2114,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6F(&mut self) {
@@ -1583,7 +2138,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6D(&mut self) {
@@ -1605,7 +2160,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6E(&mut self) {
@@ -1622,7 +2177,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into l.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.l = hl;
 
         // Update clocks
@@ -1630,7 +2185,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6B(&mut self) {
@@ -1654,7 +2209,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6C(&mut self) {
@@ -1678,7 +2233,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x6A(&mut self) {
@@ -1702,7 +2257,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_BIT_0xCB6C(&mut self) {
@@ -1719,9 +2274,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -1730,7 +2288,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB6B(&mut self) {
@@ -1747,9 +2305,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -1758,7 +2319,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB6A(&mut self) {
@@ -1775,9 +2336,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -1786,7 +2350,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB6F(&mut self) {
@@ -1803,9 +2367,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -1814,7 +2381,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB6E(&mut self) {
@@ -1822,7 +2389,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB6E`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -1831,18 +2398,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB6D(&mut self) {
@@ -1859,9 +2432,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -1870,7 +2446,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDA(&mut self) {
@@ -1894,7 +2470,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDC(&mut self) {
@@ -1918,7 +2494,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDB(&mut self) {
@@ -1942,7 +2518,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDE(&mut self) {
@@ -1959,16 +2535,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 3 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00001000;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDD(&mut self) {
@@ -1992,7 +2568,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBDF(&mut self) {
@@ -2016,7 +2592,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_ADD_0x84(&mut self) {
@@ -2036,13 +2612,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.h & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.h) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.h;
+        self.a = self.a.wrapping_add(self.h);
 
         // Check for zero
         if self.a == 0 {
@@ -2054,7 +2633,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JP_0xDA(&mut self) {
@@ -2071,14 +2650,24 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Carry as u8) != 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Update clocks
+            self.m += 4;
+            self.t += 16;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_ADD_0x86(&mut self) {
@@ -2098,14 +2687,17 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (hl & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - hl) {
             self.f |= Flag::Carry as u8;
         }
 
-        self.a += hl;
+        self.a = self.a.wrapping_add(hl);
 
         // Update flags
         if self.a==0 {
@@ -2118,7 +2710,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CALL_0xDC(&mut self) {
@@ -2136,14 +2728,28 @@ impl Cpu {
         //!   If condition cc is true, the current pc value plus three is pushed
         //!   onto the stack, then is loaded with **.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Carry as u8) != 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Push next instruction address on the stack
+            self.sp = self.sp.wrapping_sub(2);
+            self.write16(self.sp, self.pc.wrapping_add(3));
+
+            // Update clocks
+            self.m += 6;
+            self.t += 24;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_ADD_0x80(&mut self) {
@@ -2163,13 +2769,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.b & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.b) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.b;
+        self.a = self.a.wrapping_add(self.b);
 
         // Check for zero
         if self.a == 0 {
@@ -2181,7 +2790,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADD_0x81(&mut self) {
@@ -2201,13 +2810,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.c & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.c) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.c;
+        self.a = self.a.wrapping_add(self.c);
 
         // Check for zero
         if self.a == 0 {
@@ -2219,7 +2831,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADD_0x82(&mut self) {
@@ -2239,13 +2851,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.d & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.d) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.d;
+        self.a = self.a.wrapping_add(self.d);
 
         // Check for zero
         if self.a == 0 {
@@ -2257,7 +2872,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADD_0x83(&mut self) {
@@ -2277,13 +2892,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.e & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - self.e) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.e;
+        self.a = self.a.wrapping_add(self.e);
 
         // Check for zero
         if self.a == 0 {
@@ -2295,7 +2913,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RR_0xCB1F(&mut self) {
@@ -2314,18 +2932,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a right, previous carry goes to bit 7
+        self.a = (self.a >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB1D(&mut self) {
@@ -2344,18 +2974,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.l & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate l right, previous carry goes to bit 7
+        self.l = (self.l >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB1E(&mut self) {
@@ -2374,18 +3016,34 @@ impl Cpu {
         //!   contents of bit 0 are copied to the carry flag and the previous
         //!   contents of the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if phl & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate (hl) right, previous carry goes to bit 7
+        phl = (phl >> 1) | (carry << 7);
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB1B(&mut self) {
@@ -2404,18 +3062,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.e & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate e right, previous carry goes to bit 7
+        self.e = (self.e >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB1C(&mut self) {
@@ -2434,18 +3104,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.h & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate h right, previous carry goes to bit 7
+        self.h = (self.h >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RR_0xCB1A(&mut self) {
@@ -2464,18 +3146,30 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.d & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate d right, previous carry goes to bit 7
+        self.d = (self.d >> 1) | (carry << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RET_0xD0(&mut self) {
@@ -2492,16 +3186,28 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        unimplemented!();
+        // Internal delay, to check the condition
+        self.tick();
 
-        // Update flags
+        if self.f & (Flag::Carry as u8) == 0 {
+            // Pop return address from the stack
+            self.pc = self.read16(self.sp);
+            self.sp = self.sp.wrapping_add(2);
 
-        // Update clocks
-        self.m += 2;
-        self.t += 8;
+            // Internal delay, to set pc
+            self.tick();
 
-        // Update program counter
-        self.pc += 1;
+            // Update clocks
+            self.m += 5;
+            self.t += 20;
+        } else {
+            // Update clocks
+            self.m += 2;
+            self.t += 8;
+
+            // Update program counter
+            self.pc = self.pc.wrapping_add(1);
+        }
     }
 
     pub fn instr_ADC_0x8E(&mut self) {
@@ -2518,17 +3224,35 @@ impl Cpu {
         //! - Description
         //!   Adds (hl) and the carry flag to a.
 
-        unimplemented!();
+        let phl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + phl as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (phl & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADC_0x8F(&mut self) {
@@ -2545,17 +3269,34 @@ impl Cpu {
         //! - Description
         //!   Adds a and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.a as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.a & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CALL_0xD4(&mut self) {
@@ -2573,15 +3314,29 @@ impl Cpu {
         //!   If condition cc is true, the current pc value plus three is pushed
         //!   onto the stack, then is loaded with **.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Carry as u8) == 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
-    }
+            // Push next instruction address on the stack
+            self.sp = self.sp.wrapping_sub(2);
+            self.write16(self.sp, self.pc.wrapping_add(3));
+
+            // Update clocks
+            self.m += 6;
+            self.t += 24;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
+    }
 
     pub fn instr_ADC_0x8A(&mut self) {
         //! - Prototype: `ADC A, D`
@@ -2597,17 +3352,34 @@ impl Cpu {
         //! - Description
         //!   Adds d and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.d as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.d & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADC_0x8B(&mut self) {
@@ -2624,17 +3396,34 @@ impl Cpu {
         //! - Description
         //!   Adds e and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.e as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.e & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADC_0x8C(&mut self) {
@@ -2651,17 +3440,34 @@ impl Cpu {
         //! - Description
         //!   Adds h and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.h as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.h & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RL_0xCB16(&mut self) {
@@ -2680,18 +3486,34 @@ impl Cpu {
         //!   contents of bit 7 are copied to the carry flag and the previous
         //!   contents of the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if phl & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate (hl) left, previous carry goes to bit 0
+        phl = (phl << 1) | carry;
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB17(&mut self) {
@@ -2710,18 +3532,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.a & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a left, previous carry goes to bit 0
+        self.a = (self.a << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB14(&mut self) {
@@ -2740,18 +3574,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.h & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate h left, previous carry goes to bit 0
+        self.h = (self.h << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB15(&mut self) {
@@ -2770,18 +3616,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.l & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate l left, previous carry goes to bit 0
+        self.l = (self.l << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB12(&mut self) {
@@ -2800,18 +3658,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.d & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate d left, previous carry goes to bit 0
+        self.d = (self.d << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB13(&mut self) {
@@ -2830,18 +3700,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.e & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate e left, previous carry goes to bit 0
+        self.e = (self.e << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB10(&mut self) {
@@ -2860,18 +3742,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.b & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate b left, previous carry goes to bit 0
+        self.b = (self.b << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RL_0xCB11(&mut self) {
@@ -2890,18 +3784,30 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.c & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate c left, previous carry goes to bit 0
+        self.c = (self.c << 1) | carry;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD1(&mut self) {
@@ -2925,7 +3831,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD0(&mut self) {
@@ -2949,7 +3855,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD3(&mut self) {
@@ -2973,7 +3879,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD2(&mut self) {
@@ -2997,7 +3903,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD5(&mut self) {
@@ -3021,7 +3927,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD4(&mut self) {
@@ -3045,7 +3951,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD7(&mut self) {
@@ -3069,7 +3975,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD6(&mut self) {
@@ -3086,16 +3992,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 2 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00000100;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD9(&mut self) {
@@ -3119,7 +4025,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBD8(&mut self) {
@@ -3143,7 +4049,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB69(&mut self) {
@@ -3160,9 +4066,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3171,7 +4080,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB68(&mut self) {
@@ -3188,9 +4097,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 5 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00100000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3199,7 +4111,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB63(&mut self) {
@@ -3216,9 +4128,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3227,7 +4142,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB62(&mut self) {
@@ -3244,9 +4159,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3255,7 +4173,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB61(&mut self) {
@@ -3272,9 +4190,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3283,7 +4204,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB60(&mut self) {
@@ -3300,9 +4221,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3311,7 +4235,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB67(&mut self) {
@@ -3328,9 +4252,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3339,7 +4266,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB66(&mut self) {
@@ -3347,7 +4274,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB66`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -3356,18 +4283,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB65(&mut self) {
@@ -3384,9 +4317,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3395,7 +4331,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB64(&mut self) {
@@ -3412,9 +4348,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 4 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00010000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -3423,7 +4362,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB85(&mut self) {
@@ -3440,16 +4379,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB84(&mut self) {
@@ -3466,16 +4403,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB87(&mut self) {
@@ -3492,16 +4427,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB86(&mut self) {
@@ -3518,16 +4451,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11111110;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB81(&mut self) {
@@ -3544,16 +4478,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB80(&mut self) {
@@ -3570,16 +4502,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB83(&mut self) {
@@ -3596,16 +4526,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB82(&mut self) {
@@ -3622,16 +4550,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 0 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11111110;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB89(&mut self) {
@@ -3648,16 +4574,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB88(&mut self) {
@@ -3674,16 +4598,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x3E(&mut self) {
@@ -3700,7 +4622,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into a.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.a = d8;
 
         // Update clocks
@@ -3708,7 +4630,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DEC_0x3D(&mut self) {
@@ -3725,7 +4647,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from a.
 
-        self.a -= 1;
+        self.a = self.a.wrapping_sub(1);
 
         // Update flags
         if self.a==0 {
@@ -3734,13 +4656,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.a & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CCF_0x3F(&mut self) {
@@ -3757,18 +4684,17 @@ impl Cpu {
         //! - Description
         //!   Inverts the carry flag.
 
-        unimplemented!();
-
         // Update flags
         self.f &= !(Flag::Operation as u8);
         self.f &= !(Flag::HalfCarry as u8);
+        self.f ^= Flag::Carry as u8;
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x3A(&mut self) {
@@ -3787,9 +4713,9 @@ impl Cpu {
 
         // Load (hl) in a
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        self.a = self.mmu.read8(hl);
+        self.a = self.read8(hl);
         // Decrement hl
-        hl -= 1;
+        hl = hl.wrapping_sub(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
@@ -3798,7 +4724,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x3C(&mut self) {
@@ -3815,7 +4741,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to a.
 
-        self.a += 1;
+        self.a = self.a.wrapping_add(1);
 
         // Update flags
         if self.a==0 {
@@ -3824,13 +4750,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.a & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x3B(&mut self) {
@@ -3847,14 +4778,17 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from sp.
 
-        self.sp -= 1;
+        self.sp = self.sp.wrapping_sub(1);
+
+        // Internal delay
+        self.tick();
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADD_0x87(&mut self) {
@@ -3874,13 +4808,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.a & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > u8::MAX/2 {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += self.a;
+        self.a = self.a.wrapping_add(self.a);
 
         // Check for zero
         if self.a == 0 {
@@ -3892,7 +4829,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0xDE(&mut self) {
@@ -3907,21 +4844,24 @@ impl Cpu {
         //!   - `H`:  Set if appropriate
         //!   - `C`:  Set if appropriate
         //! - Description
-        //!   Subtracts * and the carry flag from a.
+        //!   Subtracts d8 and the carry flag from a.
+
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 7;
-
-        // Update flags
-        if self.a<d8+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((d8 & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < d8 as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= d8 + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(d8).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -3934,7 +4874,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RST_0xDF(&mut self) {
@@ -3952,9 +4892,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 18h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x18;
@@ -3979,23 +4922,28 @@ impl Cpu {
         //!   Subtracts one from (hl).
 
         let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        let phl: u8 = self.mmu.read8(hl);
-        self.mmu.write8(hl, phl-1);
+        let phl: u8 = self.read8(hl);
+        self.write8(hl, phl.wrapping_sub(1));
 
         // Update flags
         self.f |= Flag::Operation as u8;
-        if phl-1==0 {
+        if phl.wrapping_sub(1)==0 {
             self.f |= Flag::Zero as u8;
         } else  {
             self.f &= !(Flag::Zero as u8);
         }
+        if phl & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x34(&mut self) {
@@ -4013,23 +4961,28 @@ impl Cpu {
         //!   Adds one to (hl).
 
         let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        let phl: u8 = self.mmu.read8(hl);
-        self.mmu.write8(hl, phl+1);
+        let phl: u8 = self.read8(hl);
+        self.write8(hl, phl.wrapping_add(1));
 
         // Update flags
-        if phl+1==0 {
+        if phl.wrapping_add(1)==0 {
             self.f |= Flag::Zero as u8;
         } else  {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if phl & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SCF_0x37(&mut self) {
@@ -4046,8 +4999,6 @@ impl Cpu {
         //! - Description
         //!   Sets the carry flag.
 
-        unimplemented!();
-
         // Update flags
         self.f &= !(Flag::Operation as u8);
         self.f &= !(Flag::HalfCarry as u8);
@@ -4058,7 +5009,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x36(&mut self) {
@@ -4075,15 +5026,15 @@ impl Cpu {
         //! - Description
         //!   Loads * into (hl).
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, d8);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
+        self.write8(((self.h as u16) << 8) + self.l as u16, d8);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x31(&mut self) {
@@ -4100,7 +5051,7 @@ impl Cpu {
         //! - Description
         //!   Loads ** into sp.
 
-        let d16: u16 = self.mmu.read16(self.pc + 1);
+        let d16: u16 = self.read16(self.pc.wrapping_add(1));
         self.sp = d16;
 
         // Update clocks
@@ -4108,7 +5059,7 @@ impl Cpu {
         self.t += 12;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_JR_0x30(&mut self) {
@@ -4124,25 +5075,24 @@ impl Cpu {
         //!   - `C`:  Preserved
         //! - Description
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
         if self.f & (Flag::Carry as u8) == 0 {
+            // Internal delay
+            self.tick();
+
             // Update clocks
             self.m += 3;
             self.t += 12;
             // Update program counter
-            if r8 < 0 {
-                self.pc -= -r8 as u16;
-            } else {
-                self.pc += r8 as u16;
-            }
+            self.pc = self.pc.wrapping_add(2).wrapping_add(r8 as u16);
         } else {
             // Update clocks
             self.m += 2;
             self.t += 8;
             // Update program counter
-            self.pc += 2;
+            self.pc = self.pc.wrapping_add(2);
         }
     }
 
@@ -4160,14 +5110,17 @@ impl Cpu {
         //! - Description
         //!   Adds one to sp.
 
-        self.sp += 1;
+        self.sp = self.sp.wrapping_add(1);
+
+        // Internal delay
+        self.tick();
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x32(&mut self) {
@@ -4186,9 +5139,9 @@ impl Cpu {
 
         // Store a in (hl)
         let mut hl: u16 = ((self.h as u16) << 8) + self.l as u16;
-        self.mmu.write8(hl, self.a);
+        self.write8(hl, self.a);
         // Decrement hl
-        hl += 1;
+        hl = hl.wrapping_add(1);
         self.h = (hl >> 8) as u8;
         self.l = hl as u8;
 
@@ -4197,7 +5150,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADD_0x39(&mut self) {
@@ -4216,25 +5169,33 @@ impl Cpu {
 
         // Update registers h and l
         let lhs: u16 = ((self.h as u16) << 8) + self.l as u16;
-        let rhs: u16 = lhs;
-        let res: u16 = rhs + lhs;
+        let rhs: u16 = self.sp;
+        let res: u16 = rhs.wrapping_add(lhs);
         self.h = (res >> 8) as u8;
         self.l = res as u8;
 
         // Update flags
         self.f &= !(Flag::Operation as u8);
+        if (lhs & 0x0FFF) + (rhs & 0x0FFF) > 0x0FFF {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
         if lhs > (u16::MAX - rhs) {
             self.f |= Flag::Carry as u8;
         } else  {
             self.f &= !(Flag::Carry as u8);
         }
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JR_0x38(&mut self) {
@@ -4250,25 +5211,24 @@ impl Cpu {
         //!   - `C`:  Preserved
         //! - Description
         //!   If condition cc is true, the signed value * is added to pc. The
-        //!   jump is measured from the start of the instruction opcode.
+        //!   jump is measured from the start of the next instruction.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
         if self.f & (Flag::Carry as u8) != 0 {
+            // Internal delay
+            self.tick();
+
             // Update clocks
             self.m += 3;
             self.t += 12;
             // Update program counter
-            if r8 < 0 {
-                self.pc -= -r8 as u16;
-            } else {
-                self.pc += r8 as u16;
-            }
+            self.pc = self.pc.wrapping_add(2).wrapping_add(r8 as u16);
         } else {
             // Update clocks
             self.m += 2;
             self.t += 8;
             // Update program counter
-            self.pc += 2;
+            self.pc = self.pc.wrapping_add(2);
         }
     }
 
@@ -4286,16 +5246,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11111101;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB8D(&mut self) {
@@ -4312,16 +5273,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB8F(&mut self) {
@@ -4338,16 +5297,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB8A(&mut self) {
@@ -4364,16 +5321,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB8C(&mut self) {
@@ -4390,16 +5345,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB8B(&mut self) {
@@ -4416,16 +5369,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 1 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11111101;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_ADC_0x88(&mut self) {
@@ -4442,17 +5393,34 @@ impl Cpu {
         //! - Description
         //!   Adds b and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.b as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.b & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADC_0x89(&mut self) {
@@ -4469,17 +5437,34 @@ impl Cpu {
         //! - Description
         //!   Adds c and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.c as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.c & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_ADC_0x8D(&mut self) {
@@ -4496,17 +5481,34 @@ impl Cpu {
         //! - Description
         //!   Adds l and the carry flag to a.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + self.l as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (self.l & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_POP_0xD1(&mut self) {
@@ -4526,17 +5528,17 @@ impl Cpu {
         //!   and sp is incremented again.
 
         // Pop DE from stack
-        self.e = self.mmu.read8(self.sp);
-        self.sp += 1;
-        self.d = self.mmu.read8(self.sp);
-        self.sp += 1;
+        self.e = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
+        self.d = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JP_0xD2(&mut self) {
@@ -4553,14 +5555,24 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Carry as u8) == 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Update clocks
+            self.m += 4;
+            self.t += 16;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_PUSH_0xD5(&mut self) {
@@ -4579,18 +5591,21 @@ impl Cpu {
         //!   to by sp. sp is decremented again and e is stored into the memory
         //!   location pointed to by sp.
 
+        // Internal delay
+        self.tick();
+
         // Push DE on the stack
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.d);
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.e);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.d);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.e);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0xD6(&mut self) {
@@ -4610,25 +5625,28 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
 
         // Update flags
         if self.a==d8 {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (d8 & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<d8 {
             self.f |= Flag::Carry as u8;
         }
 
-        self.a -= d8;
+        self.a = self.a.wrapping_sub(d8);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RST_0xD7(&mut self) {
@@ -4646,9 +5664,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 10h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x10;
@@ -4672,16 +5693,28 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        unimplemented!();
+        // Internal delay, to check the condition
+        self.tick();
 
-        // Update flags
+        if self.f & (Flag::Carry as u8) != 0 {
+            // Pop return address from the stack
+            self.pc = self.read16(self.sp);
+            self.sp = self.sp.wrapping_add(2);
 
-        // Update clocks
-        self.m += 2;
-        self.t += 8;
+            // Internal delay, to set pc
+            self.tick();
 
-        // Update program counter
-        self.pc += 1;
+            // Update clocks
+            self.m += 5;
+            self.t += 20;
+        } else {
+            // Update clocks
+            self.m += 2;
+            self.t += 8;
+
+            // Update program counter
+            self.pc = self.pc.wrapping_add(1);
+        }
     }
 
     pub fn instr_RETI_0xD9(&mut self) {
@@ -4696,19 +5729,21 @@ impl Cpu {
         //!   - `H`:  Preserved
         //!   - `C`:  Preserved
         //! - Description
-        //!   Exchanges the 16-bit contents of bc, de, and hl with bc', de', and
-        //!   hl'.
+        //!   The top stack entry is popped into pc, and interrupts are
+        //!   enabled. Unlike EI, ime is set right away.
 
-        unimplemented!();
+        self.ime = true;
 
-        // Update flags
+        // Pop return address from the stack
+        self.pc = self.read16(self.sp);
+        self.sp = self.sp.wrapping_add(2);
+
+        // Internal delay, to set pc
+        self.tick();
 
         // Update clocks
         self.m += 4;
         self.t += 16;
-
-        // Update program counter
-        self.pc += 1;
     }
 
     pub fn instr_XOR_0xA9(&mut self) {
@@ -4740,7 +5775,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xA8(&mut self) {
@@ -4772,7 +5807,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA7(&mut self) {
@@ -4801,7 +5836,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA6(&mut self) {
@@ -4818,7 +5853,7 @@ impl Cpu {
         //! - Description
         //!   Bitwise AND on a with (hl).
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.a &= hl;
 
         // Update flags
@@ -4833,7 +5868,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA5(&mut self) {
@@ -4864,7 +5899,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA4(&mut self) {
@@ -4895,7 +5930,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA3(&mut self) {
@@ -4926,7 +5961,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA2(&mut self) {
@@ -4957,7 +5992,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA1(&mut self) {
@@ -4988,7 +6023,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_AND_0xA0(&mut self) {
@@ -5019,7 +6054,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SET_0xCBC8(&mut self) {
@@ -5043,7 +6078,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC9(&mut self) {
@@ -5067,7 +6102,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x7A(&mut self) {
@@ -5091,7 +6126,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x7C(&mut self) {
@@ -5115,7 +6150,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x7B(&mut self) {
@@ -5139,7 +6174,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x7E(&mut self) {
@@ -5156,7 +6191,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into a.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.a = hl;
 
         // Update clocks
@@ -5164,7 +6199,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x7D(&mut self) {
@@ -5188,7 +6223,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x7F(&mut self) {
@@ -5210,7 +6245,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_BIT_0xCB52(&mut self) {
@@ -5227,9 +6262,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5238,7 +6276,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB53(&mut self) {
@@ -5255,9 +6293,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5266,7 +6307,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB50(&mut self) {
@@ -5283,9 +6324,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5294,7 +6338,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB51(&mut self) {
@@ -5311,9 +6355,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5322,7 +6369,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB56(&mut self) {
@@ -5330,7 +6377,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB56`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -5339,18 +6386,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB57(&mut self) {
@@ -5367,9 +6420,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5378,7 +6434,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB54(&mut self) {
@@ -5395,9 +6451,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5406,7 +6465,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB55(&mut self) {
@@ -5423,9 +6482,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 2 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00000100 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5434,7 +6496,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC0(&mut self) {
@@ -5458,7 +6520,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC1(&mut self) {
@@ -5482,7 +6544,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB58(&mut self) {
@@ -5499,9 +6561,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5510,7 +6575,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC3(&mut self) {
@@ -5534,7 +6599,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC4(&mut self) {
@@ -5558,7 +6623,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC5(&mut self) {
@@ -5582,7 +6647,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC6(&mut self) {
@@ -5599,16 +6664,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 0 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00000001;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC7(&mut self) {
@@ -5632,7 +6697,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5B(&mut self) {
@@ -5649,9 +6714,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5660,7 +6728,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5C(&mut self) {
@@ -5677,9 +6745,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5688,7 +6759,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5A(&mut self) {
@@ -5705,9 +6776,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5716,7 +6790,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5F(&mut self) {
@@ -5733,9 +6807,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5744,7 +6821,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5D(&mut self) {
@@ -5761,9 +6838,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -5772,7 +6852,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB5E(&mut self) {
@@ -5780,7 +6860,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB5E`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -5789,18 +6869,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCA(&mut self) {
@@ -5824,7 +6910,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCB(&mut self) {
@@ -5848,7 +6934,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCC(&mut self) {
@@ -5872,7 +6958,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCD(&mut self) {
@@ -5896,7 +6982,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCE(&mut self) {
@@ -5913,16 +6999,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 1 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00000010;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBCF(&mut self) {
@@ -5946,7 +7032,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x79(&mut self) {
@@ -5970,7 +7056,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x78(&mut self) {
@@ -5994,7 +7080,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x71(&mut self) {
@@ -6011,14 +7097,14 @@ impl Cpu {
         //! - Description
         //!   The contents of c are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.c);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.c);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x70(&mut self) {
@@ -6035,14 +7121,14 @@ impl Cpu {
         //! - Description
         //!   The contents of b are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.b);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.b);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x73(&mut self) {
@@ -6059,14 +7145,14 @@ impl Cpu {
         //! - Description
         //!   The contents of e are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.e);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.e);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x72(&mut self) {
@@ -6083,14 +7169,14 @@ impl Cpu {
         //! - Description
         //!   The contents of d are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.d);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.d);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x75(&mut self) {
@@ -6107,14 +7193,14 @@ impl Cpu {
         //! - Description
         //!   The contents of l are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.l);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.l);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x74(&mut self) {
@@ -6131,14 +7217,14 @@ impl Cpu {
         //! - Description
         //!   The contents of h are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.h);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.h);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x77(&mut self) {
@@ -6155,14 +7241,14 @@ impl Cpu {
         //! - Description
         //!   The contents of a are loaded into (hl).
 
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, self.a);
+        self.write8(((self.h as u16) << 8) + self.l as u16, self.a);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_HALT_0x76(&mut self) {
@@ -6179,16 +7265,20 @@ impl Cpu {
         //! - Description
         //!   Suspends CPU operation until an interrupt or reset occurs.
 
-        unimplemented!();
-
-        // Update flags
+        let pending: u8 = self.mmu.peek8(IE) & self.mmu.peek8(IF) & 0x1F;
+        if !self.ime && pending != 0 {
+            // The processor does not halt, and the next opcode is read twice
+            self.halt_bug = true;
+        } else {
+            self.halt = true;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_BIT_0xCB59(&mut self) {
@@ -6205,9 +7295,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 3 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00001000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -6216,7 +7309,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_XOR_0xAF(&mut self) {
@@ -6243,7 +7336,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xAE(&mut self) {
@@ -6260,7 +7353,7 @@ impl Cpu {
         //! - Description
         //!   Bitwise XOR on a with (hl).
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.a ^= hl;
 
         // Update flags
@@ -6274,7 +7367,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xAD(&mut self) {
@@ -6304,7 +7397,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xAC(&mut self) {
@@ -6334,7 +7427,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xAB(&mut self) {
@@ -6364,7 +7457,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_XOR_0xAA(&mut self) {
@@ -6394,7 +7487,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9F(&mut self) {
@@ -6413,7 +7506,7 @@ impl Cpu {
 
         if self.f & (Flag::Carry as u8)>0 {
             self.a = 0xFFu8;
-            self.f = Flag::Carry as u8;
+            self.f = Flag::HalfCarry as u8 | Flag::Carry as u8;
         } else {
             self.a = 0;
             self.f = Flag::Zero as u8;
@@ -6427,7 +7520,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9E(&mut self) {
@@ -6444,9 +7537,27 @@ impl Cpu {
         //! - Description
         //!   Subtracts (hl) and the carry flag from a.
 
-        unimplemented!();
+        let phl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte borrows
+        if (self.a & 0x0F) < (phl & 0x0F) + carry {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < phl as u16 + carry as u16 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(phl).wrapping_sub(carry);
 
         // Update flags
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
 
         // Update clocks
@@ -6454,7 +7565,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9D(&mut self) {
@@ -6471,18 +7582,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts l and the carry flag from a.
 
-        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.l+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.l & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.l as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.l + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.l).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -6495,7 +7609,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9C(&mut self) {
@@ -6512,18 +7626,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts h and the carry flag from a.
 
-        let carry: u8 = (self.h & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.h+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.h & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.h as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.h + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.h).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -6536,7 +7653,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9B(&mut self) {
@@ -6553,18 +7670,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts e and the carry flag from a.
 
-        let carry: u8 = (self.e & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.e+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.e & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.e as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.e + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.e).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -6577,7 +7697,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x9A(&mut self) {
@@ -6594,18 +7714,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts d and the carry flag from a.
 
-        let carry: u8 = (self.d & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.d+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.d & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.d as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.d + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.d).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -6618,7 +7741,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x08(&mut self) {
@@ -6633,17 +7756,19 @@ impl Cpu {
         //!   - `H`:  Preserved
         //!   - `C`:  Preserved
         //! - Description
-        //!   Exchanges the 16-bit contents of af and af'.
+        //!   Stores sp into the memory location pointed to by **, low byte
+        //!   first.
 
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        self.mmu.write16(a16, self.sp);
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
+        self.write8(a16, self.sp as u8);
+        self.write8(a16.wrapping_add(1), (self.sp >> 8) as u8);
 
         // Update clocks
         self.m += 5;
         self.t += 20;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_ADD_0x09(&mut self) {
@@ -6663,24 +7788,32 @@ impl Cpu {
         // Update registers h and l
         let lhs: u16 = ((self.h as u16) << 8) + self.l as u16;
         let rhs: u16 = ((self.b as u16) << 8) + self.c as u16;
-        let res: u16 = rhs + lhs;
+        let res: u16 = rhs.wrapping_add(lhs);
         self.h = (res >> 8) as u8;
         self.l = res as u8;
 
         // Update flags
         self.f &= !(Flag::Operation as u8);
+        if (lhs & 0x0FFF) + (rhs & 0x0FFF) > 0x0FFF {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
         if lhs > (u16::MAX - rhs) {
             self.f |= Flag::Carry as u8;
         } else {
             self.f &= !(Flag::Carry as u8);
         }
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x04(&mut self) {
@@ -6697,7 +7830,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to b.
 
-        self.b += 1;
+        self.b = self.b.wrapping_add(1);
 
         // Update flags
         if self.b==0 {
@@ -6706,13 +7839,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.b & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x05(&mut self) {
@@ -6729,7 +7867,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from b.
 
-        self.b -= 1;
+        self.b = self.b.wrapping_sub(1);
 
         // Update flags
         if self.b==0 {
@@ -6738,13 +7876,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.b & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x06(&mut self) {
@@ -6761,7 +7904,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into b.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.b = d8;
 
         // Update clocks
@@ -6769,7 +7912,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLCA_0x07(&mut self) {
@@ -6803,7 +7946,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_NOP_0x00(&mut self) {
@@ -6825,7 +7968,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x01(&mut self) {
@@ -6842,7 +7985,7 @@ impl Cpu {
         //! - Description
         //!   Loads ** into bc.
 
-        let d16: u16 = self.mmu.read16(self.pc + 1);
+        let d16: u16 = self.read16(self.pc.wrapping_add(1));
         self.b = (d16 >> 8) as u8;
         self.c = d16 as u8;
 
@@ -6851,7 +7994,7 @@ impl Cpu {
         self.t += 12;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_LD_0x02(&mut self) {
@@ -6869,14 +8012,14 @@ impl Cpu {
         //!   Stores a into the memory location pointed to by bc.
 
         let bc: u16 = ((self.b as u16) << 8) + self.c as u16;
-        self.mmu.write8(bc, self.a);
+        self.write8(bc, self.a);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x03(&mut self) {
@@ -6894,16 +8037,19 @@ impl Cpu {
         //!   Adds one to bc.
 
         let mut bc: u16 = ((self.b as u16) << 8) + self.c as u16;
-        bc += 1;
+        bc = bc.wrapping_add(1);
         self.b = (bc >> 8) as u8;
         self.c = bc as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x0D(&mut self) {
@@ -6920,7 +8066,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from c.
 
-        self.c -= 1;
+        self.c = self.c.wrapping_sub(1);
 
         // Update flags
         if self.c==0 {
@@ -6929,13 +8075,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.c & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x0E(&mut self) {
@@ -6952,7 +8103,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into c.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.c = d8;
 
         // Update clocks
@@ -6960,7 +8111,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRCA_0x0F(&mut self) {
@@ -6978,19 +8129,23 @@ impl Cpu {
         //!   The contents of a are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
 
-        // Update flags
-        self.f &= !(Flag::Zero as u8);
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        // Rotate a right, bit 0 wraps around to bit 7
+        self.a = (self.a >> 1) | (self.a << 7);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x0A(&mut self) {
@@ -7008,14 +8163,14 @@ impl Cpu {
         //!   Loads the value pointed to by bc into a.
 
         let bc: u16 = ((self.b as u16) << 8) + self.c as u16;
-        self.a = self.mmu.read8(bc);
+        self.a = self.read8(bc);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x0B(&mut self) {
@@ -7033,16 +8188,19 @@ impl Cpu {
         //!   Subtracts one from bc.
 
         let mut bc: u16 = ((self.b as u16) << 8) + self.c as u16;
-        bc -= 1;
+        bc = bc.wrapping_sub(1);
         self.b = (bc >> 8) as u8;
         self.c = bc as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x0C(&mut self) {
@@ -7059,7 +8217,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to c.
 
-        self.c += 1;
+        self.c = self.c.wrapping_add(1);
 
         // Update flags
         if self.c==0 {
@@ -7068,13 +8226,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.c & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x97(&mut self) {
@@ -7101,7 +8264,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x96(&mut self) {
@@ -7121,26 +8284,29 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
 
         // Update flags
         if self.a==hl {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (hl & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<hl {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= hl;
+        self.a = self.a.wrapping_sub(hl);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x95(&mut self) {
@@ -7165,19 +8331,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.l & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.l {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.l;
+        self.a = self.a.wrapping_sub(self.l);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x94(&mut self) {
@@ -7202,19 +8371,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.h & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.h {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.h;
+        self.a = self.a.wrapping_sub(self.h);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x93(&mut self) {
@@ -7239,19 +8411,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.e & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.e {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.e;
+        self.a = self.a.wrapping_sub(self.e);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x92(&mut self) {
@@ -7276,19 +8451,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.d & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.d {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.d;
+        self.a = self.a.wrapping_sub(self.d);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x91(&mut self) {
@@ -7313,19 +8491,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.c & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.c {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.c;
+        self.a = self.a.wrapping_sub(self.c);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SUB_0x90(&mut self) {
@@ -7350,19 +8531,22 @@ impl Cpu {
             self.f |= Flag::Zero as u8;
         }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.b & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<self.b {
             self.f |= Flag::Carry as u8;
         }
 
         // Update register a
-        self.a -= self.b;
+        self.a = self.a.wrapping_sub(self.b);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x99(&mut self) {
@@ -7379,18 +8563,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts c and the carry flag from a.
 
-        let carry: u8 = (self.c & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.c+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.c & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.c as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.c + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.c).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -7403,7 +8590,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SBC_0x98(&mut self) {
@@ -7420,18 +8607,21 @@ impl Cpu {
         //! - Description
         //!   Subtracts b and the carry flag from a.
 
-        let carry: u8 = (self.b & (Flag::Carry as u8)) >> 7;
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
         // Reset flags
         self.f = Flag::None as u8;
 
-        // Update flags
-        if self.a<self.b+carry {
+        // Check for nibble and byte borrows
+        if ((self.a & 0x0F) as u16) < ((self.b & 0x0F) as u16) + carry as u16 {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.a as u16) < self.b as u16 + carry as u16 {
             self.f |= Flag::Carry as u8;
         }
 
-        // Perform the sub
-        self.a -= self.b + carry;
+        // Perform the SBC
+        self.a = self.a.wrapping_sub(self.b).wrapping_sub(carry);
 
         // Update flags
         if self.a==0 {
@@ -7444,7 +8634,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0xE2(&mut self) {
@@ -7461,14 +8651,14 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        self.mmu.write8(self.c as u16 + 0xFF00u16, self.a);
+        self.write8(self.c as u16 + 0xFF00u16, self.a);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_POP_0xE1(&mut self) {
@@ -7488,17 +8678,17 @@ impl Cpu {
         //!   and sp is incremented again.
 
         // Pop HL from stack
-        self.l = self.mmu.read8(self.sp);
-        self.sp += 1;
-        self.h = self.mmu.read8(self.sp);
-        self.sp += 1;
+        self.l = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
+        self.h = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LDH_0xE0(&mut self) {
@@ -7515,15 +8705,15 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        let a8: u16 = self.mmu.read8(self.pc + 1) as u16 + 0xFF00u16;
-        self.mmu.write8(a8, self.a);
+        let a8: u16 = self.read8(self.pc.wrapping_add(1)) as u16 + 0xFF00u16;
+        self.write8(a8, self.a);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB09(&mut self) {
@@ -7541,18 +8731,28 @@ impl Cpu {
         //!   The contents of c are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.c & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate c right, bit 0 wraps around to bit 7
+        self.c = (self.c >> 1) | (self.c << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB08(&mut self) {
@@ -7570,18 +8770,28 @@ impl Cpu {
         //!   The contents of b are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.b & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate b right, bit 0 wraps around to bit 7
+        self.b = (self.b >> 1) | (self.b << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_PUSH_0xE5(&mut self) {
@@ -7600,18 +8810,21 @@ impl Cpu {
         //!   to by sp. sp is decremented again and l is stored into the memory
         //!   location pointed to by sp.
 
+        // Internal delay
+        self.tick();
+
         // Push HL on the stack
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.h);
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.l);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.h);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.l);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RLC_0xCB05(&mut self) {
@@ -7629,18 +8842,28 @@ impl Cpu {
         //!   The contents of l are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.l & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate l left, bit 7 wraps around to bit 0
+        self.l = (self.l << 1) | (self.l >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB04(&mut self) {
@@ -7658,18 +8881,28 @@ impl Cpu {
         //!   The contents of h are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
-
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.h & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate h left, bit 7 wraps around to bit 0
+        self.h = (self.h << 1) | (self.h >> 7);
+
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB07(&mut self) {
@@ -7687,18 +8920,28 @@ impl Cpu {
         //!   The contents of a are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.a & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a left, bit 7 wraps around to bit 0
+        self.a = (self.a << 1) | (self.a >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB06(&mut self) {
@@ -7716,18 +8959,32 @@ impl Cpu {
         //!   The contents of (hl) are rotated left one bit position. The
         //!   contents of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if phl & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate (hl) left, bit 7 wraps around to bit 0
+        phl = (phl << 1) | (phl >> 7);
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB01(&mut self) {
@@ -7745,18 +9002,28 @@ impl Cpu {
         //!   The contents of c are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.c & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate c left, bit 7 wraps around to bit 0
+        self.c = (self.c << 1) | (self.c >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB00(&mut self) {
@@ -7774,18 +9041,28 @@ impl Cpu {
         //!   The contents of b are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.b & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate b left, bit 7 wraps around to bit 0
+        self.b = (self.b << 1) | (self.b >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB03(&mut self) {
@@ -7803,18 +9080,28 @@ impl Cpu {
         //!   The contents of e are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.e & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate e left, bit 7 wraps around to bit 0
+        self.e = (self.e << 1) | (self.e >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RLC_0xCB02(&mut self) {
@@ -7832,18 +9119,28 @@ impl Cpu {
         //!   The contents of d are rotated left one bit position. The contents
         //!   of bit 7 are copied to the carry flag and bit 0.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.d & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate d left, bit 7 wraps around to bit 0
+        self.d = (self.d << 1) | (self.d >> 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE5(&mut self) {
@@ -7867,7 +9164,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0xEA(&mut self) {
@@ -7884,15 +9181,15 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        self.mmu.write8(a16, self.a);
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
+        self.write8(a16, self.a);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_RST_0xEF(&mut self) {
@@ -7910,9 +9207,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 28h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x28;
@@ -7939,7 +9239,7 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.a ^= d8;
 
         // Update flags
@@ -7952,7 +9252,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0E(&mut self) {
@@ -7970,18 +9270,32 @@ impl Cpu {
         //!   The contents of (hl) are rotated right one bit position. The
         //!   contents of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if phl & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate (hl) right, bit 0 wraps around to bit 7
+        phl = (phl >> 1) | (phl << 7);
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0D(&mut self) {
@@ -7999,18 +9313,28 @@ impl Cpu {
         //!   The contents of l are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.l & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate l right, bit 0 wraps around to bit 7
+        self.l = (self.l >> 1) | (self.l << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0F(&mut self) {
@@ -8028,18 +9352,28 @@ impl Cpu {
         //!   The contents of a are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a right, bit 0 wraps around to bit 7
+        self.a = (self.a >> 1) | (self.a << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0A(&mut self) {
@@ -8057,18 +9391,28 @@ impl Cpu {
         //!   The contents of d are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.d & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate d right, bit 0 wraps around to bit 7
+        self.d = (self.d >> 1) | (self.d << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0C(&mut self) {
@@ -8086,18 +9430,28 @@ impl Cpu {
         //!   The contents of h are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.h & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate h right, bit 0 wraps around to bit 7
+        self.h = (self.h >> 1) | (self.h << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRC_0xCB0B(&mut self) {
@@ -8115,18 +9469,28 @@ impl Cpu {
         //!   The contents of e are rotated right one bit position. The contents
         //!   of bit 0 are copied to the carry flag and bit 7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.e & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate e right, bit 0 wraps around to bit 7
+        self.e = (self.e >> 1) | (self.e << 7);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x40(&mut self) {
@@ -8148,7 +9512,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x41(&mut self) {
@@ -8172,7 +9536,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x42(&mut self) {
@@ -8196,7 +9560,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x43(&mut self) {
@@ -8220,7 +9584,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x44(&mut self) {
@@ -8244,7 +9608,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x45(&mut self) {
@@ -8268,7 +9632,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x46(&mut self) {
@@ -8285,7 +9649,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into b.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.b = hl;
 
         // Update clocks
@@ -8293,7 +9657,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x47(&mut self) {
@@ -8317,7 +9681,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x48(&mut self) {
@@ -8341,7 +9705,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x49(&mut self) {
@@ -8363,7 +9727,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4A(&mut self) {
@@ -8387,7 +9751,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4B(&mut self) {
@@ -8411,7 +9775,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4C(&mut self) {
@@ -8435,7 +9799,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4D(&mut self) {
@@ -8459,7 +9823,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4E(&mut self) {
@@ -8476,7 +9840,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into c.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.c = hl;
 
         // Update clocks
@@ -8484,7 +9848,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x4F(&mut self) {
@@ -8508,7 +9872,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_BIT_0xCB4A(&mut self) {
@@ -8525,9 +9889,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -8536,7 +9903,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB4C(&mut self) {
@@ -8553,9 +9920,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -8564,7 +9934,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB4B(&mut self) {
@@ -8581,9 +9951,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -8592,7 +9965,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB4E(&mut self) {
@@ -8600,7 +9973,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB4E`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -8609,18 +9982,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB4D(&mut self) {
@@ -8637,9 +10016,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -8648,7 +10030,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB4F(&mut self) {
@@ -8665,9 +10047,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -8676,7 +10061,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBF(&mut self) {
@@ -8693,16 +10078,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBE(&mut self) {
@@ -8719,16 +10102,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b01111111;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBD(&mut self) {
@@ -8745,16 +10129,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBC(&mut self) {
@@ -8771,16 +10153,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBB(&mut self) {
@@ -8797,16 +10177,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBBA(&mut self) {
@@ -8823,16 +10201,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_CP_0xFE(&mut self) {
@@ -8853,13 +10229,16 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        let a: u8 = self.a - d8;
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
+        let a: u8 = self.a.wrapping_sub(d8);
 
         // Update flags
         if a==0 {
             self.f |= Flag::Zero as u8;
         }
+        if (self.a & 0x0F) < (d8 & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a<d8 {
             self.f |= Flag::Carry as u8;
         }
@@ -8870,7 +10249,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_CP_0xBB(&mut self) {
@@ -8888,17 +10267,27 @@ impl Cpu {
         //!   Subtracts e from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.e {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.e & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.e {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xBC(&mut self) {
@@ -8916,17 +10305,27 @@ impl Cpu {
         //!   Subtracts h from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.h {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.h & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.h {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xBA(&mut self) {
@@ -8944,17 +10343,27 @@ impl Cpu {
         //!   Subtracts d from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.d {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.d & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.d {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xBF(&mut self) {
@@ -8972,17 +10381,27 @@ impl Cpu {
         //!   Subtracts a from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.a {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.a & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.a {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xBD(&mut self) {
@@ -9000,17 +10419,27 @@ impl Cpu {
         //!   Subtracts l from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.l {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.l & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.l {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xBE(&mut self) {
@@ -9028,17 +10457,29 @@ impl Cpu {
         //!   Subtracts (hl) from a and affects flags according to the result. a
         //!   is not modified.
 
-        unimplemented!();
+        let phl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
+
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==phl {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (phl & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<phl {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xB8(&mut self) {
@@ -9056,17 +10497,27 @@ impl Cpu {
         //!   Subtracts b from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.b {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
-
-        // Update clocks
+        if (self.a & 0x0F) < (self.b & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.b {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CP_0xB9(&mut self) {
@@ -9084,17 +10535,27 @@ impl Cpu {
         //!   Subtracts c from a and affects flags according to the result. a is
         //!   not modified.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
 
         // Update flags
+        if self.a==self.c {
+            self.f |= Flag::Zero as u8;
+        }
         self.f |= Flag::Operation as u8;
+        if (self.a & 0x0F) < (self.c & 0x0F) {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if self.a<self.c {
+            self.f |= Flag::Carry as u8;
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB2(&mut self) {
@@ -9126,7 +10587,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB3(&mut self) {
@@ -9158,7 +10619,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB0(&mut self) {
@@ -9190,7 +10651,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB1(&mut self) {
@@ -9222,7 +10683,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB6(&mut self) {
@@ -9242,7 +10703,7 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.a |= hl;
 
         // Update flags
@@ -9255,7 +10716,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB7(&mut self) {
@@ -9283,7 +10744,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB4(&mut self) {
@@ -9315,7 +10776,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xB5(&mut self) {
@@ -9347,7 +10808,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RES_0xCBB7(&mut self) {
@@ -9364,16 +10825,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB6(&mut self) {
@@ -9390,16 +10849,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b10111111;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB5(&mut self) {
@@ -9416,16 +10876,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB4(&mut self) {
@@ -9442,16 +10900,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB3(&mut self) {
@@ -9468,16 +10924,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB2(&mut self) {
@@ -9494,16 +10948,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB1(&mut self) {
@@ -9520,16 +10972,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB0(&mut self) {
@@ -9546,16 +10996,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 6 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b10111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_EI_0xFB(&mut self) {
@@ -9574,16 +11022,14 @@ impl Cpu {
         //!   to occur. An interrupt will not occur until after the immediatedly
         //!   following instruction.
 
-        unimplemented!();
-
-        // Update flags
+        self.ime_pending = true;
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RES_0xCBB9(&mut self) {
@@ -9600,16 +11046,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBB8(&mut self) {
@@ -9626,16 +11070,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 7 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b01111111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB41(&mut self) {
@@ -9652,9 +11094,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9663,7 +11108,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB40(&mut self) {
@@ -9680,9 +11125,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9691,7 +11139,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB43(&mut self) {
@@ -9708,9 +11156,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9719,7 +11170,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB42(&mut self) {
@@ -9736,9 +11187,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9747,7 +11201,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB45(&mut self) {
@@ -9764,9 +11218,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9775,7 +11232,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB44(&mut self) {
@@ -9792,9 +11249,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9803,7 +11263,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB47(&mut self) {
@@ -9820,9 +11280,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9831,7 +11294,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB46(&mut self) {
@@ -9839,7 +11302,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB46`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -9848,18 +11311,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 0 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b00000001 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB49(&mut self) {
@@ -9876,9 +11345,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9887,7 +11359,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB48(&mut self) {
@@ -9904,9 +11376,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 1 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b00000010 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -9915,7 +11390,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RRA_0x1F(&mut self) {
@@ -9934,19 +11409,25 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 7.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
-        // Update flags
-        self.f &= !(Flag::Zero as u8);
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a right, previous carry goes to bit 7
+        self.a = (self.a >> 1) | (carry << 7);
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x1E(&mut self) {
@@ -9963,7 +11444,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into e.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.e = d8;
 
         // Update clocks
@@ -9971,7 +11452,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DEC_0x1D(&mut self) {
@@ -9988,7 +11469,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from e.
 
-        self.e -= 1;
+        self.e = self.e.wrapping_sub(1);
 
         // Update flags
         if self.e==0 {
@@ -9997,13 +11478,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.e & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x1C(&mut self) {
@@ -10020,7 +11506,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to e.
 
-        self.e += 1;
+        self.e = self.e.wrapping_add(1);
 
         // Update flags
         if self.e==0 {
@@ -10029,13 +11515,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.e & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_DEC_0x1B(&mut self) {
@@ -10053,16 +11544,19 @@ impl Cpu {
         //!   Subtracts one from de.
 
         let mut de: u16 = ((self.d as u16) << 8) + self.e as u16;
-        de -= 1;
+        de = de.wrapping_sub(1);
         self.d = (de >> 8) as u8;
         self.e = de as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x1A(&mut self) {
@@ -10080,14 +11574,14 @@ impl Cpu {
         //!   Loads the value pointed to by de into a.
 
         let de: u16 = ((self.d as u16) << 8) + self.e as u16;
-        self.a = self.mmu.read8(de);
+        self.a = self.read8(de);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_OR_0xF6(&mut self) {
@@ -10107,7 +11601,7 @@ impl Cpu {
         // Reset flags
         self.f = Flag::None as u8;
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.a |= d8;
 
         // Update flags
@@ -10120,7 +11614,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RST_0xF7(&mut self) {
@@ -10138,9 +11632,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 30h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x30;
@@ -10171,7 +11668,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_PUSH_0xF5(&mut self) {
@@ -10190,18 +11687,21 @@ impl Cpu {
         //!   to by sp. sp is decremented again and f is stored into the memory
         //!   location pointed to by sp.
 
+        // Internal delay
+        self.tick();
+
         // Push AF on the stack
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.a);
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.f);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.a);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.f);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SWAP_0xCB34(&mut self) {
@@ -10216,22 +11716,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of h are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of h are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
 
-        unimplemented!();
+
+        // Swap upper and lower nibbles of h
+        self.h = (self.h >> 4) | (self.h << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE0(&mut self) {
@@ -10255,7 +11759,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DI_0xF3(&mut self) {
@@ -10273,16 +11777,15 @@ impl Cpu {
         //!   Resets both interrupt flip-flops, thus prenting maskable
         //!   interrupts from triggering.
 
-        unimplemented!();
-
-        // Update flags
+        self.ime = false;
+        self.ime_pending = false;
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SET_0xCBE1(&mut self) {
@@ -10306,7 +11809,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LDH_0xF0(&mut self) {
@@ -10323,15 +11826,15 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        let a8: u16 = self.mmu.read8(self.pc + 1) as u16 + 0xFF00u16;
-        self.a = self.mmu.read8(a8);
+        let a8: u16 = self.read8(self.pc.wrapping_add(1)) as u16 + 0xFF00u16;
+        self.a = self.read8(a8);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE6(&mut self) {
@@ -10348,16 +11851,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 4 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00010000;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_POP_0xF1(&mut self) {
@@ -10376,26 +11879,18 @@ impl Cpu {
         //!   incremented. The memory location pointed to by sp is stored into a
         //!   and sp is incremented again.
 
-        // Reset flags
-        self.f = Flag::None as u8;
-
-        // Pop AF from stack
-        self.f = self.mmu.read8(self.sp);
-        self.sp += 1;
-        self.a = self.mmu.read8(self.sp);
-        self.sp += 1;
-
-        // Update flags
-        if self.sp==0 {
-            self.f = Flag::Carry as u8 | Flag::Zero as u8;
-        }
+        // Pop AF from stack, the low nibble of f always reading as 0
+        self.f = self.read8(self.sp) & 0xF0;
+        self.sp = self.sp.wrapping_add(1);
+        self.a = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SET_0xCBE7(&mut self) {
@@ -10419,7 +11914,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_ADD_0x19(&mut self) {
@@ -10439,24 +11934,32 @@ impl Cpu {
         // Update registers h and l
         let lhs: u16 = ((self.h as u16) << 8) + self.l as u16;
         let rhs: u16 = ((self.d as u16) << 8) + self.e as u16;
-        let res: u16 = rhs + lhs;
+        let res: u16 = rhs.wrapping_add(lhs);
         self.h = (res >> 8) as u8;
         self.l = res as u8;
 
         // Update flags
         self.f &= !(Flag::Operation as u8);
+        if (lhs & 0x0FFF) + (rhs & 0x0FFF) > 0x0FFF {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
         if lhs > (u16::MAX - rhs) {
             self.f |= Flag::Carry as u8;
         } else {
             self.f &= !(Flag::Carry as u8);
         }
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_JR_0x18(&mut self) {
@@ -10472,15 +11975,14 @@ impl Cpu {
         //!   - `C`:  Preserved
         //! - Description
         //!   The signed value * is added to pc. The jump is measured from the
-        //!   start of the instruction opcode.
+        //!   start of the next instruction.
 
         // Update program counter
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        if r8 < 0 {
-            self.pc -= -r8 as u16;
-        } else {
-            self.pc += r8 as u16;
-        }
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
+        self.pc = self.pc.wrapping_add(2).wrapping_add(r8 as u16);
+
+        // Internal delay
+        self.tick();
 
         // Update clocks
         self.m += 3;
@@ -10503,19 +12005,25 @@ impl Cpu {
         //!   of bit 7 are copied to the carry flag and the previous contents of
         //!   the carry flag are copied to bit 0.
 
-        unimplemented!();
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
 
-        // Update flags
-        self.f &= !(Flag::Zero as u8);
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 7 goes to the carry flag
+        if self.a & 0b10000000 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Rotate a left, previous carry goes to bit 0
+        self.a = (self.a << 1) | carry;
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x16(&mut self) {
@@ -10532,7 +12040,7 @@ impl Cpu {
         //! - Description
         //!   Loads * into d.
 
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
         self.d = d8;
 
         // Update clocks
@@ -10540,7 +12048,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_DEC_0x15(&mut self) {
@@ -10557,7 +12065,7 @@ impl Cpu {
         //! - Description
         //!   Subtracts one from d.
 
-        self.d -= 1;
+        self.d = self.d.wrapping_sub(1);
 
         // Update flags
         if self.d==0 {
@@ -10566,13 +12074,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f |= Flag::Operation as u8;
+        if self.d & 0x0F == 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x14(&mut self) {
@@ -10589,7 +12102,7 @@ impl Cpu {
         //! - Description
         //!   Adds one to d.
 
-        self.d += 1;
+        self.d = self.d.wrapping_add(1);
 
         // Update flags
         if self.d==0 {
@@ -10598,13 +12111,18 @@ impl Cpu {
             self.f &= !(Flag::Zero as u8);
         }
         self.f &= !(Flag::Operation as u8);
+        if self.d & 0x0F == 0 {
+            self.f |= Flag::HalfCarry as u8;
+        } else {
+            self.f &= !(Flag::HalfCarry as u8);
+        }
 
         // Update clocks
         self.m += 1;
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_INC_0x13(&mut self) {
@@ -10622,16 +12140,19 @@ impl Cpu {
         //!   Adds one to de.
 
         let mut de: u16 = ((self.d as u16) << 8) + self.e as u16;
-        de += 1;
+        de = de.wrapping_add(1);
         self.d = (de >> 8) as u8;
         self.e = de as u8;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x12(&mut self) {
@@ -10649,14 +12170,14 @@ impl Cpu {
         //!   Stores a into the memory location pointed to by de.
 
         let de: u16 = ((self.d as u16) << 8) + self.e as u16;
-        self.mmu.write8(de, self.a);
+        self.write8(de, self.a);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x11(&mut self) {
@@ -10673,7 +12194,7 @@ impl Cpu {
         //! - Description
         //!   Loads ** into de.
 
-        let d16: u16 = self.mmu.read16(self.pc + 1);
+        let d16: u16 = self.read16(self.pc.wrapping_add(1));
         self.d = (d16 >> 8) as u8;
         self.e = d16 as u8;
 
@@ -10682,7 +12203,7 @@ impl Cpu {
         self.t += 12;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_STOP_0x10(&mut self) {
@@ -10707,7 +12228,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SET_0xCBFC(&mut self) {
@@ -10731,7 +12252,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBFB(&mut self) {
@@ -10755,7 +12276,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBFA(&mut self) {
@@ -10779,7 +12300,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBFF(&mut self) {
@@ -10803,7 +12324,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBFE(&mut self) {
@@ -10820,16 +12341,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 7 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b10000000;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBFD(&mut self) {
@@ -10853,7 +12374,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RST_0xFF(&mut self) {
@@ -10871,9 +12392,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 38h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x38;
@@ -10899,18 +12423,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.d & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift d right, bit 7 is reset
+        self.d >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB3B(&mut self) {
@@ -10929,18 +12463,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.e & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift e right, bit 7 is reset
+        self.e >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB3C(&mut self) {
@@ -10959,18 +12503,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.h & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift h right, bit 7 is reset
+        self.h >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.h==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB3D(&mut self) {
@@ -10989,18 +12543,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.l & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift l right, bit 7 is reset
+        self.l >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB3E(&mut self) {
@@ -11019,18 +12583,32 @@ impl Cpu {
         //!   contents of bit 0 are copied to the carry flag and a zero is put
         //!   into bit 7.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if phl & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift (hl) right, bit 7 is reset
+        phl >>= 1;
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB3F(&mut self) {
@@ -11049,18 +12627,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.a & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift a right, bit 7 is reset
+        self.a >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0xFA(&mut self) {
@@ -11077,15 +12665,15 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        self.a = self.mmu.read8(a16);
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
+        self.a = self.read8(a16);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 3;
+        self.pc = self.pc.wrapping_add(3);
     }
 
     pub fn instr_SRL_0xCB38(&mut self) {
@@ -11104,18 +12692,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.b & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift b right, bit 7 is reset
+        self.b >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SRL_0xCB39(&mut self) {
@@ -11134,18 +12732,28 @@ impl Cpu {
         //!   of bit 0 are copied to the carry flag and a zero is put into bit
         //!   7.
 
-        unimplemented!();
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Bit 0 goes to the carry flag
+        if self.c & 0b00000001 > 0 {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Shift c right, bit 7 is reset
+        self.c >>= 1;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0xF8(&mut self) {
@@ -11160,28 +12768,32 @@ impl Cpu {
         //!   - `H`:  Set if appropriate
         //!   - `C`:  Set if appropriate
         //! - Description
-        //!   If condition cc is true, the top stack entry is popped into pc.
+        //!   Loads the sum of sp and signed offset r8 into hl.
 
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        let mut spr8: u16 = self.sp;
-        if r8 < 0 {
-            spr8 -= -r8 as u16;
-        } else {
-            spr8 += r8 as u16;
-        }
+        let r8: i8 = self.read8(self.pc.wrapping_add(1)) as i8;
+        let spr8: u16 = self.sp.wrapping_add(r8 as u16);
         self.h = (spr8 >> 8) as u8;
         self.l = spr8 as u8;
 
-        // Update flags
-        self.f &= !(Flag::Zero as u8);
-        self.f &= !(Flag::Operation as u8);
+        // Update flags, from the carries of the unsigned addition of the
+        // offset to the low byte of sp
+        self.f = Flag::None as u8;
+        if (self.sp & 0x000F) + ((r8 as u8 as u16) & 0x000F) > 0x000F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if (self.sp & 0x00FF) + (r8 as u8 as u16) > 0x00FF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Internal delay
+        self.tick();
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0xF9(&mut self) {
@@ -11200,12 +12812,15 @@ impl Cpu {
 
         self.sp = ((self.h as u16) << 8) + self.l as u16;
 
+        // Internal delay
+        self.tick();
+
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SWAP_0xCB30(&mut self) {
@@ -11220,22 +12835,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of b are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of b are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
 
-        unimplemented!();
+        // Swap upper and lower nibbles of b
+        self.b = (self.b >> 4) | (self.b << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.b==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SWAP_0xCB31(&mut self) {
@@ -11250,22 +12869,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of c are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of c are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
 
-        unimplemented!();
+
+        // Swap upper and lower nibbles of c
+        self.c = (self.c >> 4) | (self.c << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.c==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SWAP_0xCB32(&mut self) {
@@ -11280,22 +12903,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of d are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of d are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
 
-        unimplemented!();
+        // Swap upper and lower nibbles of d
+        self.d = (self.d >> 4) | (self.d << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.d==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SWAP_0xCB33(&mut self) {
@@ -11310,22 +12937,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of e are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of e are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
 
-        unimplemented!();
+        // Swap upper and lower nibbles of e
+        self.e = (self.e >> 4) | (self.e << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.e==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0xF2(&mut self) {
@@ -11342,14 +12973,14 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        self.a = self.mmu.read8(self.c as u16 + 0xFF00u16);
+        self.a = self.read8(self.c as u16 + 0xFF00u16);
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SWAP_0xCB35(&mut self) {
@@ -11364,22 +12995,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of l are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of l are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
 
-        unimplemented!();
+
+        // Swap upper and lower nibbles of l
+        self.l = (self.l >> 4) | (self.l << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.l==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SWAP_0xCB36(&mut self) {
@@ -11394,23 +13029,30 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of (hl) are shifted left one bit position. The
-        //!   contents of bit 7 are put into the carry flag and a one is put
-        //!   into bit 0.
+        //!   The upper and lower nibbles of (hl) are swapped.
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+
+        // Swap upper and lower nibbles of (hl)
+        phl = (phl >> 4) | (phl << 4);
+        self.write8(hl, phl);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if phl==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SWAP_0xCB37(&mut self) {
@@ -11425,22 +13067,26 @@ impl Cpu {
         //!   - `H`:  Force unset (0)
         //!   - `C`:  Force unset (0)
         //! - Description
-        //!   The contents of a are shifted left one bit position. The contents
-        //!   of bit 7 are put into the carry flag and a one is put into bit 0.
+        //!   The upper and lower nibbles of a are swapped.
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
 
-        unimplemented!();
+        // Swap upper and lower nibbles of a
+        self.a = (self.a >> 4) | (self.a << 4);
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
-        self.f &= !(Flag::HalfCarry as u8);
-        self.f &= !(Flag::Carry as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF9(&mut self) {
@@ -11464,7 +13110,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF8(&mut self) {
@@ -11488,7 +13134,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF3(&mut self) {
@@ -11512,7 +13158,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF2(&mut self) {
@@ -11536,7 +13182,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF1(&mut self) {
@@ -11560,7 +13206,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF0(&mut self) {
@@ -11584,7 +13230,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF7(&mut self) {
@@ -11608,7 +13254,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF6(&mut self) {
@@ -11625,16 +13271,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 6 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b01000000;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF5(&mut self) {
@@ -11658,7 +13304,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBF4(&mut self) {
@@ -11682,7 +13328,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x5C(&mut self) {
@@ -11706,7 +13352,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x5B(&mut self) {
@@ -11728,7 +13374,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x5A(&mut self) {
@@ -11752,7 +13398,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x5F(&mut self) {
@@ -11776,7 +13422,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x5E(&mut self) {
@@ -11793,7 +13439,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into e.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.e = hl;
 
         // Update clocks
@@ -11801,7 +13447,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x5D(&mut self) {
@@ -11825,7 +13471,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RES_0xCBA8(&mut self) {
@@ -11842,16 +13488,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA9(&mut self) {
@@ -11868,16 +13512,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA6(&mut self) {
@@ -11894,16 +13536,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11101111;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA7(&mut self) {
@@ -11920,16 +13563,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA4(&mut self) {
@@ -11946,16 +13587,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA5(&mut self) {
@@ -11972,16 +13611,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA2(&mut self) {
@@ -11998,16 +13635,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA3(&mut self) {
@@ -12024,16 +13659,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA0(&mut self) {
@@ -12050,16 +13683,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBA1(&mut self) {
@@ -12076,16 +13707,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 4 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11101111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAF(&mut self) {
@@ -12102,16 +13731,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAD(&mut self) {
@@ -12128,16 +13755,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAE(&mut self) {
@@ -12154,16 +13779,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11011111;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAB(&mut self) {
@@ -12180,16 +13806,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAC(&mut self) {
@@ -12206,16 +13830,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCBAA(&mut self) {
@@ -12232,16 +13854,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 5 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11011111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_LD_0x53(&mut self) {
@@ -12265,7 +13885,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x52(&mut self) {
@@ -12287,7 +13907,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x51(&mut self) {
@@ -12311,7 +13931,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x50(&mut self) {
@@ -12335,7 +13955,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x57(&mut self) {
@@ -12359,7 +13979,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x56(&mut self) {
@@ -12376,7 +13996,7 @@ impl Cpu {
         //! - Description
         //!   The contents of (hl) are loaded into d.
 
-        let hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         self.d = hl;
 
         // Update clocks
@@ -12384,7 +14004,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x55(&mut self) {
@@ -12408,7 +14028,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x54(&mut self) {
@@ -12432,7 +14052,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x59(&mut self) {
@@ -12456,7 +14076,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_LD_0x58(&mut self) {
@@ -12480,7 +14100,7 @@ impl Cpu {
         self.t += 4;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_SET_0xCBEB(&mut self) {
@@ -12504,7 +14124,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBEC(&mut self) {
@@ -12528,7 +14148,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBEA(&mut self) {
@@ -12552,7 +14172,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBEF(&mut self) {
@@ -12576,7 +14196,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBED(&mut self) {
@@ -12600,7 +14220,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBEE(&mut self) {
@@ -12617,16 +14237,16 @@ impl Cpu {
         //! - Description
         //!   Sets bit 5 of (hl).
 
-        let mut hl: u8 = self.mmu.read8(((self.h as u16) << 8) + self.l as u16);
+        let mut hl: u8 = self.read8(((self.h as u16) << 8) + self.l as u16);
         hl |= 0b00100000;
-        self.mmu.write8(((self.h as u16) << 8) + self.l as u16, hl);
+        self.write8(((self.h as u16) << 8) + self.l as u16, hl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_PUSH_0xC5(&mut self) {
@@ -12645,18 +14265,21 @@ impl Cpu {
         //!   to by sp. sp is decremented again and c is stored into the memory
         //!   location pointed to by sp.
 
+        // Internal delay
+        self.tick();
+
         // Push BC on the stack
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.b);
-        self.sp -= 1;
-        self.mmu.write8(self.sp, self.c);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.b);
+        self.sp = self.sp.wrapping_sub(1);
+        self.write8(self.sp, self.c);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_CALL_0xC4(&mut self) {
@@ -12674,14 +14297,28 @@ impl Cpu {
         //!   If condition cc is true, the current pc value plus three is pushed
         //!   onto the stack, then is loaded with **.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Zero as u8) == 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Push next instruction address on the stack
+            self.sp = self.sp.wrapping_sub(2);
+            self.write16(self.sp, self.pc.wrapping_add(3));
+
+            // Update clocks
+            self.m += 6;
+            self.t += 24;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_RST_0xC7(&mut self) {
@@ -12699,9 +14336,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 00h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x00;
@@ -12729,15 +14369,18 @@ impl Cpu {
         self.f = Flag::None as u8;
 
         // Retrieve immediate value
-        let d8: u8 = self.mmu.read8(self.pc + 1);
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
 
-        // Check if there will be an overflow
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (d8 & 0x0F) > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
         if self.a > (u8::MAX - d8) {
             self.f |= Flag::Carry as u8;
         }
 
         // Perform the ADD
-        self.a += d8;
+        self.a = self.a.wrapping_add(d8);
 
         // Check for zero
         if self.a == 0 {
@@ -12749,7 +14392,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_POP_0xC1(&mut self) {
@@ -12769,17 +14412,17 @@ impl Cpu {
         //!   and sp is incremented again.
 
         // Pop BC from stack
-        self.c = self.mmu.read8(self.sp);
-        self.sp += 1;
-        self.b = self.mmu.read8(self.sp);
-        self.sp += 1;
+        self.c = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
+        self.b = self.read8(self.sp);
+        self.sp = self.sp.wrapping_add(1);
 
         // Update clocks
         self.m += 3;
         self.t += 12;
 
         // Update program counter
-        self.pc += 1;
+        self.pc = self.pc.wrapping_add(1);
     }
 
     pub fn instr_RET_0xC0(&mut self) {
@@ -12796,16 +14439,28 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        unimplemented!();
+        // Internal delay, to check the condition
+        self.tick();
 
-        // Update flags
+        if self.f & (Flag::Zero as u8) == 0 {
+            // Pop return address from the stack
+            self.pc = self.read16(self.sp);
+            self.sp = self.sp.wrapping_add(2);
 
-        // Update clocks
-        self.m += 2;
-        self.t += 8;
+            // Internal delay, to set pc
+            self.tick();
 
-        // Update program counter
-        self.pc += 1;
+            // Update clocks
+            self.m += 5;
+            self.t += 20;
+        } else {
+            // Update clocks
+            self.m += 2;
+            self.t += 8;
+
+            // Update program counter
+            self.pc = self.pc.wrapping_add(1);
+        }
     }
 
     pub fn instr_JP_0xC3(&mut self) {
@@ -12822,14 +14477,14 @@ impl Cpu {
         //! - Description
         //!   ** is copied to pc.
 
-        self.pc = self.mmu.read16(self.pc + 1);
+        self.pc = self.read16(self.pc.wrapping_add(1));
+
+        // Internal delay
+        self.tick();
 
         // Update clocks
         self.m += 4;
         self.t += 16;
-
-        // Update program counter
-        self.pc += 3;
     }
 
     pub fn instr_JP_0xC2(&mut self) {
@@ -12846,14 +14501,24 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Zero as u8) == 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Update clocks
+            self.m += 4;
+            self.t += 16;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_RET_0xC9(&mut self) {
@@ -12870,16 +14535,16 @@ impl Cpu {
         //! - Description
         //!   The top stack entry is popped into pc.
 
-        unimplemented!();
+        // Pop return address from the stack
+        self.pc = self.read16(self.sp);
+        self.sp = self.sp.wrapping_add(2);
 
-        // Update flags
+        // Internal delay, to set pc
+        self.tick();
 
         // Update clocks
         self.m += 4;
         self.t += 16;
-
-        // Update program counter
-        self.pc += 1;
     }
 
     pub fn instr_RET_0xC8(&mut self) {
@@ -12896,16 +14561,28 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, the top stack entry is popped into pc.
 
-        unimplemented!();
+        // Internal delay, to check the condition
+        self.tick();
 
-        // Update flags
+        if self.f & (Flag::Zero as u8) != 0 {
+            // Pop return address from the stack
+            self.pc = self.read16(self.sp);
+            self.sp = self.sp.wrapping_add(2);
 
-        // Update clocks
-        self.m += 2;
-        self.t += 8;
+            // Internal delay, to set pc
+            self.tick();
 
-        // Update program counter
-        self.pc += 1;
+            // Update clocks
+            self.m += 5;
+            self.t += 20;
+        } else {
+            // Update clocks
+            self.m += 2;
+            self.t += 8;
+
+            // Update program counter
+            self.pc = self.pc.wrapping_add(1);
+        }
     }
 
     pub fn instr_RES_0xCB9F(&mut self) {
@@ -12922,16 +14599,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE3(&mut self) {
@@ -12955,7 +14630,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB9D(&mut self) {
@@ -12972,16 +14647,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB9E(&mut self) {
@@ -12998,16 +14671,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11110111;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB9B(&mut self) {
@@ -13024,16 +14698,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB9C(&mut self) {
@@ -13050,16 +14722,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE4(&mut self) {
@@ -13083,7 +14753,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB9A(&mut self) {
@@ -13100,16 +14770,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE8(&mut self) {
@@ -13133,7 +14801,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBE9(&mut self) {
@@ -13157,7 +14825,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_SET_0xCBC2(&mut self) {
@@ -13181,7 +14849,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB78(&mut self) {
@@ -13198,9 +14866,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13209,7 +14880,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB79(&mut self) {
@@ -13226,9 +14897,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13237,7 +14911,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB74(&mut self) {
@@ -13254,9 +14928,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of h.
 
-        unimplemented!();
-
         // Update flags
+        if self.h & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13265,7 +14942,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB75(&mut self) {
@@ -13282,9 +14959,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13293,7 +14973,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB76(&mut self) {
@@ -13301,7 +14981,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB76`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -13310,18 +14990,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB77(&mut self) {
@@ -13338,9 +15024,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13349,7 +15038,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB70(&mut self) {
@@ -13366,9 +15055,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of b.
 
-        unimplemented!();
-
         // Update flags
+        if self.b & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13377,7 +15069,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB71(&mut self) {
@@ -13394,9 +15086,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of c.
 
-        unimplemented!();
-
         // Update flags
+        if self.c & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13405,7 +15100,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB72(&mut self) {
@@ -13422,9 +15117,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13433,7 +15131,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB73(&mut self) {
@@ -13450,9 +15148,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 6 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b01000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13461,7 +15162,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7D(&mut self) {
@@ -13478,9 +15179,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of l.
 
-        unimplemented!();
-
         // Update flags
+        if self.l & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13489,7 +15193,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7E(&mut self) {
@@ -13497,7 +15201,7 @@ impl Cpu {
         //! - Mnemonic:  `BIT`
         //! - Size:      2 bytes
         //! - Binary:    `0xCB7E`
-        //! - Cycles:    16 cycles
+        //! - Cycles:    12 cycles
         //! - Flags
         //!   - `Z`:  Set if appropriate
         //!   - `N`:  Force unset (0)
@@ -13506,18 +15210,24 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of (hl).
 
-        unimplemented!();
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let phl: u8 = self.read8(hl);
 
         // Update flags
+        if phl & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
         // Update clocks
-        self.m += 4;
-        self.t += 16;
+        self.m += 3;
+        self.t += 12;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7F(&mut self) {
@@ -13534,9 +15244,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of a.
 
-        unimplemented!();
-
         // Update flags
+        if self.a & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13545,7 +15258,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7A(&mut self) {
@@ -13562,9 +15275,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of d.
 
-        unimplemented!();
-
         // Update flags
+        if self.d & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13573,7 +15289,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7B(&mut self) {
@@ -13590,9 +15306,12 @@ impl Cpu {
         //! - Description
         //!   Tests bit 7 of e.
 
-        unimplemented!();
-
         // Update flags
+        if self.e & 0b10000000 == 0 {
+            self.f |= Flag::Zero as u8;
+        } else {
+            self.f &= !(Flag::Zero as u8);
+        }
         self.f &= !(Flag::Operation as u8);
         self.f |= Flag::HalfCarry as u8;
 
@@ -13601,7 +15320,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_BIT_0xCB7C(&mut self) {
@@ -13632,7 +15351,7 @@ impl Cpu {
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB96(&mut self) {
@@ -13649,16 +15368,17 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of (hl).
 
-        unimplemented!();
-
-        // Update flags
+        let hl: u16 = ((self.h as u16) << 8) + self.l as u16;
+        let mut phl: u8 = self.read8(hl);
+        phl &= 0b11111011;
+        self.write8(hl, phl);
 
         // Update clocks
         self.m += 4;
         self.t += 16;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB97(&mut self) {
@@ -13675,16 +15395,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of a.
 
-        unimplemented!();
-
-        // Update flags
+        self.a &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB94(&mut self) {
@@ -13701,16 +15419,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of h.
 
-        unimplemented!();
-
-        // Update flags
+        self.h &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB95(&mut self) {
@@ -13727,16 +15443,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of l.
 
-        unimplemented!();
-
-        // Update flags
+        self.l &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB92(&mut self) {
@@ -13753,16 +15467,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of d.
 
-        unimplemented!();
-
-        // Update flags
+        self.d &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB93(&mut self) {
@@ -13779,16 +15491,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of e.
 
-        unimplemented!();
-
-        // Update flags
+        self.e &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB90(&mut self) {
@@ -13805,16 +15515,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB91(&mut self) {
@@ -13831,16 +15539,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 2 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11111011;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB98(&mut self) {
@@ -13857,16 +15563,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of b.
 
-        unimplemented!();
-
-        // Update flags
+        self.b &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_RES_0xCB99(&mut self) {
@@ -13883,16 +15587,14 @@ impl Cpu {
         //! - Description
         //!   Resets bit 3 of c.
 
-        unimplemented!();
-
-        // Update flags
+        self.c &= 0b11110111;
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_ADC_0xCE(&mut self) {
@@ -13909,17 +15611,35 @@ impl Cpu {
         //! - Description
         //!   Adds * and the carry flag to a.
 
-        unimplemented!();
+        let d8: u8 = self.read8(self.pc.wrapping_add(1));
+        let carry: u8 = (self.f & (Flag::Carry as u8)) >> 4;
+        let res: u16 = self.a as u16 + d8 as u16 + carry as u16;
+
+        // Reset flags
+        self.f = Flag::None as u8;
+
+        // Check for nibble and byte overflows
+        if (self.a & 0x0F) + (d8 & 0x0F) + carry > 0x0F {
+            self.f |= Flag::HalfCarry as u8;
+        }
+        if res > 0xFF {
+            self.f |= Flag::Carry as u8;
+        }
+
+        // Perform the ADC
+        self.a = res as u8;
 
         // Update flags
-        self.f &= !(Flag::Operation as u8);
+        if self.a==0 {
+            self.f |= Flag::Zero as u8;
+        }
 
         // Update clocks
         self.m += 2;
         self.t += 8;
 
         // Update program counter
-        self.pc += 2;
+        self.pc = self.pc.wrapping_add(2);
     }
 
     pub fn instr_CALL_0xCD(&mut self) {
@@ -13937,14 +15657,21 @@ impl Cpu {
         //!   The current pc value plus three is pushed onto the stack, then is
         //!   loaded with **.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
+
+        // Internal delay
+        self.tick();
+
+        // Push next instruction address on the stack
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(3));
+
+        // Update program counter
+        self.pc = a16;
 
         // Update clocks
         self.m += 6;
         self.t += 24;
-
-        // Update program counter
-        self.pc += 3;
     }
 
     pub fn instr_RST_0xCF(&mut self) {
@@ -13962,9 +15689,12 @@ impl Cpu {
         //!   The current pc value plus one is pushed onto the stack, then is
         //!   loaded with 08h.
 
+        // Internal delay
+        self.tick();
+
         // Push next instruction address on the stack
-        self.sp -= 2;
-        self.mmu.write16(self.sp, self.pc + 1);
+        self.sp = self.sp.wrapping_sub(2);
+        self.write16(self.sp, self.pc.wrapping_add(1));
 
         // Update program counter
         self.pc = 0x08;
@@ -13988,14 +15718,24 @@ impl Cpu {
         //! - Description
         //!   If condition cc is true, ** is copied to pc.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Zero as u8) != 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Update clocks
+            self.m += 4;
+            self.t += 16;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_CALL_0xCC(&mut self) {
@@ -14013,14 +15753,28 @@ impl Cpu {
         //!   If condition cc is true, the current pc value plus three is pushed
         //!   onto the stack, then is loaded with **.
 
-        unimplemented!();
+        let a16: u16 = self.read16(self.pc.wrapping_add(1));
 
-        // Update clocks
-        self.m += 3;
-        self.t += 12;
+        if self.f & (Flag::Zero as u8) != 0 {
+            // Internal delay
+            self.tick();
 
-        // Update program counter
-        self.pc += 3;
+            // Push next instruction address on the stack
+            self.sp = self.sp.wrapping_sub(2);
+            self.write16(self.sp, self.pc.wrapping_add(3));
+
+            // Update clocks
+            self.m += 6;
+            self.t += 24;
+            // Update program counter
+            self.pc = a16;
+        } else {
+            // Update clocks
+            self.m += 3;
+            self.t += 12;
+            // Update program counter
+            self.pc = self.pc.wrapping_add(3);
+        }
     }
 
     pub fn instr_PREFIX_0xCB(&mut self) {
@@ -14037,4701 +15791,577 @@ impl Cpu {
         //! - Description
         //!   BITS instruction prefix
 
-        unimplemented!();
-
-        // Update flags
-
-        // Update clocks
-        self.m += 1;
-        self.t += 4;
-
-        // Update program counter
-        self.pc += 1;
-    }
-
-    fn disp_NOP_0x00(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("NOP");
-        (result, 1)
-    }
-
-    fn disp_LD_0x01(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("BC");
-        result.push_str(", ");
-        let d16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", d16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_LD_0x02(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(BC)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_INC_0x03(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("BC");
-        (result, 1)
-    }
-
-    fn disp_INC_0x04(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x05(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x06(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RLCA_0x07(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLCA");
-        (result, 1)
-    }
-
-    fn disp_LD_0x08(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        let pa16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("(0x{:0>4.4X})", pa16).as_slice());
-        result.push_str(", ");
-        result.push_str("SP");
-        (result, 3)
-    }
-
-    fn disp_ADD_0x09(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        result.push_str("BC");
-        (result, 1)
-    }
-
-    fn disp_LD_0x0A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(BC)");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x0B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("BC");
-        (result, 1)
-    }
-
-    fn disp_INC_0x0C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x0D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x0E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RRCA_0x0F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRCA");
-        (result, 1)
-    }
-
-    fn disp_STOP_0x10(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("STOP");
-        result.push_str(" ");
-        result.push_str("0");
-        (result, 1)
-    }
-
-    fn disp_LD_0x11(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("DE");
-        result.push_str(", ");
-        let d16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", d16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_LD_0x12(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(DE)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_INC_0x13(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("DE");
-        (result, 1)
-    }
-
-    fn disp_INC_0x14(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x15(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x16(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RLA_0x17(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLA");
-        (result, 1)
-    }
-
-    fn disp_JR_0x18(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JR");
-        result.push_str(" ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_ADD_0x19(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        result.push_str("DE");
-        (result, 1)
-    }
-
-    fn disp_LD_0x1A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(DE)");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x1B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("DE");
-        (result, 1)
-    }
-
-    fn disp_INC_0x1C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x1D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x1E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RRA_0x1F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRA");
-        (result, 1)
-    }
-
-    fn disp_JR_0x20(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JR");
-        result.push_str(" ");
-        result.push_str("NZ");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_LD_0x21(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        let d16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", d16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_LD_0x22(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL+)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_INC_0x23(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_INC_0x24(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x25(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x26(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_DAA_0x27(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DAA");
-        (result, 1)
-    }
-
-    fn disp_JR_0x28(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JR");
-        result.push_str(" ");
-        result.push_str("Z");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_ADD_0x29(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_LD_0x2A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL+)");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x2B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_INC_0x2C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x2D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x2E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_CPL_0x2F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CPL");
-        (result, 1)
-    }
-
-    fn disp_JR_0x30(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JR");
-        result.push_str(" ");
-        result.push_str("NC");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_LD_0x31(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("SP");
-        result.push_str(", ");
-        let d16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", d16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_LD_0x32(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL-)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_INC_0x33(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("SP");
-        (result, 1)
-    }
-
-    fn disp_INC_0x34(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x35(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x36(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_SCF_0x37(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SCF");
-        (result, 1)
-    }
-
-    fn disp_JR_0x38(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JR");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_ADD_0x39(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        result.push_str("SP");
-        (result, 1)
-    }
-
-    fn disp_LD_0x3A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL-)");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x3B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("SP");
-        (result, 1)
-    }
-
-    fn disp_INC_0x3C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("INC");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_DEC_0x3D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DEC");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x3E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_CCF_0x3F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CCF");
-        (result, 1)
-    }
-
-    fn disp_LD_0x40(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x41(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x42(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x43(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x44(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x45(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x46(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x47(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("B");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x48(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x49(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x4F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x50(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x51(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x52(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x53(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x54(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x55(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x56(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x57(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("D");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x58(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x59(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x5F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("E");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x60(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x61(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x62(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x63(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x64(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x65(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x66(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x67(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("H");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x68(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x69(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x6F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("L");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x70(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x71(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x72(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x73(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x74(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x75(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_HALT_0x76(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("HALT");
-        (result, 1)
-    }
-
-    fn disp_LD_0x77(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_LD_0x78(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_LD_0x79(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0x7F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x80(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x81(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x82(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x83(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x84(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x85(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x86(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_ADD_0x87(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x88(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x89(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_ADC_0x8F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x90(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x91(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x92(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x93(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x94(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x95(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x96(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_SUB_0x97(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x98(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x99(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_SBC_0x9F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_AND_0xA7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xA8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xA9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_XOR_0xAF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_OR_0xB7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_CP_0xB8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 1)
-    }
-
-    fn disp_CP_0xB9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_CP_0xBF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_RET_0xC0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RET");
-        result.push_str(" ");
-        result.push_str("NZ");
-        (result, 1)
-    }
-
-    fn disp_POP_0xC1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("POP");
-        result.push_str(" ");
-        result.push_str("BC");
-        (result, 1)
-    }
-
-    fn disp_JP_0xC2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        result.push_str("NZ");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_JP_0xC3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_CALL_0xC4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CALL");
-        result.push_str(" ");
-        result.push_str("NZ");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_PUSH_0xC5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("PUSH");
-        result.push_str(" ");
-        result.push_str("BC");
-        (result, 1)
-    }
-
-    fn disp_ADD_0xC6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xC7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("00H");
-        (result, 1)
-    }
-
-    fn disp_RET_0xC8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RET");
-        result.push_str(" ");
-        result.push_str("Z");
-        (result, 1)
-    }
-
-    fn disp_RET_0xC9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RET");
-        (result, 1)
-    }
-
-    fn disp_JP_0xCA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        result.push_str("Z");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_PREFIX_0xCB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("PREFIX");
-        result.push_str(" ");
-        result.push_str("CB");
-        (result, 1)
-    }
-
-    fn disp_RLC_0xCB00(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB01(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB02(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB03(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB04(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB05(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB06(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RLC_0xCB07(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RLC");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB08(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB09(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RRC_0xCB0F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RRC");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB10(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB11(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB12(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB13(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB14(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB15(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB16(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RL_0xCB17(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RL");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB18(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB19(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RR_0xCB1F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RR");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB20(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB21(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB22(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB23(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB24(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB25(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB26(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SLA_0xCB27(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SLA");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB28(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB29(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SRA_0xCB2F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRA");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SWAP_0xCB30(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SWAP_0xCB31(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
+        // The CB prefixed handler accounts for the whole instruction, prefix
+        // byte included, so there is nothing to update here.
+        let opcode: u8 = self.mmu.read8(self.pc.wrapping_add(1));
+        self.dispatch_cb(opcode);
+    }
+
+    pub fn instr_ILLEGAL(&mut self) {
+        //! Placeholder for the opcodes that are not part of the
+        //! instruction set: 0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC,
+        //! 0xED, 0xF4, 0xFC and 0xFD. `step` reports them before
+        //! dispatching, so this is never reached.
+        unreachable!();
+    }
+
+    fn dispatch(&mut self, opcode: u8) {
+        //! Run the handler of `opcode`. Matched rather than looked up in
+        //! a table, as statics cannot be generic over the bus.
+        match opcode {
+            0x00 => self.instr_NOP_0x00(),
+            0x01 => self.instr_LD_0x01(),
+            0x02 => self.instr_LD_0x02(),
+            0x03 => self.instr_INC_0x03(),
+            0x04 => self.instr_INC_0x04(),
+            0x05 => self.instr_DEC_0x05(),
+            0x06 => self.instr_LD_0x06(),
+            0x07 => self.instr_RLCA_0x07(),
+            0x08 => self.instr_LD_0x08(),
+            0x09 => self.instr_ADD_0x09(),
+            0x0A => self.instr_LD_0x0A(),
+            0x0B => self.instr_DEC_0x0B(),
+            0x0C => self.instr_INC_0x0C(),
+            0x0D => self.instr_DEC_0x0D(),
+            0x0E => self.instr_LD_0x0E(),
+            0x0F => self.instr_RRCA_0x0F(),
+            0x10 => self.instr_STOP_0x10(),
+            0x11 => self.instr_LD_0x11(),
+            0x12 => self.instr_LD_0x12(),
+            0x13 => self.instr_INC_0x13(),
+            0x14 => self.instr_INC_0x14(),
+            0x15 => self.instr_DEC_0x15(),
+            0x16 => self.instr_LD_0x16(),
+            0x17 => self.instr_RLA_0x17(),
+            0x18 => self.instr_JR_0x18(),
+            0x19 => self.instr_ADD_0x19(),
+            0x1A => self.instr_LD_0x1A(),
+            0x1B => self.instr_DEC_0x1B(),
+            0x1C => self.instr_INC_0x1C(),
+            0x1D => self.instr_DEC_0x1D(),
+            0x1E => self.instr_LD_0x1E(),
+            0x1F => self.instr_RRA_0x1F(),
+            0x20 => self.instr_JR_0x20(),
+            0x21 => self.instr_LD_0x21(),
+            0x22 => self.instr_LD_0x22(),
+            0x23 => self.instr_INC_0x23(),
+            0x24 => self.instr_INC_0x24(),
+            0x25 => self.instr_DEC_0x25(),
+            0x26 => self.instr_LD_0x26(),
+            0x27 => self.instr_DAA_0x27(),
+            0x28 => self.instr_JR_0x28(),
+            0x29 => self.instr_ADD_0x29(),
+            0x2A => self.instr_LD_0x2A(),
+            0x2B => self.instr_DEC_0x2B(),
+            0x2C => self.instr_INC_0x2C(),
+            0x2D => self.instr_DEC_0x2D(),
+            0x2E => self.instr_LD_0x2E(),
+            0x2F => self.instr_CPL_0x2F(),
+            0x30 => self.instr_JR_0x30(),
+            0x31 => self.instr_LD_0x31(),
+            0x32 => self.instr_LD_0x32(),
+            0x33 => self.instr_INC_0x33(),
+            0x34 => self.instr_INC_0x34(),
+            0x35 => self.instr_DEC_0x35(),
+            0x36 => self.instr_LD_0x36(),
+            0x37 => self.instr_SCF_0x37(),
+            0x38 => self.instr_JR_0x38(),
+            0x39 => self.instr_ADD_0x39(),
+            0x3A => self.instr_LD_0x3A(),
+            0x3B => self.instr_DEC_0x3B(),
+            0x3C => self.instr_INC_0x3C(),
+            0x3D => self.instr_DEC_0x3D(),
+            0x3E => self.instr_LD_0x3E(),
+            0x3F => self.instr_CCF_0x3F(),
+            0x40 => self.instr_LD_0x40(),
+            0x41 => self.instr_LD_0x41(),
+            0x42 => self.instr_LD_0x42(),
+            0x43 => self.instr_LD_0x43(),
+            0x44 => self.instr_LD_0x44(),
+            0x45 => self.instr_LD_0x45(),
+            0x46 => self.instr_LD_0x46(),
+            0x47 => self.instr_LD_0x47(),
+            0x48 => self.instr_LD_0x48(),
+            0x49 => self.instr_LD_0x49(),
+            0x4A => self.instr_LD_0x4A(),
+            0x4B => self.instr_LD_0x4B(),
+            0x4C => self.instr_LD_0x4C(),
+            0x4D => self.instr_LD_0x4D(),
+            0x4E => self.instr_LD_0x4E(),
+            0x4F => self.instr_LD_0x4F(),
+            0x50 => self.instr_LD_0x50(),
+            0x51 => self.instr_LD_0x51(),
+            0x52 => self.instr_LD_0x52(),
+            0x53 => self.instr_LD_0x53(),
+            0x54 => self.instr_LD_0x54(),
+            0x55 => self.instr_LD_0x55(),
+            0x56 => self.instr_LD_0x56(),
+            0x57 => self.instr_LD_0x57(),
+            0x58 => self.instr_LD_0x58(),
+            0x59 => self.instr_LD_0x59(),
+            0x5A => self.instr_LD_0x5A(),
+            0x5B => self.instr_LD_0x5B(),
+            0x5C => self.instr_LD_0x5C(),
+            0x5D => self.instr_LD_0x5D(),
+            0x5E => self.instr_LD_0x5E(),
+            0x5F => self.instr_LD_0x5F(),
+            0x60 => self.instr_LD_0x60(),
+            0x61 => self.instr_LD_0x61(),
+            0x62 => self.instr_LD_0x62(),
+            0x63 => self.instr_LD_0x63(),
+            0x64 => self.instr_LD_0x64(),
+            0x65 => self.instr_LD_0x65(),
+            0x66 => self.instr_LD_0x66(),
+            0x67 => self.instr_LD_0x67(),
+            0x68 => self.instr_LD_0x68(),
+            0x69 => self.instr_LD_0x69(),
+            0x6A => self.instr_LD_0x6A(),
+            0x6B => self.instr_LD_0x6B(),
+            0x6C => self.instr_LD_0x6C(),
+            0x6D => self.instr_LD_0x6D(),
+            0x6E => self.instr_LD_0x6E(),
+            0x6F => self.instr_LD_0x6F(),
+            0x70 => self.instr_LD_0x70(),
+            0x71 => self.instr_LD_0x71(),
+            0x72 => self.instr_LD_0x72(),
+            0x73 => self.instr_LD_0x73(),
+            0x74 => self.instr_LD_0x74(),
+            0x75 => self.instr_LD_0x75(),
+            0x76 => self.instr_HALT_0x76(),
+            0x77 => self.instr_LD_0x77(),
+            0x78 => self.instr_LD_0x78(),
+            0x79 => self.instr_LD_0x79(),
+            0x7A => self.instr_LD_0x7A(),
+            0x7B => self.instr_LD_0x7B(),
+            0x7C => self.instr_LD_0x7C(),
+            0x7D => self.instr_LD_0x7D(),
+            0x7E => self.instr_LD_0x7E(),
+            0x7F => self.instr_LD_0x7F(),
+            0x80 => self.instr_ADD_0x80(),
+            0x81 => self.instr_ADD_0x81(),
+            0x82 => self.instr_ADD_0x82(),
+            0x83 => self.instr_ADD_0x83(),
+            0x84 => self.instr_ADD_0x84(),
+            0x85 => self.instr_ADD_0x85(),
+            0x86 => self.instr_ADD_0x86(),
+            0x87 => self.instr_ADD_0x87(),
+            0x88 => self.instr_ADC_0x88(),
+            0x89 => self.instr_ADC_0x89(),
+            0x8A => self.instr_ADC_0x8A(),
+            0x8B => self.instr_ADC_0x8B(),
+            0x8C => self.instr_ADC_0x8C(),
+            0x8D => self.instr_ADC_0x8D(),
+            0x8E => self.instr_ADC_0x8E(),
+            0x8F => self.instr_ADC_0x8F(),
+            0x90 => self.instr_SUB_0x90(),
+            0x91 => self.instr_SUB_0x91(),
+            0x92 => self.instr_SUB_0x92(),
+            0x93 => self.instr_SUB_0x93(),
+            0x94 => self.instr_SUB_0x94(),
+            0x95 => self.instr_SUB_0x95(),
+            0x96 => self.instr_SUB_0x96(),
+            0x97 => self.instr_SUB_0x97(),
+            0x98 => self.instr_SBC_0x98(),
+            0x99 => self.instr_SBC_0x99(),
+            0x9A => self.instr_SBC_0x9A(),
+            0x9B => self.instr_SBC_0x9B(),
+            0x9C => self.instr_SBC_0x9C(),
+            0x9D => self.instr_SBC_0x9D(),
+            0x9E => self.instr_SBC_0x9E(),
+            0x9F => self.instr_SBC_0x9F(),
+            0xA0 => self.instr_AND_0xA0(),
+            0xA1 => self.instr_AND_0xA1(),
+            0xA2 => self.instr_AND_0xA2(),
+            0xA3 => self.instr_AND_0xA3(),
+            0xA4 => self.instr_AND_0xA4(),
+            0xA5 => self.instr_AND_0xA5(),
+            0xA6 => self.instr_AND_0xA6(),
+            0xA7 => self.instr_AND_0xA7(),
+            0xA8 => self.instr_XOR_0xA8(),
+            0xA9 => self.instr_XOR_0xA9(),
+            0xAA => self.instr_XOR_0xAA(),
+            0xAB => self.instr_XOR_0xAB(),
+            0xAC => self.instr_XOR_0xAC(),
+            0xAD => self.instr_XOR_0xAD(),
+            0xAE => self.instr_XOR_0xAE(),
+            0xAF => self.instr_XOR_0xAF(),
+            0xB0 => self.instr_OR_0xB0(),
+            0xB1 => self.instr_OR_0xB1(),
+            0xB2 => self.instr_OR_0xB2(),
+            0xB3 => self.instr_OR_0xB3(),
+            0xB4 => self.instr_OR_0xB4(),
+            0xB5 => self.instr_OR_0xB5(),
+            0xB6 => self.instr_OR_0xB6(),
+            0xB7 => self.instr_OR_0xB7(),
+            0xB8 => self.instr_CP_0xB8(),
+            0xB9 => self.instr_CP_0xB9(),
+            0xBA => self.instr_CP_0xBA(),
+            0xBB => self.instr_CP_0xBB(),
+            0xBC => self.instr_CP_0xBC(),
+            0xBD => self.instr_CP_0xBD(),
+            0xBE => self.instr_CP_0xBE(),
+            0xBF => self.instr_CP_0xBF(),
+            0xC0 => self.instr_RET_0xC0(),
+            0xC1 => self.instr_POP_0xC1(),
+            0xC2 => self.instr_JP_0xC2(),
+            0xC3 => self.instr_JP_0xC3(),
+            0xC4 => self.instr_CALL_0xC4(),
+            0xC5 => self.instr_PUSH_0xC5(),
+            0xC6 => self.instr_ADD_0xC6(),
+            0xC7 => self.instr_RST_0xC7(),
+            0xC8 => self.instr_RET_0xC8(),
+            0xC9 => self.instr_RET_0xC9(),
+            0xCA => self.instr_JP_0xCA(),
+            0xCB => self.instr_PREFIX_0xCB(),
+            0xCC => self.instr_CALL_0xCC(),
+            0xCD => self.instr_CALL_0xCD(),
+            0xCE => self.instr_ADC_0xCE(),
+            0xCF => self.instr_RST_0xCF(),
+            0xD0 => self.instr_RET_0xD0(),
+            0xD1 => self.instr_POP_0xD1(),
+            0xD2 => self.instr_JP_0xD2(),
+            0xD3 => self.instr_ILLEGAL(),
+            0xD4 => self.instr_CALL_0xD4(),
+            0xD5 => self.instr_PUSH_0xD5(),
+            0xD6 => self.instr_SUB_0xD6(),
+            0xD7 => self.instr_RST_0xD7(),
+            0xD8 => self.instr_RET_0xD8(),
+            0xD9 => self.instr_RETI_0xD9(),
+            0xDA => self.instr_JP_0xDA(),
+            0xDB => self.instr_ILLEGAL(),
+            0xDC => self.instr_CALL_0xDC(),
+            0xDD => self.instr_ILLEGAL(),
+            0xDE => self.instr_SBC_0xDE(),
+            0xDF => self.instr_RST_0xDF(),
+            0xE0 => self.instr_LDH_0xE0(),
+            0xE1 => self.instr_POP_0xE1(),
+            0xE2 => self.instr_LD_0xE2(),
+            0xE3 => self.instr_ILLEGAL(),
+            0xE4 => self.instr_ILLEGAL(),
+            0xE5 => self.instr_PUSH_0xE5(),
+            0xE6 => self.instr_AND_0xE6(),
+            0xE7 => self.instr_RST_0xE7(),
+            0xE8 => self.instr_ADD_0xE8(),
+            0xE9 => self.instr_JP_0xE9(),
+            0xEA => self.instr_LD_0xEA(),
+            0xEB => self.instr_ILLEGAL(),
+            0xEC => self.instr_ILLEGAL(),
+            0xED => self.instr_ILLEGAL(),
+            0xEE => self.instr_XOR_0xEE(),
+            0xEF => self.instr_RST_0xEF(),
+            0xF0 => self.instr_LDH_0xF0(),
+            0xF1 => self.instr_POP_0xF1(),
+            0xF2 => self.instr_LD_0xF2(),
+            0xF3 => self.instr_DI_0xF3(),
+            0xF4 => self.instr_ILLEGAL(),
+            0xF5 => self.instr_PUSH_0xF5(),
+            0xF6 => self.instr_OR_0xF6(),
+            0xF7 => self.instr_RST_0xF7(),
+            0xF8 => self.instr_LD_0xF8(),
+            0xF9 => self.instr_LD_0xF9(),
+            0xFA => self.instr_LD_0xFA(),
+            0xFB => self.instr_EI_0xFB(),
+            0xFC => self.instr_ILLEGAL(),
+            0xFD => self.instr_ILLEGAL(),
+            0xFE => self.instr_CP_0xFE(),
+            _    => self.instr_RST_0xFF(),
+        }
     }
 
-    fn disp_SWAP_0xCB32(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
+    fn dispatch_cb(&mut self, opcode: u8) {
+        //! Run the handler of the `0xCB` prefixed `opcode`, given the
+        //! byte following the prefix.
+        match opcode {
+            0x00 => self.instr_RLC_0xCB00(),
+            0x01 => self.instr_RLC_0xCB01(),
+            0x02 => self.instr_RLC_0xCB02(),
+            0x03 => self.instr_RLC_0xCB03(),
+            0x04 => self.instr_RLC_0xCB04(),
+            0x05 => self.instr_RLC_0xCB05(),
+            0x06 => self.instr_RLC_0xCB06(),
+            0x07 => self.instr_RLC_0xCB07(),
+            0x08 => self.instr_RRC_0xCB08(),
+            0x09 => self.instr_RRC_0xCB09(),
+            0x0A => self.instr_RRC_0xCB0A(),
+            0x0B => self.instr_RRC_0xCB0B(),
+            0x0C => self.instr_RRC_0xCB0C(),
+            0x0D => self.instr_RRC_0xCB0D(),
+            0x0E => self.instr_RRC_0xCB0E(),
+            0x0F => self.instr_RRC_0xCB0F(),
+            0x10 => self.instr_RL_0xCB10(),
+            0x11 => self.instr_RL_0xCB11(),
+            0x12 => self.instr_RL_0xCB12(),
+            0x13 => self.instr_RL_0xCB13(),
+            0x14 => self.instr_RL_0xCB14(),
+            0x15 => self.instr_RL_0xCB15(),
+            0x16 => self.instr_RL_0xCB16(),
+            0x17 => self.instr_RL_0xCB17(),
+            0x18 => self.instr_RR_0xCB18(),
+            0x19 => self.instr_RR_0xCB19(),
+            0x1A => self.instr_RR_0xCB1A(),
+            0x1B => self.instr_RR_0xCB1B(),
+            0x1C => self.instr_RR_0xCB1C(),
+            0x1D => self.instr_RR_0xCB1D(),
+            0x1E => self.instr_RR_0xCB1E(),
+            0x1F => self.instr_RR_0xCB1F(),
+            0x20 => self.instr_SLA_0xCB20(),
+            0x21 => self.instr_SLA_0xCB21(),
+            0x22 => self.instr_SLA_0xCB22(),
+            0x23 => self.instr_SLA_0xCB23(),
+            0x24 => self.instr_SLA_0xCB24(),
+            0x25 => self.instr_SLA_0xCB25(),
+            0x26 => self.instr_SLA_0xCB26(),
+            0x27 => self.instr_SLA_0xCB27(),
+            0x28 => self.instr_SRA_0xCB28(),
+            0x29 => self.instr_SRA_0xCB29(),
+            0x2A => self.instr_SRA_0xCB2A(),
+            0x2B => self.instr_SRA_0xCB2B(),
+            0x2C => self.instr_SRA_0xCB2C(),
+            0x2D => self.instr_SRA_0xCB2D(),
+            0x2E => self.instr_SRA_0xCB2E(),
+            0x2F => self.instr_SRA_0xCB2F(),
+            0x30 => self.instr_SWAP_0xCB30(),
+            0x31 => self.instr_SWAP_0xCB31(),
+            0x32 => self.instr_SWAP_0xCB32(),
+            0x33 => self.instr_SWAP_0xCB33(),
+            0x34 => self.instr_SWAP_0xCB34(),
+            0x35 => self.instr_SWAP_0xCB35(),
+            0x36 => self.instr_SWAP_0xCB36(),
+            0x37 => self.instr_SWAP_0xCB37(),
+            0x38 => self.instr_SRL_0xCB38(),
+            0x39 => self.instr_SRL_0xCB39(),
+            0x3A => self.instr_SRL_0xCB3A(),
+            0x3B => self.instr_SRL_0xCB3B(),
+            0x3C => self.instr_SRL_0xCB3C(),
+            0x3D => self.instr_SRL_0xCB3D(),
+            0x3E => self.instr_SRL_0xCB3E(),
+            0x3F => self.instr_SRL_0xCB3F(),
+            0x40 => self.instr_BIT_0xCB40(),
+            0x41 => self.instr_BIT_0xCB41(),
+            0x42 => self.instr_BIT_0xCB42(),
+            0x43 => self.instr_BIT_0xCB43(),
+            0x44 => self.instr_BIT_0xCB44(),
+            0x45 => self.instr_BIT_0xCB45(),
+            0x46 => self.instr_BIT_0xCB46(),
+            0x47 => self.instr_BIT_0xCB47(),
+            0x48 => self.instr_BIT_0xCB48(),
+            0x49 => self.instr_BIT_0xCB49(),
+            0x4A => self.instr_BIT_0xCB4A(),
+            0x4B => self.instr_BIT_0xCB4B(),
+            0x4C => self.instr_BIT_0xCB4C(),
+            0x4D => self.instr_BIT_0xCB4D(),
+            0x4E => self.instr_BIT_0xCB4E(),
+            0x4F => self.instr_BIT_0xCB4F(),
+            0x50 => self.instr_BIT_0xCB50(),
+            0x51 => self.instr_BIT_0xCB51(),
+            0x52 => self.instr_BIT_0xCB52(),
+            0x53 => self.instr_BIT_0xCB53(),
+            0x54 => self.instr_BIT_0xCB54(),
+            0x55 => self.instr_BIT_0xCB55(),
+            0x56 => self.instr_BIT_0xCB56(),
+            0x57 => self.instr_BIT_0xCB57(),
+            0x58 => self.instr_BIT_0xCB58(),
+            0x59 => self.instr_BIT_0xCB59(),
+            0x5A => self.instr_BIT_0xCB5A(),
+            0x5B => self.instr_BIT_0xCB5B(),
+            0x5C => self.instr_BIT_0xCB5C(),
+            0x5D => self.instr_BIT_0xCB5D(),
+            0x5E => self.instr_BIT_0xCB5E(),
+            0x5F => self.instr_BIT_0xCB5F(),
+            0x60 => self.instr_BIT_0xCB60(),
+            0x61 => self.instr_BIT_0xCB61(),
+            0x62 => self.instr_BIT_0xCB62(),
+            0x63 => self.instr_BIT_0xCB63(),
+            0x64 => self.instr_BIT_0xCB64(),
+            0x65 => self.instr_BIT_0xCB65(),
+            0x66 => self.instr_BIT_0xCB66(),
+            0x67 => self.instr_BIT_0xCB67(),
+            0x68 => self.instr_BIT_0xCB68(),
+            0x69 => self.instr_BIT_0xCB69(),
+            0x6A => self.instr_BIT_0xCB6A(),
+            0x6B => self.instr_BIT_0xCB6B(),
+            0x6C => self.instr_BIT_0xCB6C(),
+            0x6D => self.instr_BIT_0xCB6D(),
+            0x6E => self.instr_BIT_0xCB6E(),
+            0x6F => self.instr_BIT_0xCB6F(),
+            0x70 => self.instr_BIT_0xCB70(),
+            0x71 => self.instr_BIT_0xCB71(),
+            0x72 => self.instr_BIT_0xCB72(),
+            0x73 => self.instr_BIT_0xCB73(),
+            0x74 => self.instr_BIT_0xCB74(),
+            0x75 => self.instr_BIT_0xCB75(),
+            0x76 => self.instr_BIT_0xCB76(),
+            0x77 => self.instr_BIT_0xCB77(),
+            0x78 => self.instr_BIT_0xCB78(),
+            0x79 => self.instr_BIT_0xCB79(),
+            0x7A => self.instr_BIT_0xCB7A(),
+            0x7B => self.instr_BIT_0xCB7B(),
+            0x7C => self.instr_BIT_0xCB7C(),
+            0x7D => self.instr_BIT_0xCB7D(),
+            0x7E => self.instr_BIT_0xCB7E(),
+            0x7F => self.instr_BIT_0xCB7F(),
+            0x80 => self.instr_RES_0xCB80(),
+            0x81 => self.instr_RES_0xCB81(),
+            0x82 => self.instr_RES_0xCB82(),
+            0x83 => self.instr_RES_0xCB83(),
+            0x84 => self.instr_RES_0xCB84(),
+            0x85 => self.instr_RES_0xCB85(),
+            0x86 => self.instr_RES_0xCB86(),
+            0x87 => self.instr_RES_0xCB87(),
+            0x88 => self.instr_RES_0xCB88(),
+            0x89 => self.instr_RES_0xCB89(),
+            0x8A => self.instr_RES_0xCB8A(),
+            0x8B => self.instr_RES_0xCB8B(),
+            0x8C => self.instr_RES_0xCB8C(),
+            0x8D => self.instr_RES_0xCB8D(),
+            0x8E => self.instr_RES_0xCB8E(),
+            0x8F => self.instr_RES_0xCB8F(),
+            0x90 => self.instr_RES_0xCB90(),
+            0x91 => self.instr_RES_0xCB91(),
+            0x92 => self.instr_RES_0xCB92(),
+            0x93 => self.instr_RES_0xCB93(),
+            0x94 => self.instr_RES_0xCB94(),
+            0x95 => self.instr_RES_0xCB95(),
+            0x96 => self.instr_RES_0xCB96(),
+            0x97 => self.instr_RES_0xCB97(),
+            0x98 => self.instr_RES_0xCB98(),
+            0x99 => self.instr_RES_0xCB99(),
+            0x9A => self.instr_RES_0xCB9A(),
+            0x9B => self.instr_RES_0xCB9B(),
+            0x9C => self.instr_RES_0xCB9C(),
+            0x9D => self.instr_RES_0xCB9D(),
+            0x9E => self.instr_RES_0xCB9E(),
+            0x9F => self.instr_RES_0xCB9F(),
+            0xA0 => self.instr_RES_0xCBA0(),
+            0xA1 => self.instr_RES_0xCBA1(),
+            0xA2 => self.instr_RES_0xCBA2(),
+            0xA3 => self.instr_RES_0xCBA3(),
+            0xA4 => self.instr_RES_0xCBA4(),
+            0xA5 => self.instr_RES_0xCBA5(),
+            0xA6 => self.instr_RES_0xCBA6(),
+            0xA7 => self.instr_RES_0xCBA7(),
+            0xA8 => self.instr_RES_0xCBA8(),
+            0xA9 => self.instr_RES_0xCBA9(),
+            0xAA => self.instr_RES_0xCBAA(),
+            0xAB => self.instr_RES_0xCBAB(),
+            0xAC => self.instr_RES_0xCBAC(),
+            0xAD => self.instr_RES_0xCBAD(),
+            0xAE => self.instr_RES_0xCBAE(),
+            0xAF => self.instr_RES_0xCBAF(),
+            0xB0 => self.instr_RES_0xCBB0(),
+            0xB1 => self.instr_RES_0xCBB1(),
+            0xB2 => self.instr_RES_0xCBB2(),
+            0xB3 => self.instr_RES_0xCBB3(),
+            0xB4 => self.instr_RES_0xCBB4(),
+            0xB5 => self.instr_RES_0xCBB5(),
+            0xB6 => self.instr_RES_0xCBB6(),
+            0xB7 => self.instr_RES_0xCBB7(),
+            0xB8 => self.instr_RES_0xCBB8(),
+            0xB9 => self.instr_RES_0xCBB9(),
+            0xBA => self.instr_RES_0xCBBA(),
+            0xBB => self.instr_RES_0xCBBB(),
+            0xBC => self.instr_RES_0xCBBC(),
+            0xBD => self.instr_RES_0xCBBD(),
+            0xBE => self.instr_RES_0xCBBE(),
+            0xBF => self.instr_RES_0xCBBF(),
+            0xC0 => self.instr_SET_0xCBC0(),
+            0xC1 => self.instr_SET_0xCBC1(),
+            0xC2 => self.instr_SET_0xCBC2(),
+            0xC3 => self.instr_SET_0xCBC3(),
+            0xC4 => self.instr_SET_0xCBC4(),
+            0xC5 => self.instr_SET_0xCBC5(),
+            0xC6 => self.instr_SET_0xCBC6(),
+            0xC7 => self.instr_SET_0xCBC7(),
+            0xC8 => self.instr_SET_0xCBC8(),
+            0xC9 => self.instr_SET_0xCBC9(),
+            0xCA => self.instr_SET_0xCBCA(),
+            0xCB => self.instr_SET_0xCBCB(),
+            0xCC => self.instr_SET_0xCBCC(),
+            0xCD => self.instr_SET_0xCBCD(),
+            0xCE => self.instr_SET_0xCBCE(),
+            0xCF => self.instr_SET_0xCBCF(),
+            0xD0 => self.instr_SET_0xCBD0(),
+            0xD1 => self.instr_SET_0xCBD1(),
+            0xD2 => self.instr_SET_0xCBD2(),
+            0xD3 => self.instr_SET_0xCBD3(),
+            0xD4 => self.instr_SET_0xCBD4(),
+            0xD5 => self.instr_SET_0xCBD5(),
+            0xD6 => self.instr_SET_0xCBD6(),
+            0xD7 => self.instr_SET_0xCBD7(),
+            0xD8 => self.instr_SET_0xCBD8(),
+            0xD9 => self.instr_SET_0xCBD9(),
+            0xDA => self.instr_SET_0xCBDA(),
+            0xDB => self.instr_SET_0xCBDB(),
+            0xDC => self.instr_SET_0xCBDC(),
+            0xDD => self.instr_SET_0xCBDD(),
+            0xDE => self.instr_SET_0xCBDE(),
+            0xDF => self.instr_SET_0xCBDF(),
+            0xE0 => self.instr_SET_0xCBE0(),
+            0xE1 => self.instr_SET_0xCBE1(),
+            0xE2 => self.instr_SET_0xCBE2(),
+            0xE3 => self.instr_SET_0xCBE3(),
+            0xE4 => self.instr_SET_0xCBE4(),
+            0xE5 => self.instr_SET_0xCBE5(),
+            0xE6 => self.instr_SET_0xCBE6(),
+            0xE7 => self.instr_SET_0xCBE7(),
+            0xE8 => self.instr_SET_0xCBE8(),
+            0xE9 => self.instr_SET_0xCBE9(),
+            0xEA => self.instr_SET_0xCBEA(),
+            0xEB => self.instr_SET_0xCBEB(),
+            0xEC => self.instr_SET_0xCBEC(),
+            0xED => self.instr_SET_0xCBED(),
+            0xEE => self.instr_SET_0xCBEE(),
+            0xEF => self.instr_SET_0xCBEF(),
+            0xF0 => self.instr_SET_0xCBF0(),
+            0xF1 => self.instr_SET_0xCBF1(),
+            0xF2 => self.instr_SET_0xCBF2(),
+            0xF3 => self.instr_SET_0xCBF3(),
+            0xF4 => self.instr_SET_0xCBF4(),
+            0xF5 => self.instr_SET_0xCBF5(),
+            0xF6 => self.instr_SET_0xCBF6(),
+            0xF7 => self.instr_SET_0xCBF7(),
+            0xF8 => self.instr_SET_0xCBF8(),
+            0xF9 => self.instr_SET_0xCBF9(),
+            0xFA => self.instr_SET_0xCBFA(),
+            0xFB => self.instr_SET_0xCBFB(),
+            0xFC => self.instr_SET_0xCBFC(),
+            0xFD => self.instr_SET_0xCBFD(),
+            0xFE => self.instr_SET_0xCBFE(),
+            _    => self.instr_SET_0xCBFF(),
+        }
     }
+}
 
-    fn disp_SWAP_0xCB33(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
+fn illegal(opcode: u8) -> bool {
+    //! Return whether `opcode` is not part of the instruction set.
+    match opcode {
+        0xD3 | 0xDB | 0xDD | 0xE3 | 0xE4 | 0xEB |
+        0xEC | 0xED | 0xF4 | 0xFC | 0xFD => true,
+        _ => false,
     }
+}
 
-    fn disp_SWAP_0xCB34(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SWAP_0xCB35(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
+// ==============================================
+// Traits
+// ==============================================
+impl fmt::Display for CpuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CpuError::IllegalOpcode { pc, opcode } =>
+                write!(f, "illegal opcode 0x{:0>2.2X} at 0x{:0>4.4X}", opcode, pc),
+        }
     }
+}
 
-    fn disp_SWAP_0xCB36(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SWAP_0xCB37(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SWAP");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB38(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB39(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SRL_0xCB3F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SRL");
-        result.push_str(" ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB40(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB41(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB42(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB43(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB44(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB45(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB46(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB47(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB48(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB49(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB4F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB50(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB51(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB52(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB53(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB54(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB55(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB56(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB57(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB58(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB59(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB5F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB60(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB61(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB62(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB63(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB64(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB65(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB66(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB67(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB68(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB69(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB6F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB70(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB71(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB72(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB73(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB74(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB75(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB76(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB77(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB78(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB79(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_BIT_0xCB7F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("BIT");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB80(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB81(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB82(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB83(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB84(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB85(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB86(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB87(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB88(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB89(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB8F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB90(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB91(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB92(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB93(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB94(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB95(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB96(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB97(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB98(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB99(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9A(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9B(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9C(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9D(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9E(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCB9F(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBA9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBAF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBB9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_RES_0xCBBF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RES");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("0");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBC9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBCF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("1");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("2");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBD9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBDF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("3");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("4");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBE9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBEA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBEB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBEC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBED(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBEE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBEF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("5");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("6");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("B");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBF9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("C");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("D");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("E");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("H");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("L");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("(HL)");
-        (result, 2)
-    }
-
-    fn disp_SET_0xCBFF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SET");
-        result.push_str(" ");
-        result.push_str("7");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_CALL_0xCC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CALL");
-        result.push_str(" ");
-        result.push_str("Z");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_CALL_0xCD(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CALL");
-        result.push_str(" ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_ADC_0xCE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xCF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("08H");
-        (result, 1)
-    }
-
-    fn disp_RET_0xD0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RET");
-        result.push_str(" ");
-        result.push_str("NC");
-        (result, 1)
-    }
-
-    fn disp_POP_0xD1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("POP");
-        result.push_str(" ");
-        result.push_str("DE");
-        (result, 1)
-    }
-
-    fn disp_JP_0xD2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        result.push_str("NC");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_CALL_0xD4(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CALL");
-        result.push_str(" ");
-        result.push_str("NC");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_PUSH_0xD5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("PUSH");
-        result.push_str(" ");
-        result.push_str("DE");
-        (result, 1)
-    }
-
-    fn disp_SUB_0xD6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SUB");
-        result.push_str(" ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xD7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("10H");
-        (result, 1)
-    }
-
-    fn disp_RET_0xD8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RET");
-        result.push_str(" ");
-        result.push_str("C");
-        (result, 1)
-    }
-
-    fn disp_RETI_0xD9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RETI");
-        (result, 1)
-    }
-
-    fn disp_JP_0xDA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_CALL_0xDC(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CALL");
-        result.push_str(" ");
-        result.push_str("C");
-        result.push_str(", ");
-        let a16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("0x{:0>4.4X}", a16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_SBC_0xDE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("SBC");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xDF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("18H");
-        (result, 1)
-    }
-
-    fn disp_LDH_0xE0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LDH");
-        result.push_str(" ");
-        let pa8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("(0x{:0>2.2X})", pa8).as_slice());
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 2)
-    }
-
-    fn disp_POP_0xE1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("POP");
-        result.push_str(" ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_LD_0xE2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("(C)");
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 1)
-    }
-
-    fn disp_PUSH_0xE5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("PUSH");
-        result.push_str(" ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_AND_0xE6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("AND");
-        result.push_str(" ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xE7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("20H");
-        (result, 1)
-    }
-
-    fn disp_ADD_0xE8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("ADD");
-        result.push_str(" ");
-        result.push_str("SP");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_JP_0xE9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("JP");
-        result.push_str(" ");
-        result.push_str("(HL)");
-        (result, 1)
-    }
-
-    fn disp_LD_0xEA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        let pa16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("(0x{:0>4.4X})", pa16).as_slice());
-        result.push_str(", ");
-        result.push_str("A");
-        (result, 3)
-    }
-
-    fn disp_XOR_0xEE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("XOR");
-        result.push_str(" ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xEF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("28H");
-        (result, 1)
-    }
-
-    fn disp_LDH_0xF0(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LDH");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let pa8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("(0x{:0>2.2X})", pa8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_POP_0xF1(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("POP");
-        result.push_str(" ");
-        result.push_str("AF");
-        (result, 1)
-    }
-
-    fn disp_LD_0xF2(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        result.push_str("(C)");
-        (result, 1)
-    }
-
-    fn disp_DI_0xF3(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("DI");
-        (result, 1)
-    }
-
-    fn disp_PUSH_0xF5(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("PUSH");
-        result.push_str(" ");
-        result.push_str("AF");
-        (result, 1)
-    }
-
-    fn disp_OR_0xF6(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("OR");
-        result.push_str(" ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xF7(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("30H");
-        (result, 1)
-    }
-
-    fn disp_LD_0xF8(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("HL");
-        result.push_str(", ");
-        let r8: i8 = self.mmu.read8(self.pc + 1) as i8;
-        result.push_str(format!("SP+0x{:0>2.2X}", r8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_LD_0xF9(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("SP");
-        result.push_str(", ");
-        result.push_str("HL");
-        (result, 1)
-    }
-
-    fn disp_LD_0xFA(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("LD");
-        result.push_str(" ");
-        result.push_str("A");
-        result.push_str(", ");
-        let pa16: u16 = self.mmu.read16(self.pc + 1);
-        result.push_str(format!("(0x{:0>4.4X})", pa16).as_slice());
-        (result, 3)
-    }
-
-    fn disp_EI_0xFB(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("EI");
-        (result, 1)
-    }
-
-    fn disp_CP_0xFE(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("CP");
-        result.push_str(" ");
-        let d8: u8 = self.mmu.read8(self.pc + 1);
-        result.push_str(format!("0x{:0>2.2X}", d8).as_slice());
-        (result, 2)
-    }
-
-    fn disp_RST_0xFF(&self) -> (String, u8) {
-        let mut result: String = String::new();
-        result.push_str("RST");
-        result.push_str(" ");
-        result.push_str("38H");
-        (result, 1)
+impl Error for CpuError {
+    fn description(&self) -> &str {
+        match *self {
+            CpuError::IllegalOpcode { .. } => "illegal opcode",
+        }
     }
 }
 
-// ==============================================
-// Traits
-// ==============================================
-impl fmt::Debug for Cpu {
+impl<B: Bus> fmt::Debug for Cpu<B> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let af: u16 = ((self.a as u16) << 8) + self.f as u16;
         let bc: u16 = ((self.b as u16) << 8) + self.c as u16;
@@ -18785,515 +16415,3 @@ impl fmt::Debug for Cpu {
                                 self.sp))
     }
 }
-
-// ==============================================
-// Statics
-// ==============================================
-
-lazy_static! {
-    static ref DECODER: BTreeMap<u16, (fn(&mut Cpu), fn(&Cpu) -> (String, u8))> = {
-        let mut decoder: BTreeMap<u16, (fn(&mut Cpu), fn(&Cpu) -> (String, u8))> = BTreeMap::new();
-        decoder.insert(0x00 as u16, (Cpu::instr_NOP_0x00, Cpu::disp_NOP_0x00));
-        decoder.insert(0x01 as u16, (Cpu::instr_LD_0x01, Cpu::disp_LD_0x01));
-        decoder.insert(0x02 as u16, (Cpu::instr_LD_0x02, Cpu::disp_LD_0x02));
-        decoder.insert(0x03 as u16, (Cpu::instr_INC_0x03, Cpu::disp_INC_0x03));
-        decoder.insert(0x04 as u16, (Cpu::instr_INC_0x04, Cpu::disp_INC_0x04));
-        decoder.insert(0x05 as u16, (Cpu::instr_DEC_0x05, Cpu::disp_DEC_0x05));
-        decoder.insert(0x06 as u16, (Cpu::instr_LD_0x06, Cpu::disp_LD_0x06));
-        decoder.insert(0x07 as u16, (Cpu::instr_RLCA_0x07, Cpu::disp_RLCA_0x07));
-        decoder.insert(0x08 as u16, (Cpu::instr_LD_0x08, Cpu::disp_LD_0x08));
-        decoder.insert(0x09 as u16, (Cpu::instr_ADD_0x09, Cpu::disp_ADD_0x09));
-        decoder.insert(0x0A as u16, (Cpu::instr_LD_0x0A, Cpu::disp_LD_0x0A));
-        decoder.insert(0x0B as u16, (Cpu::instr_DEC_0x0B, Cpu::disp_DEC_0x0B));
-        decoder.insert(0x0C as u16, (Cpu::instr_INC_0x0C, Cpu::disp_INC_0x0C));
-        decoder.insert(0x0D as u16, (Cpu::instr_DEC_0x0D, Cpu::disp_DEC_0x0D));
-        decoder.insert(0x0E as u16, (Cpu::instr_LD_0x0E, Cpu::disp_LD_0x0E));
-        decoder.insert(0x0F as u16, (Cpu::instr_RRCA_0x0F, Cpu::disp_RRCA_0x0F));
-        decoder.insert(0x10 as u16, (Cpu::instr_STOP_0x10, Cpu::disp_STOP_0x10));
-        decoder.insert(0x11 as u16, (Cpu::instr_LD_0x11, Cpu::disp_LD_0x11));
-        decoder.insert(0x12 as u16, (Cpu::instr_LD_0x12, Cpu::disp_LD_0x12));
-        decoder.insert(0x13 as u16, (Cpu::instr_INC_0x13, Cpu::disp_INC_0x13));
-        decoder.insert(0x14 as u16, (Cpu::instr_INC_0x14, Cpu::disp_INC_0x14));
-        decoder.insert(0x15 as u16, (Cpu::instr_DEC_0x15, Cpu::disp_DEC_0x15));
-        decoder.insert(0x16 as u16, (Cpu::instr_LD_0x16, Cpu::disp_LD_0x16));
-        decoder.insert(0x17 as u16, (Cpu::instr_RLA_0x17, Cpu::disp_RLA_0x17));
-        decoder.insert(0x18 as u16, (Cpu::instr_JR_0x18, Cpu::disp_JR_0x18));
-        decoder.insert(0x19 as u16, (Cpu::instr_ADD_0x19, Cpu::disp_ADD_0x19));
-        decoder.insert(0x1A as u16, (Cpu::instr_LD_0x1A, Cpu::disp_LD_0x1A));
-        decoder.insert(0x1B as u16, (Cpu::instr_DEC_0x1B, Cpu::disp_DEC_0x1B));
-        decoder.insert(0x1C as u16, (Cpu::instr_INC_0x1C, Cpu::disp_INC_0x1C));
-        decoder.insert(0x1D as u16, (Cpu::instr_DEC_0x1D, Cpu::disp_DEC_0x1D));
-        decoder.insert(0x1E as u16, (Cpu::instr_LD_0x1E, Cpu::disp_LD_0x1E));
-        decoder.insert(0x1F as u16, (Cpu::instr_RRA_0x1F, Cpu::disp_RRA_0x1F));
-        decoder.insert(0x20 as u16, (Cpu::instr_JR_0x20, Cpu::disp_JR_0x20));
-        decoder.insert(0x21 as u16, (Cpu::instr_LD_0x21, Cpu::disp_LD_0x21));
-        decoder.insert(0x22 as u16, (Cpu::instr_LD_0x22, Cpu::disp_LD_0x22));
-        decoder.insert(0x23 as u16, (Cpu::instr_INC_0x23, Cpu::disp_INC_0x23));
-        decoder.insert(0x24 as u16, (Cpu::instr_INC_0x24, Cpu::disp_INC_0x24));
-        decoder.insert(0x25 as u16, (Cpu::instr_DEC_0x25, Cpu::disp_DEC_0x25));
-        decoder.insert(0x26 as u16, (Cpu::instr_LD_0x26, Cpu::disp_LD_0x26));
-        decoder.insert(0x27 as u16, (Cpu::instr_DAA_0x27, Cpu::disp_DAA_0x27));
-        decoder.insert(0x28 as u16, (Cpu::instr_JR_0x28, Cpu::disp_JR_0x28));
-        decoder.insert(0x29 as u16, (Cpu::instr_ADD_0x29, Cpu::disp_ADD_0x29));
-        decoder.insert(0x2A as u16, (Cpu::instr_LD_0x2A, Cpu::disp_LD_0x2A));
-        decoder.insert(0x2B as u16, (Cpu::instr_DEC_0x2B, Cpu::disp_DEC_0x2B));
-        decoder.insert(0x2C as u16, (Cpu::instr_INC_0x2C, Cpu::disp_INC_0x2C));
-        decoder.insert(0x2D as u16, (Cpu::instr_DEC_0x2D, Cpu::disp_DEC_0x2D));
-        decoder.insert(0x2E as u16, (Cpu::instr_LD_0x2E, Cpu::disp_LD_0x2E));
-        decoder.insert(0x2F as u16, (Cpu::instr_CPL_0x2F, Cpu::disp_CPL_0x2F));
-        decoder.insert(0x30 as u16, (Cpu::instr_JR_0x30, Cpu::disp_JR_0x30));
-        decoder.insert(0x31 as u16, (Cpu::instr_LD_0x31, Cpu::disp_LD_0x31));
-        decoder.insert(0x32 as u16, (Cpu::instr_LD_0x32, Cpu::disp_LD_0x32));
-        decoder.insert(0x33 as u16, (Cpu::instr_INC_0x33, Cpu::disp_INC_0x33));
-        decoder.insert(0x34 as u16, (Cpu::instr_INC_0x34, Cpu::disp_INC_0x34));
-        decoder.insert(0x35 as u16, (Cpu::instr_DEC_0x35, Cpu::disp_DEC_0x35));
-        decoder.insert(0x36 as u16, (Cpu::instr_LD_0x36, Cpu::disp_LD_0x36));
-        decoder.insert(0x37 as u16, (Cpu::instr_SCF_0x37, Cpu::disp_SCF_0x37));
-        decoder.insert(0x38 as u16, (Cpu::instr_JR_0x38, Cpu::disp_JR_0x38));
-        decoder.insert(0x39 as u16, (Cpu::instr_ADD_0x39, Cpu::disp_ADD_0x39));
-        decoder.insert(0x3A as u16, (Cpu::instr_LD_0x3A, Cpu::disp_LD_0x3A));
-        decoder.insert(0x3B as u16, (Cpu::instr_DEC_0x3B, Cpu::disp_DEC_0x3B));
-        decoder.insert(0x3C as u16, (Cpu::instr_INC_0x3C, Cpu::disp_INC_0x3C));
-        decoder.insert(0x3D as u16, (Cpu::instr_DEC_0x3D, Cpu::disp_DEC_0x3D));
-        decoder.insert(0x3E as u16, (Cpu::instr_LD_0x3E, Cpu::disp_LD_0x3E));
-        decoder.insert(0x3F as u16, (Cpu::instr_CCF_0x3F, Cpu::disp_CCF_0x3F));
-        decoder.insert(0x40 as u16, (Cpu::instr_LD_0x40, Cpu::disp_LD_0x40));
-        decoder.insert(0x41 as u16, (Cpu::instr_LD_0x41, Cpu::disp_LD_0x41));
-        decoder.insert(0x42 as u16, (Cpu::instr_LD_0x42, Cpu::disp_LD_0x42));
-        decoder.insert(0x43 as u16, (Cpu::instr_LD_0x43, Cpu::disp_LD_0x43));
-        decoder.insert(0x44 as u16, (Cpu::instr_LD_0x44, Cpu::disp_LD_0x44));
-        decoder.insert(0x45 as u16, (Cpu::instr_LD_0x45, Cpu::disp_LD_0x45));
-        decoder.insert(0x46 as u16, (Cpu::instr_LD_0x46, Cpu::disp_LD_0x46));
-        decoder.insert(0x47 as u16, (Cpu::instr_LD_0x47, Cpu::disp_LD_0x47));
-        decoder.insert(0x48 as u16, (Cpu::instr_LD_0x48, Cpu::disp_LD_0x48));
-        decoder.insert(0x49 as u16, (Cpu::instr_LD_0x49, Cpu::disp_LD_0x49));
-        decoder.insert(0x4A as u16, (Cpu::instr_LD_0x4A, Cpu::disp_LD_0x4A));
-        decoder.insert(0x4B as u16, (Cpu::instr_LD_0x4B, Cpu::disp_LD_0x4B));
-        decoder.insert(0x4C as u16, (Cpu::instr_LD_0x4C, Cpu::disp_LD_0x4C));
-        decoder.insert(0x4D as u16, (Cpu::instr_LD_0x4D, Cpu::disp_LD_0x4D));
-        decoder.insert(0x4E as u16, (Cpu::instr_LD_0x4E, Cpu::disp_LD_0x4E));
-        decoder.insert(0x4F as u16, (Cpu::instr_LD_0x4F, Cpu::disp_LD_0x4F));
-        decoder.insert(0x50 as u16, (Cpu::instr_LD_0x50, Cpu::disp_LD_0x50));
-        decoder.insert(0x51 as u16, (Cpu::instr_LD_0x51, Cpu::disp_LD_0x51));
-        decoder.insert(0x52 as u16, (Cpu::instr_LD_0x52, Cpu::disp_LD_0x52));
-        decoder.insert(0x53 as u16, (Cpu::instr_LD_0x53, Cpu::disp_LD_0x53));
-        decoder.insert(0x54 as u16, (Cpu::instr_LD_0x54, Cpu::disp_LD_0x54));
-        decoder.insert(0x55 as u16, (Cpu::instr_LD_0x55, Cpu::disp_LD_0x55));
-        decoder.insert(0x56 as u16, (Cpu::instr_LD_0x56, Cpu::disp_LD_0x56));
-        decoder.insert(0x57 as u16, (Cpu::instr_LD_0x57, Cpu::disp_LD_0x57));
-        decoder.insert(0x58 as u16, (Cpu::instr_LD_0x58, Cpu::disp_LD_0x58));
-        decoder.insert(0x59 as u16, (Cpu::instr_LD_0x59, Cpu::disp_LD_0x59));
-        decoder.insert(0x5A as u16, (Cpu::instr_LD_0x5A, Cpu::disp_LD_0x5A));
-        decoder.insert(0x5B as u16, (Cpu::instr_LD_0x5B, Cpu::disp_LD_0x5B));
-        decoder.insert(0x5C as u16, (Cpu::instr_LD_0x5C, Cpu::disp_LD_0x5C));
-        decoder.insert(0x5D as u16, (Cpu::instr_LD_0x5D, Cpu::disp_LD_0x5D));
-        decoder.insert(0x5E as u16, (Cpu::instr_LD_0x5E, Cpu::disp_LD_0x5E));
-        decoder.insert(0x5F as u16, (Cpu::instr_LD_0x5F, Cpu::disp_LD_0x5F));
-        decoder.insert(0x60 as u16, (Cpu::instr_LD_0x60, Cpu::disp_LD_0x60));
-        decoder.insert(0x61 as u16, (Cpu::instr_LD_0x61, Cpu::disp_LD_0x61));
-        decoder.insert(0x62 as u16, (Cpu::instr_LD_0x62, Cpu::disp_LD_0x62));
-        decoder.insert(0x63 as u16, (Cpu::instr_LD_0x63, Cpu::disp_LD_0x63));
-        decoder.insert(0x64 as u16, (Cpu::instr_LD_0x64, Cpu::disp_LD_0x64));
-        decoder.insert(0x65 as u16, (Cpu::instr_LD_0x65, Cpu::disp_LD_0x65));
-        decoder.insert(0x66 as u16, (Cpu::instr_LD_0x66, Cpu::disp_LD_0x66));
-        decoder.insert(0x67 as u16, (Cpu::instr_LD_0x67, Cpu::disp_LD_0x67));
-        decoder.insert(0x68 as u16, (Cpu::instr_LD_0x68, Cpu::disp_LD_0x68));
-        decoder.insert(0x69 as u16, (Cpu::instr_LD_0x69, Cpu::disp_LD_0x69));
-        decoder.insert(0x6A as u16, (Cpu::instr_LD_0x6A, Cpu::disp_LD_0x6A));
-        decoder.insert(0x6B as u16, (Cpu::instr_LD_0x6B, Cpu::disp_LD_0x6B));
-        decoder.insert(0x6C as u16, (Cpu::instr_LD_0x6C, Cpu::disp_LD_0x6C));
-        decoder.insert(0x6D as u16, (Cpu::instr_LD_0x6D, Cpu::disp_LD_0x6D));
-        decoder.insert(0x6E as u16, (Cpu::instr_LD_0x6E, Cpu::disp_LD_0x6E));
-        decoder.insert(0x6F as u16, (Cpu::instr_LD_0x6F, Cpu::disp_LD_0x6F));
-        decoder.insert(0x70 as u16, (Cpu::instr_LD_0x70, Cpu::disp_LD_0x70));
-        decoder.insert(0x71 as u16, (Cpu::instr_LD_0x71, Cpu::disp_LD_0x71));
-        decoder.insert(0x72 as u16, (Cpu::instr_LD_0x72, Cpu::disp_LD_0x72));
-        decoder.insert(0x73 as u16, (Cpu::instr_LD_0x73, Cpu::disp_LD_0x73));
-        decoder.insert(0x74 as u16, (Cpu::instr_LD_0x74, Cpu::disp_LD_0x74));
-        decoder.insert(0x75 as u16, (Cpu::instr_LD_0x75, Cpu::disp_LD_0x75));
-        decoder.insert(0x76 as u16, (Cpu::instr_HALT_0x76, Cpu::disp_HALT_0x76));
-        decoder.insert(0x77 as u16, (Cpu::instr_LD_0x77, Cpu::disp_LD_0x77));
-        decoder.insert(0x78 as u16, (Cpu::instr_LD_0x78, Cpu::disp_LD_0x78));
-        decoder.insert(0x79 as u16, (Cpu::instr_LD_0x79, Cpu::disp_LD_0x79));
-        decoder.insert(0x7A as u16, (Cpu::instr_LD_0x7A, Cpu::disp_LD_0x7A));
-        decoder.insert(0x7B as u16, (Cpu::instr_LD_0x7B, Cpu::disp_LD_0x7B));
-        decoder.insert(0x7C as u16, (Cpu::instr_LD_0x7C, Cpu::disp_LD_0x7C));
-        decoder.insert(0x7D as u16, (Cpu::instr_LD_0x7D, Cpu::disp_LD_0x7D));
-        decoder.insert(0x7E as u16, (Cpu::instr_LD_0x7E, Cpu::disp_LD_0x7E));
-        decoder.insert(0x7F as u16, (Cpu::instr_LD_0x7F, Cpu::disp_LD_0x7F));
-        decoder.insert(0x80 as u16, (Cpu::instr_ADD_0x80, Cpu::disp_ADD_0x80));
-        decoder.insert(0x81 as u16, (Cpu::instr_ADD_0x81, Cpu::disp_ADD_0x81));
-        decoder.insert(0x82 as u16, (Cpu::instr_ADD_0x82, Cpu::disp_ADD_0x82));
-        decoder.insert(0x83 as u16, (Cpu::instr_ADD_0x83, Cpu::disp_ADD_0x83));
-        decoder.insert(0x84 as u16, (Cpu::instr_ADD_0x84, Cpu::disp_ADD_0x84));
-        decoder.insert(0x85 as u16, (Cpu::instr_ADD_0x85, Cpu::disp_ADD_0x85));
-        decoder.insert(0x86 as u16, (Cpu::instr_ADD_0x86, Cpu::disp_ADD_0x86));
-        decoder.insert(0x87 as u16, (Cpu::instr_ADD_0x87, Cpu::disp_ADD_0x87));
-        decoder.insert(0x88 as u16, (Cpu::instr_ADC_0x88, Cpu::disp_ADC_0x88));
-        decoder.insert(0x89 as u16, (Cpu::instr_ADC_0x89, Cpu::disp_ADC_0x89));
-        decoder.insert(0x8A as u16, (Cpu::instr_ADC_0x8A, Cpu::disp_ADC_0x8A));
-        decoder.insert(0x8B as u16, (Cpu::instr_ADC_0x8B, Cpu::disp_ADC_0x8B));
-        decoder.insert(0x8C as u16, (Cpu::instr_ADC_0x8C, Cpu::disp_ADC_0x8C));
-        decoder.insert(0x8D as u16, (Cpu::instr_ADC_0x8D, Cpu::disp_ADC_0x8D));
-        decoder.insert(0x8E as u16, (Cpu::instr_ADC_0x8E, Cpu::disp_ADC_0x8E));
-        decoder.insert(0x8F as u16, (Cpu::instr_ADC_0x8F, Cpu::disp_ADC_0x8F));
-        decoder.insert(0x90 as u16, (Cpu::instr_SUB_0x90, Cpu::disp_SUB_0x90));
-        decoder.insert(0x91 as u16, (Cpu::instr_SUB_0x91, Cpu::disp_SUB_0x91));
-        decoder.insert(0x92 as u16, (Cpu::instr_SUB_0x92, Cpu::disp_SUB_0x92));
-        decoder.insert(0x93 as u16, (Cpu::instr_SUB_0x93, Cpu::disp_SUB_0x93));
-        decoder.insert(0x94 as u16, (Cpu::instr_SUB_0x94, Cpu::disp_SUB_0x94));
-        decoder.insert(0x95 as u16, (Cpu::instr_SUB_0x95, Cpu::disp_SUB_0x95));
-        decoder.insert(0x96 as u16, (Cpu::instr_SUB_0x96, Cpu::disp_SUB_0x96));
-        decoder.insert(0x97 as u16, (Cpu::instr_SUB_0x97, Cpu::disp_SUB_0x97));
-        decoder.insert(0x98 as u16, (Cpu::instr_SBC_0x98, Cpu::disp_SBC_0x98));
-        decoder.insert(0x99 as u16, (Cpu::instr_SBC_0x99, Cpu::disp_SBC_0x99));
-        decoder.insert(0x9A as u16, (Cpu::instr_SBC_0x9A, Cpu::disp_SBC_0x9A));
-        decoder.insert(0x9B as u16, (Cpu::instr_SBC_0x9B, Cpu::disp_SBC_0x9B));
-        decoder.insert(0x9C as u16, (Cpu::instr_SBC_0x9C, Cpu::disp_SBC_0x9C));
-        decoder.insert(0x9D as u16, (Cpu::instr_SBC_0x9D, Cpu::disp_SBC_0x9D));
-        decoder.insert(0x9E as u16, (Cpu::instr_SBC_0x9E, Cpu::disp_SBC_0x9E));
-        decoder.insert(0x9F as u16, (Cpu::instr_SBC_0x9F, Cpu::disp_SBC_0x9F));
-        decoder.insert(0xA0 as u16, (Cpu::instr_AND_0xA0, Cpu::disp_AND_0xA0));
-        decoder.insert(0xA1 as u16, (Cpu::instr_AND_0xA1, Cpu::disp_AND_0xA1));
-        decoder.insert(0xA2 as u16, (Cpu::instr_AND_0xA2, Cpu::disp_AND_0xA2));
-        decoder.insert(0xA3 as u16, (Cpu::instr_AND_0xA3, Cpu::disp_AND_0xA3));
-        decoder.insert(0xA4 as u16, (Cpu::instr_AND_0xA4, Cpu::disp_AND_0xA4));
-        decoder.insert(0xA5 as u16, (Cpu::instr_AND_0xA5, Cpu::disp_AND_0xA5));
-        decoder.insert(0xA6 as u16, (Cpu::instr_AND_0xA6, Cpu::disp_AND_0xA6));
-        decoder.insert(0xA7 as u16, (Cpu::instr_AND_0xA7, Cpu::disp_AND_0xA7));
-        decoder.insert(0xA8 as u16, (Cpu::instr_XOR_0xA8, Cpu::disp_XOR_0xA8));
-        decoder.insert(0xA9 as u16, (Cpu::instr_XOR_0xA9, Cpu::disp_XOR_0xA9));
-        decoder.insert(0xAA as u16, (Cpu::instr_XOR_0xAA, Cpu::disp_XOR_0xAA));
-        decoder.insert(0xAB as u16, (Cpu::instr_XOR_0xAB, Cpu::disp_XOR_0xAB));
-        decoder.insert(0xAC as u16, (Cpu::instr_XOR_0xAC, Cpu::disp_XOR_0xAC));
-        decoder.insert(0xAD as u16, (Cpu::instr_XOR_0xAD, Cpu::disp_XOR_0xAD));
-        decoder.insert(0xAE as u16, (Cpu::instr_XOR_0xAE, Cpu::disp_XOR_0xAE));
-        decoder.insert(0xAF as u16, (Cpu::instr_XOR_0xAF, Cpu::disp_XOR_0xAF));
-        decoder.insert(0xB0 as u16, (Cpu::instr_OR_0xB0, Cpu::disp_OR_0xB0));
-        decoder.insert(0xB1 as u16, (Cpu::instr_OR_0xB1, Cpu::disp_OR_0xB1));
-        decoder.insert(0xB2 as u16, (Cpu::instr_OR_0xB2, Cpu::disp_OR_0xB2));
-        decoder.insert(0xB3 as u16, (Cpu::instr_OR_0xB3, Cpu::disp_OR_0xB3));
-        decoder.insert(0xB4 as u16, (Cpu::instr_OR_0xB4, Cpu::disp_OR_0xB4));
-        decoder.insert(0xB5 as u16, (Cpu::instr_OR_0xB5, Cpu::disp_OR_0xB5));
-        decoder.insert(0xB6 as u16, (Cpu::instr_OR_0xB6, Cpu::disp_OR_0xB6));
-        decoder.insert(0xB7 as u16, (Cpu::instr_OR_0xB7, Cpu::disp_OR_0xB7));
-        decoder.insert(0xB8 as u16, (Cpu::instr_CP_0xB8, Cpu::disp_CP_0xB8));
-        decoder.insert(0xB9 as u16, (Cpu::instr_CP_0xB9, Cpu::disp_CP_0xB9));
-        decoder.insert(0xBA as u16, (Cpu::instr_CP_0xBA, Cpu::disp_CP_0xBA));
-        decoder.insert(0xBB as u16, (Cpu::instr_CP_0xBB, Cpu::disp_CP_0xBB));
-        decoder.insert(0xBC as u16, (Cpu::instr_CP_0xBC, Cpu::disp_CP_0xBC));
-        decoder.insert(0xBD as u16, (Cpu::instr_CP_0xBD, Cpu::disp_CP_0xBD));
-        decoder.insert(0xBE as u16, (Cpu::instr_CP_0xBE, Cpu::disp_CP_0xBE));
-        decoder.insert(0xBF as u16, (Cpu::instr_CP_0xBF, Cpu::disp_CP_0xBF));
-        decoder.insert(0xC0 as u16, (Cpu::instr_RET_0xC0, Cpu::disp_RET_0xC0));
-        decoder.insert(0xC1 as u16, (Cpu::instr_POP_0xC1, Cpu::disp_POP_0xC1));
-        decoder.insert(0xC2 as u16, (Cpu::instr_JP_0xC2, Cpu::disp_JP_0xC2));
-        decoder.insert(0xC3 as u16, (Cpu::instr_JP_0xC3, Cpu::disp_JP_0xC3));
-        decoder.insert(0xC4 as u16, (Cpu::instr_CALL_0xC4, Cpu::disp_CALL_0xC4));
-        decoder.insert(0xC5 as u16, (Cpu::instr_PUSH_0xC5, Cpu::disp_PUSH_0xC5));
-        decoder.insert(0xC6 as u16, (Cpu::instr_ADD_0xC6, Cpu::disp_ADD_0xC6));
-        decoder.insert(0xC7 as u16, (Cpu::instr_RST_0xC7, Cpu::disp_RST_0xC7));
-        decoder.insert(0xC8 as u16, (Cpu::instr_RET_0xC8, Cpu::disp_RET_0xC8));
-        decoder.insert(0xC9 as u16, (Cpu::instr_RET_0xC9, Cpu::disp_RET_0xC9));
-        decoder.insert(0xCA as u16, (Cpu::instr_JP_0xCA, Cpu::disp_JP_0xCA));
-        decoder.insert(0xCB as u16, (Cpu::instr_PREFIX_0xCB, Cpu::disp_PREFIX_0xCB));
-        decoder.insert(0xCC as u16, (Cpu::instr_CALL_0xCC, Cpu::disp_CALL_0xCC));
-        decoder.insert(0xCD as u16, (Cpu::instr_CALL_0xCD, Cpu::disp_CALL_0xCD));
-        decoder.insert(0xCE as u16, (Cpu::instr_ADC_0xCE, Cpu::disp_ADC_0xCE));
-        decoder.insert(0xCF as u16, (Cpu::instr_RST_0xCF, Cpu::disp_RST_0xCF));
-        decoder.insert(0xD0 as u16, (Cpu::instr_RET_0xD0, Cpu::disp_RET_0xD0));
-        decoder.insert(0xD1 as u16, (Cpu::instr_POP_0xD1, Cpu::disp_POP_0xD1));
-        decoder.insert(0xD2 as u16, (Cpu::instr_JP_0xD2, Cpu::disp_JP_0xD2));
-        decoder.insert(0xD4 as u16, (Cpu::instr_CALL_0xD4, Cpu::disp_CALL_0xD4));
-        decoder.insert(0xD5 as u16, (Cpu::instr_PUSH_0xD5, Cpu::disp_PUSH_0xD5));
-        decoder.insert(0xD6 as u16, (Cpu::instr_SUB_0xD6, Cpu::disp_SUB_0xD6));
-        decoder.insert(0xD7 as u16, (Cpu::instr_RST_0xD7, Cpu::disp_RST_0xD7));
-        decoder.insert(0xD8 as u16, (Cpu::instr_RET_0xD8, Cpu::disp_RET_0xD8));
-        decoder.insert(0xD9 as u16, (Cpu::instr_RETI_0xD9, Cpu::disp_RETI_0xD9));
-        decoder.insert(0xDA as u16, (Cpu::instr_JP_0xDA, Cpu::disp_JP_0xDA));
-        decoder.insert(0xDC as u16, (Cpu::instr_CALL_0xDC, Cpu::disp_CALL_0xDC));
-        decoder.insert(0xDE as u16, (Cpu::instr_SBC_0xDE, Cpu::disp_SBC_0xDE));
-        decoder.insert(0xDF as u16, (Cpu::instr_RST_0xDF, Cpu::disp_RST_0xDF));
-        decoder.insert(0xE0 as u16, (Cpu::instr_LDH_0xE0, Cpu::disp_LDH_0xE0));
-        decoder.insert(0xE1 as u16, (Cpu::instr_POP_0xE1, Cpu::disp_POP_0xE1));
-        decoder.insert(0xE2 as u16, (Cpu::instr_LD_0xE2, Cpu::disp_LD_0xE2));
-        decoder.insert(0xE5 as u16, (Cpu::instr_PUSH_0xE5, Cpu::disp_PUSH_0xE5));
-        decoder.insert(0xE6 as u16, (Cpu::instr_AND_0xE6, Cpu::disp_AND_0xE6));
-        decoder.insert(0xE7 as u16, (Cpu::instr_RST_0xE7, Cpu::disp_RST_0xE7));
-        decoder.insert(0xE8 as u16, (Cpu::instr_ADD_0xE8, Cpu::disp_ADD_0xE8));
-        decoder.insert(0xE9 as u16, (Cpu::instr_JP_0xE9, Cpu::disp_JP_0xE9));
-        decoder.insert(0xEA as u16, (Cpu::instr_LD_0xEA, Cpu::disp_LD_0xEA));
-        decoder.insert(0xEE as u16, (Cpu::instr_XOR_0xEE, Cpu::disp_XOR_0xEE));
-        decoder.insert(0xEF as u16, (Cpu::instr_RST_0xEF, Cpu::disp_RST_0xEF));
-        decoder.insert(0xF0 as u16, (Cpu::instr_LDH_0xF0, Cpu::disp_LDH_0xF0));
-        decoder.insert(0xF1 as u16, (Cpu::instr_POP_0xF1, Cpu::disp_POP_0xF1));
-        decoder.insert(0xF2 as u16, (Cpu::instr_LD_0xF2, Cpu::disp_LD_0xF2));
-        decoder.insert(0xF3 as u16, (Cpu::instr_DI_0xF3, Cpu::disp_DI_0xF3));
-        decoder.insert(0xF5 as u16, (Cpu::instr_PUSH_0xF5, Cpu::disp_PUSH_0xF5));
-        decoder.insert(0xF6 as u16, (Cpu::instr_OR_0xF6, Cpu::disp_OR_0xF6));
-        decoder.insert(0xF7 as u16, (Cpu::instr_RST_0xF7, Cpu::disp_RST_0xF7));
-        decoder.insert(0xF8 as u16, (Cpu::instr_LD_0xF8, Cpu::disp_LD_0xF8));
-        decoder.insert(0xF9 as u16, (Cpu::instr_LD_0xF9, Cpu::disp_LD_0xF9));
-        decoder.insert(0xFA as u16, (Cpu::instr_LD_0xFA, Cpu::disp_LD_0xFA));
-        decoder.insert(0xFB as u16, (Cpu::instr_EI_0xFB, Cpu::disp_EI_0xFB));
-        decoder.insert(0xFE as u16, (Cpu::instr_CP_0xFE, Cpu::disp_CP_0xFE));
-        decoder.insert(0xFF as u16, (Cpu::instr_RST_0xFF, Cpu::disp_RST_0xFF));
-        decoder.insert(0xCB00 as u16, (Cpu::instr_RLC_0xCB00, Cpu::disp_RLC_0xCB00));
-        decoder.insert(0xCB01 as u16, (Cpu::instr_RLC_0xCB01, Cpu::disp_RLC_0xCB01));
-        decoder.insert(0xCB02 as u16, (Cpu::instr_RLC_0xCB02, Cpu::disp_RLC_0xCB02));
-        decoder.insert(0xCB03 as u16, (Cpu::instr_RLC_0xCB03, Cpu::disp_RLC_0xCB03));
-        decoder.insert(0xCB04 as u16, (Cpu::instr_RLC_0xCB04, Cpu::disp_RLC_0xCB04));
-        decoder.insert(0xCB05 as u16, (Cpu::instr_RLC_0xCB05, Cpu::disp_RLC_0xCB05));
-        decoder.insert(0xCB06 as u16, (Cpu::instr_RLC_0xCB06, Cpu::disp_RLC_0xCB06));
-        decoder.insert(0xCB07 as u16, (Cpu::instr_RLC_0xCB07, Cpu::disp_RLC_0xCB07));
-        decoder.insert(0xCB08 as u16, (Cpu::instr_RRC_0xCB08, Cpu::disp_RRC_0xCB08));
-        decoder.insert(0xCB09 as u16, (Cpu::instr_RRC_0xCB09, Cpu::disp_RRC_0xCB09));
-        decoder.insert(0xCB0A as u16, (Cpu::instr_RRC_0xCB0A, Cpu::disp_RRC_0xCB0A));
-        decoder.insert(0xCB0B as u16, (Cpu::instr_RRC_0xCB0B, Cpu::disp_RRC_0xCB0B));
-        decoder.insert(0xCB0C as u16, (Cpu::instr_RRC_0xCB0C, Cpu::disp_RRC_0xCB0C));
-        decoder.insert(0xCB0D as u16, (Cpu::instr_RRC_0xCB0D, Cpu::disp_RRC_0xCB0D));
-        decoder.insert(0xCB0E as u16, (Cpu::instr_RRC_0xCB0E, Cpu::disp_RRC_0xCB0E));
-        decoder.insert(0xCB0F as u16, (Cpu::instr_RRC_0xCB0F, Cpu::disp_RRC_0xCB0F));
-        decoder.insert(0xCB10 as u16, (Cpu::instr_RL_0xCB10, Cpu::disp_RL_0xCB10));
-        decoder.insert(0xCB11 as u16, (Cpu::instr_RL_0xCB11, Cpu::disp_RL_0xCB11));
-        decoder.insert(0xCB12 as u16, (Cpu::instr_RL_0xCB12, Cpu::disp_RL_0xCB12));
-        decoder.insert(0xCB13 as u16, (Cpu::instr_RL_0xCB13, Cpu::disp_RL_0xCB13));
-        decoder.insert(0xCB14 as u16, (Cpu::instr_RL_0xCB14, Cpu::disp_RL_0xCB14));
-        decoder.insert(0xCB15 as u16, (Cpu::instr_RL_0xCB15, Cpu::disp_RL_0xCB15));
-        decoder.insert(0xCB16 as u16, (Cpu::instr_RL_0xCB16, Cpu::disp_RL_0xCB16));
-        decoder.insert(0xCB17 as u16, (Cpu::instr_RL_0xCB17, Cpu::disp_RL_0xCB17));
-        decoder.insert(0xCB18 as u16, (Cpu::instr_RR_0xCB18, Cpu::disp_RR_0xCB18));
-        decoder.insert(0xCB19 as u16, (Cpu::instr_RR_0xCB19, Cpu::disp_RR_0xCB19));
-        decoder.insert(0xCB1A as u16, (Cpu::instr_RR_0xCB1A, Cpu::disp_RR_0xCB1A));
-        decoder.insert(0xCB1B as u16, (Cpu::instr_RR_0xCB1B, Cpu::disp_RR_0xCB1B));
-        decoder.insert(0xCB1C as u16, (Cpu::instr_RR_0xCB1C, Cpu::disp_RR_0xCB1C));
-        decoder.insert(0xCB1D as u16, (Cpu::instr_RR_0xCB1D, Cpu::disp_RR_0xCB1D));
-        decoder.insert(0xCB1E as u16, (Cpu::instr_RR_0xCB1E, Cpu::disp_RR_0xCB1E));
-        decoder.insert(0xCB1F as u16, (Cpu::instr_RR_0xCB1F, Cpu::disp_RR_0xCB1F));
-        decoder.insert(0xCB20 as u16, (Cpu::instr_SLA_0xCB20, Cpu::disp_SLA_0xCB20));
-        decoder.insert(0xCB21 as u16, (Cpu::instr_SLA_0xCB21, Cpu::disp_SLA_0xCB21));
-        decoder.insert(0xCB22 as u16, (Cpu::instr_SLA_0xCB22, Cpu::disp_SLA_0xCB22));
-        decoder.insert(0xCB23 as u16, (Cpu::instr_SLA_0xCB23, Cpu::disp_SLA_0xCB23));
-        decoder.insert(0xCB24 as u16, (Cpu::instr_SLA_0xCB24, Cpu::disp_SLA_0xCB24));
-        decoder.insert(0xCB25 as u16, (Cpu::instr_SLA_0xCB25, Cpu::disp_SLA_0xCB25));
-        decoder.insert(0xCB26 as u16, (Cpu::instr_SLA_0xCB26, Cpu::disp_SLA_0xCB26));
-        decoder.insert(0xCB27 as u16, (Cpu::instr_SLA_0xCB27, Cpu::disp_SLA_0xCB27));
-        decoder.insert(0xCB28 as u16, (Cpu::instr_SRA_0xCB28, Cpu::disp_SRA_0xCB28));
-        decoder.insert(0xCB29 as u16, (Cpu::instr_SRA_0xCB29, Cpu::disp_SRA_0xCB29));
-        decoder.insert(0xCB2A as u16, (Cpu::instr_SRA_0xCB2A, Cpu::disp_SRA_0xCB2A));
-        decoder.insert(0xCB2B as u16, (Cpu::instr_SRA_0xCB2B, Cpu::disp_SRA_0xCB2B));
-        decoder.insert(0xCB2C as u16, (Cpu::instr_SRA_0xCB2C, Cpu::disp_SRA_0xCB2C));
-        decoder.insert(0xCB2D as u16, (Cpu::instr_SRA_0xCB2D, Cpu::disp_SRA_0xCB2D));
-        decoder.insert(0xCB2E as u16, (Cpu::instr_SRA_0xCB2E, Cpu::disp_SRA_0xCB2E));
-        decoder.insert(0xCB2F as u16, (Cpu::instr_SRA_0xCB2F, Cpu::disp_SRA_0xCB2F));
-        decoder.insert(0xCB30 as u16, (Cpu::instr_SWAP_0xCB30, Cpu::disp_SWAP_0xCB30));
-        decoder.insert(0xCB31 as u16, (Cpu::instr_SWAP_0xCB31, Cpu::disp_SWAP_0xCB31));
-        decoder.insert(0xCB32 as u16, (Cpu::instr_SWAP_0xCB32, Cpu::disp_SWAP_0xCB32));
-        decoder.insert(0xCB33 as u16, (Cpu::instr_SWAP_0xCB33, Cpu::disp_SWAP_0xCB33));
-        decoder.insert(0xCB34 as u16, (Cpu::instr_SWAP_0xCB34, Cpu::disp_SWAP_0xCB34));
-        decoder.insert(0xCB35 as u16, (Cpu::instr_SWAP_0xCB35, Cpu::disp_SWAP_0xCB35));
-        decoder.insert(0xCB36 as u16, (Cpu::instr_SWAP_0xCB36, Cpu::disp_SWAP_0xCB36));
-        decoder.insert(0xCB37 as u16, (Cpu::instr_SWAP_0xCB37, Cpu::disp_SWAP_0xCB37));
-        decoder.insert(0xCB38 as u16, (Cpu::instr_SRL_0xCB38, Cpu::disp_SRL_0xCB38));
-        decoder.insert(0xCB39 as u16, (Cpu::instr_SRL_0xCB39, Cpu::disp_SRL_0xCB39));
-        decoder.insert(0xCB3A as u16, (Cpu::instr_SRL_0xCB3A, Cpu::disp_SRL_0xCB3A));
-        decoder.insert(0xCB3B as u16, (Cpu::instr_SRL_0xCB3B, Cpu::disp_SRL_0xCB3B));
-        decoder.insert(0xCB3C as u16, (Cpu::instr_SRL_0xCB3C, Cpu::disp_SRL_0xCB3C));
-        decoder.insert(0xCB3D as u16, (Cpu::instr_SRL_0xCB3D, Cpu::disp_SRL_0xCB3D));
-        decoder.insert(0xCB3E as u16, (Cpu::instr_SRL_0xCB3E, Cpu::disp_SRL_0xCB3E));
-        decoder.insert(0xCB3F as u16, (Cpu::instr_SRL_0xCB3F, Cpu::disp_SRL_0xCB3F));
-        decoder.insert(0xCB40 as u16, (Cpu::instr_BIT_0xCB40, Cpu::disp_BIT_0xCB40));
-        decoder.insert(0xCB41 as u16, (Cpu::instr_BIT_0xCB41, Cpu::disp_BIT_0xCB41));
-        decoder.insert(0xCB42 as u16, (Cpu::instr_BIT_0xCB42, Cpu::disp_BIT_0xCB42));
-        decoder.insert(0xCB43 as u16, (Cpu::instr_BIT_0xCB43, Cpu::disp_BIT_0xCB43));
-        decoder.insert(0xCB44 as u16, (Cpu::instr_BIT_0xCB44, Cpu::disp_BIT_0xCB44));
-        decoder.insert(0xCB45 as u16, (Cpu::instr_BIT_0xCB45, Cpu::disp_BIT_0xCB45));
-        decoder.insert(0xCB46 as u16, (Cpu::instr_BIT_0xCB46, Cpu::disp_BIT_0xCB46));
-        decoder.insert(0xCB47 as u16, (Cpu::instr_BIT_0xCB47, Cpu::disp_BIT_0xCB47));
-        decoder.insert(0xCB48 as u16, (Cpu::instr_BIT_0xCB48, Cpu::disp_BIT_0xCB48));
-        decoder.insert(0xCB49 as u16, (Cpu::instr_BIT_0xCB49, Cpu::disp_BIT_0xCB49));
-        decoder.insert(0xCB4A as u16, (Cpu::instr_BIT_0xCB4A, Cpu::disp_BIT_0xCB4A));
-        decoder.insert(0xCB4B as u16, (Cpu::instr_BIT_0xCB4B, Cpu::disp_BIT_0xCB4B));
-        decoder.insert(0xCB4C as u16, (Cpu::instr_BIT_0xCB4C, Cpu::disp_BIT_0xCB4C));
-        decoder.insert(0xCB4D as u16, (Cpu::instr_BIT_0xCB4D, Cpu::disp_BIT_0xCB4D));
-        decoder.insert(0xCB4E as u16, (Cpu::instr_BIT_0xCB4E, Cpu::disp_BIT_0xCB4E));
-        decoder.insert(0xCB4F as u16, (Cpu::instr_BIT_0xCB4F, Cpu::disp_BIT_0xCB4F));
-        decoder.insert(0xCB50 as u16, (Cpu::instr_BIT_0xCB50, Cpu::disp_BIT_0xCB50));
-        decoder.insert(0xCB51 as u16, (Cpu::instr_BIT_0xCB51, Cpu::disp_BIT_0xCB51));
-        decoder.insert(0xCB52 as u16, (Cpu::instr_BIT_0xCB52, Cpu::disp_BIT_0xCB52));
-        decoder.insert(0xCB53 as u16, (Cpu::instr_BIT_0xCB53, Cpu::disp_BIT_0xCB53));
-        decoder.insert(0xCB54 as u16, (Cpu::instr_BIT_0xCB54, Cpu::disp_BIT_0xCB54));
-        decoder.insert(0xCB55 as u16, (Cpu::instr_BIT_0xCB55, Cpu::disp_BIT_0xCB55));
-        decoder.insert(0xCB56 as u16, (Cpu::instr_BIT_0xCB56, Cpu::disp_BIT_0xCB56));
-        decoder.insert(0xCB57 as u16, (Cpu::instr_BIT_0xCB57, Cpu::disp_BIT_0xCB57));
-        decoder.insert(0xCB58 as u16, (Cpu::instr_BIT_0xCB58, Cpu::disp_BIT_0xCB58));
-        decoder.insert(0xCB59 as u16, (Cpu::instr_BIT_0xCB59, Cpu::disp_BIT_0xCB59));
-        decoder.insert(0xCB5A as u16, (Cpu::instr_BIT_0xCB5A, Cpu::disp_BIT_0xCB5A));
-        decoder.insert(0xCB5B as u16, (Cpu::instr_BIT_0xCB5B, Cpu::disp_BIT_0xCB5B));
-        decoder.insert(0xCB5C as u16, (Cpu::instr_BIT_0xCB5C, Cpu::disp_BIT_0xCB5C));
-        decoder.insert(0xCB5D as u16, (Cpu::instr_BIT_0xCB5D, Cpu::disp_BIT_0xCB5D));
-        decoder.insert(0xCB5E as u16, (Cpu::instr_BIT_0xCB5E, Cpu::disp_BIT_0xCB5E));
-        decoder.insert(0xCB5F as u16, (Cpu::instr_BIT_0xCB5F, Cpu::disp_BIT_0xCB5F));
-        decoder.insert(0xCB60 as u16, (Cpu::instr_BIT_0xCB60, Cpu::disp_BIT_0xCB60));
-        decoder.insert(0xCB61 as u16, (Cpu::instr_BIT_0xCB61, Cpu::disp_BIT_0xCB61));
-        decoder.insert(0xCB62 as u16, (Cpu::instr_BIT_0xCB62, Cpu::disp_BIT_0xCB62));
-        decoder.insert(0xCB63 as u16, (Cpu::instr_BIT_0xCB63, Cpu::disp_BIT_0xCB63));
-        decoder.insert(0xCB64 as u16, (Cpu::instr_BIT_0xCB64, Cpu::disp_BIT_0xCB64));
-        decoder.insert(0xCB65 as u16, (Cpu::instr_BIT_0xCB65, Cpu::disp_BIT_0xCB65));
-        decoder.insert(0xCB66 as u16, (Cpu::instr_BIT_0xCB66, Cpu::disp_BIT_0xCB66));
-        decoder.insert(0xCB67 as u16, (Cpu::instr_BIT_0xCB67, Cpu::disp_BIT_0xCB67));
-        decoder.insert(0xCB68 as u16, (Cpu::instr_BIT_0xCB68, Cpu::disp_BIT_0xCB68));
-        decoder.insert(0xCB69 as u16, (Cpu::instr_BIT_0xCB69, Cpu::disp_BIT_0xCB69));
-        decoder.insert(0xCB6A as u16, (Cpu::instr_BIT_0xCB6A, Cpu::disp_BIT_0xCB6A));
-        decoder.insert(0xCB6B as u16, (Cpu::instr_BIT_0xCB6B, Cpu::disp_BIT_0xCB6B));
-        decoder.insert(0xCB6C as u16, (Cpu::instr_BIT_0xCB6C, Cpu::disp_BIT_0xCB6C));
-        decoder.insert(0xCB6D as u16, (Cpu::instr_BIT_0xCB6D, Cpu::disp_BIT_0xCB6D));
-        decoder.insert(0xCB6E as u16, (Cpu::instr_BIT_0xCB6E, Cpu::disp_BIT_0xCB6E));
-        decoder.insert(0xCB6F as u16, (Cpu::instr_BIT_0xCB6F, Cpu::disp_BIT_0xCB6F));
-        decoder.insert(0xCB70 as u16, (Cpu::instr_BIT_0xCB70, Cpu::disp_BIT_0xCB70));
-        decoder.insert(0xCB71 as u16, (Cpu::instr_BIT_0xCB71, Cpu::disp_BIT_0xCB71));
-        decoder.insert(0xCB72 as u16, (Cpu::instr_BIT_0xCB72, Cpu::disp_BIT_0xCB72));
-        decoder.insert(0xCB73 as u16, (Cpu::instr_BIT_0xCB73, Cpu::disp_BIT_0xCB73));
-        decoder.insert(0xCB74 as u16, (Cpu::instr_BIT_0xCB74, Cpu::disp_BIT_0xCB74));
-        decoder.insert(0xCB75 as u16, (Cpu::instr_BIT_0xCB75, Cpu::disp_BIT_0xCB75));
-        decoder.insert(0xCB76 as u16, (Cpu::instr_BIT_0xCB76, Cpu::disp_BIT_0xCB76));
-        decoder.insert(0xCB77 as u16, (Cpu::instr_BIT_0xCB77, Cpu::disp_BIT_0xCB77));
-        decoder.insert(0xCB78 as u16, (Cpu::instr_BIT_0xCB78, Cpu::disp_BIT_0xCB78));
-        decoder.insert(0xCB79 as u16, (Cpu::instr_BIT_0xCB79, Cpu::disp_BIT_0xCB79));
-        decoder.insert(0xCB7A as u16, (Cpu::instr_BIT_0xCB7A, Cpu::disp_BIT_0xCB7A));
-        decoder.insert(0xCB7B as u16, (Cpu::instr_BIT_0xCB7B, Cpu::disp_BIT_0xCB7B));
-        decoder.insert(0xCB7C as u16, (Cpu::instr_BIT_0xCB7C, Cpu::disp_BIT_0xCB7C));
-        decoder.insert(0xCB7D as u16, (Cpu::instr_BIT_0xCB7D, Cpu::disp_BIT_0xCB7D));
-        decoder.insert(0xCB7E as u16, (Cpu::instr_BIT_0xCB7E, Cpu::disp_BIT_0xCB7E));
-        decoder.insert(0xCB7F as u16, (Cpu::instr_BIT_0xCB7F, Cpu::disp_BIT_0xCB7F));
-        decoder.insert(0xCB80 as u16, (Cpu::instr_RES_0xCB80, Cpu::disp_RES_0xCB80));
-        decoder.insert(0xCB81 as u16, (Cpu::instr_RES_0xCB81, Cpu::disp_RES_0xCB81));
-        decoder.insert(0xCB82 as u16, (Cpu::instr_RES_0xCB82, Cpu::disp_RES_0xCB82));
-        decoder.insert(0xCB83 as u16, (Cpu::instr_RES_0xCB83, Cpu::disp_RES_0xCB83));
-        decoder.insert(0xCB84 as u16, (Cpu::instr_RES_0xCB84, Cpu::disp_RES_0xCB84));
-        decoder.insert(0xCB85 as u16, (Cpu::instr_RES_0xCB85, Cpu::disp_RES_0xCB85));
-        decoder.insert(0xCB86 as u16, (Cpu::instr_RES_0xCB86, Cpu::disp_RES_0xCB86));
-        decoder.insert(0xCB87 as u16, (Cpu::instr_RES_0xCB87, Cpu::disp_RES_0xCB87));
-        decoder.insert(0xCB88 as u16, (Cpu::instr_RES_0xCB88, Cpu::disp_RES_0xCB88));
-        decoder.insert(0xCB89 as u16, (Cpu::instr_RES_0xCB89, Cpu::disp_RES_0xCB89));
-        decoder.insert(0xCB8A as u16, (Cpu::instr_RES_0xCB8A, Cpu::disp_RES_0xCB8A));
-        decoder.insert(0xCB8B as u16, (Cpu::instr_RES_0xCB8B, Cpu::disp_RES_0xCB8B));
-        decoder.insert(0xCB8C as u16, (Cpu::instr_RES_0xCB8C, Cpu::disp_RES_0xCB8C));
-        decoder.insert(0xCB8D as u16, (Cpu::instr_RES_0xCB8D, Cpu::disp_RES_0xCB8D));
-        decoder.insert(0xCB8E as u16, (Cpu::instr_RES_0xCB8E, Cpu::disp_RES_0xCB8E));
-        decoder.insert(0xCB8F as u16, (Cpu::instr_RES_0xCB8F, Cpu::disp_RES_0xCB8F));
-        decoder.insert(0xCB90 as u16, (Cpu::instr_RES_0xCB90, Cpu::disp_RES_0xCB90));
-        decoder.insert(0xCB91 as u16, (Cpu::instr_RES_0xCB91, Cpu::disp_RES_0xCB91));
-        decoder.insert(0xCB92 as u16, (Cpu::instr_RES_0xCB92, Cpu::disp_RES_0xCB92));
-        decoder.insert(0xCB93 as u16, (Cpu::instr_RES_0xCB93, Cpu::disp_RES_0xCB93));
-        decoder.insert(0xCB94 as u16, (Cpu::instr_RES_0xCB94, Cpu::disp_RES_0xCB94));
-        decoder.insert(0xCB95 as u16, (Cpu::instr_RES_0xCB95, Cpu::disp_RES_0xCB95));
-        decoder.insert(0xCB96 as u16, (Cpu::instr_RES_0xCB96, Cpu::disp_RES_0xCB96));
-        decoder.insert(0xCB97 as u16, (Cpu::instr_RES_0xCB97, Cpu::disp_RES_0xCB97));
-        decoder.insert(0xCB98 as u16, (Cpu::instr_RES_0xCB98, Cpu::disp_RES_0xCB98));
-        decoder.insert(0xCB99 as u16, (Cpu::instr_RES_0xCB99, Cpu::disp_RES_0xCB99));
-        decoder.insert(0xCB9A as u16, (Cpu::instr_RES_0xCB9A, Cpu::disp_RES_0xCB9A));
-        decoder.insert(0xCB9B as u16, (Cpu::instr_RES_0xCB9B, Cpu::disp_RES_0xCB9B));
-        decoder.insert(0xCB9C as u16, (Cpu::instr_RES_0xCB9C, Cpu::disp_RES_0xCB9C));
-        decoder.insert(0xCB9D as u16, (Cpu::instr_RES_0xCB9D, Cpu::disp_RES_0xCB9D));
-        decoder.insert(0xCB9E as u16, (Cpu::instr_RES_0xCB9E, Cpu::disp_RES_0xCB9E));
-        decoder.insert(0xCB9F as u16, (Cpu::instr_RES_0xCB9F, Cpu::disp_RES_0xCB9F));
-        decoder.insert(0xCBA0 as u16, (Cpu::instr_RES_0xCBA0, Cpu::disp_RES_0xCBA0));
-        decoder.insert(0xCBA1 as u16, (Cpu::instr_RES_0xCBA1, Cpu::disp_RES_0xCBA1));
-        decoder.insert(0xCBA2 as u16, (Cpu::instr_RES_0xCBA2, Cpu::disp_RES_0xCBA2));
-        decoder.insert(0xCBA3 as u16, (Cpu::instr_RES_0xCBA3, Cpu::disp_RES_0xCBA3));
-        decoder.insert(0xCBA4 as u16, (Cpu::instr_RES_0xCBA4, Cpu::disp_RES_0xCBA4));
-        decoder.insert(0xCBA5 as u16, (Cpu::instr_RES_0xCBA5, Cpu::disp_RES_0xCBA5));
-        decoder.insert(0xCBA6 as u16, (Cpu::instr_RES_0xCBA6, Cpu::disp_RES_0xCBA6));
-        decoder.insert(0xCBA7 as u16, (Cpu::instr_RES_0xCBA7, Cpu::disp_RES_0xCBA7));
-        decoder.insert(0xCBA8 as u16, (Cpu::instr_RES_0xCBA8, Cpu::disp_RES_0xCBA8));
-        decoder.insert(0xCBA9 as u16, (Cpu::instr_RES_0xCBA9, Cpu::disp_RES_0xCBA9));
-        decoder.insert(0xCBAA as u16, (Cpu::instr_RES_0xCBAA, Cpu::disp_RES_0xCBAA));
-        decoder.insert(0xCBAB as u16, (Cpu::instr_RES_0xCBAB, Cpu::disp_RES_0xCBAB));
-        decoder.insert(0xCBAC as u16, (Cpu::instr_RES_0xCBAC, Cpu::disp_RES_0xCBAC));
-        decoder.insert(0xCBAD as u16, (Cpu::instr_RES_0xCBAD, Cpu::disp_RES_0xCBAD));
-        decoder.insert(0xCBAE as u16, (Cpu::instr_RES_0xCBAE, Cpu::disp_RES_0xCBAE));
-        decoder.insert(0xCBAF as u16, (Cpu::instr_RES_0xCBAF, Cpu::disp_RES_0xCBAF));
-        decoder.insert(0xCBB0 as u16, (Cpu::instr_RES_0xCBB0, Cpu::disp_RES_0xCBB0));
-        decoder.insert(0xCBB1 as u16, (Cpu::instr_RES_0xCBB1, Cpu::disp_RES_0xCBB1));
-        decoder.insert(0xCBB2 as u16, (Cpu::instr_RES_0xCBB2, Cpu::disp_RES_0xCBB2));
-        decoder.insert(0xCBB3 as u16, (Cpu::instr_RES_0xCBB3, Cpu::disp_RES_0xCBB3));
-        decoder.insert(0xCBB4 as u16, (Cpu::instr_RES_0xCBB4, Cpu::disp_RES_0xCBB4));
-        decoder.insert(0xCBB5 as u16, (Cpu::instr_RES_0xCBB5, Cpu::disp_RES_0xCBB5));
-        decoder.insert(0xCBB6 as u16, (Cpu::instr_RES_0xCBB6, Cpu::disp_RES_0xCBB6));
-        decoder.insert(0xCBB7 as u16, (Cpu::instr_RES_0xCBB7, Cpu::disp_RES_0xCBB7));
-        decoder.insert(0xCBB8 as u16, (Cpu::instr_RES_0xCBB8, Cpu::disp_RES_0xCBB8));
-        decoder.insert(0xCBB9 as u16, (Cpu::instr_RES_0xCBB9, Cpu::disp_RES_0xCBB9));
-        decoder.insert(0xCBBA as u16, (Cpu::instr_RES_0xCBBA, Cpu::disp_RES_0xCBBA));
-        decoder.insert(0xCBBB as u16, (Cpu::instr_RES_0xCBBB, Cpu::disp_RES_0xCBBB));
-        decoder.insert(0xCBBC as u16, (Cpu::instr_RES_0xCBBC, Cpu::disp_RES_0xCBBC));
-        decoder.insert(0xCBBD as u16, (Cpu::instr_RES_0xCBBD, Cpu::disp_RES_0xCBBD));
-        decoder.insert(0xCBBE as u16, (Cpu::instr_RES_0xCBBE, Cpu::disp_RES_0xCBBE));
-        decoder.insert(0xCBBF as u16, (Cpu::instr_RES_0xCBBF, Cpu::disp_RES_0xCBBF));
-        decoder.insert(0xCBC0 as u16, (Cpu::instr_SET_0xCBC0, Cpu::disp_SET_0xCBC0));
-        decoder.insert(0xCBC1 as u16, (Cpu::instr_SET_0xCBC1, Cpu::disp_SET_0xCBC1));
-        decoder.insert(0xCBC2 as u16, (Cpu::instr_SET_0xCBC2, Cpu::disp_SET_0xCBC2));
-        decoder.insert(0xCBC3 as u16, (Cpu::instr_SET_0xCBC3, Cpu::disp_SET_0xCBC3));
-        decoder.insert(0xCBC4 as u16, (Cpu::instr_SET_0xCBC4, Cpu::disp_SET_0xCBC4));
-        decoder.insert(0xCBC5 as u16, (Cpu::instr_SET_0xCBC5, Cpu::disp_SET_0xCBC5));
-        decoder.insert(0xCBC6 as u16, (Cpu::instr_SET_0xCBC6, Cpu::disp_SET_0xCBC6));
-        decoder.insert(0xCBC7 as u16, (Cpu::instr_SET_0xCBC7, Cpu::disp_SET_0xCBC7));
-        decoder.insert(0xCBC8 as u16, (Cpu::instr_SET_0xCBC8, Cpu::disp_SET_0xCBC8));
-        decoder.insert(0xCBC9 as u16, (Cpu::instr_SET_0xCBC9, Cpu::disp_SET_0xCBC9));
-        decoder.insert(0xCBCA as u16, (Cpu::instr_SET_0xCBCA, Cpu::disp_SET_0xCBCA));
-        decoder.insert(0xCBCB as u16, (Cpu::instr_SET_0xCBCB, Cpu::disp_SET_0xCBCB));
-        decoder.insert(0xCBCC as u16, (Cpu::instr_SET_0xCBCC, Cpu::disp_SET_0xCBCC));
-        decoder.insert(0xCBCD as u16, (Cpu::instr_SET_0xCBCD, Cpu::disp_SET_0xCBCD));
-        decoder.insert(0xCBCE as u16, (Cpu::instr_SET_0xCBCE, Cpu::disp_SET_0xCBCE));
-        decoder.insert(0xCBCF as u16, (Cpu::instr_SET_0xCBCF, Cpu::disp_SET_0xCBCF));
-        decoder.insert(0xCBD0 as u16, (Cpu::instr_SET_0xCBD0, Cpu::disp_SET_0xCBD0));
-        decoder.insert(0xCBD1 as u16, (Cpu::instr_SET_0xCBD1, Cpu::disp_SET_0xCBD1));
-        decoder.insert(0xCBD2 as u16, (Cpu::instr_SET_0xCBD2, Cpu::disp_SET_0xCBD2));
-        decoder.insert(0xCBD3 as u16, (Cpu::instr_SET_0xCBD3, Cpu::disp_SET_0xCBD3));
-        decoder.insert(0xCBD4 as u16, (Cpu::instr_SET_0xCBD4, Cpu::disp_SET_0xCBD4));
-        decoder.insert(0xCBD5 as u16, (Cpu::instr_SET_0xCBD5, Cpu::disp_SET_0xCBD5));
-        decoder.insert(0xCBD6 as u16, (Cpu::instr_SET_0xCBD6, Cpu::disp_SET_0xCBD6));
-        decoder.insert(0xCBD7 as u16, (Cpu::instr_SET_0xCBD7, Cpu::disp_SET_0xCBD7));
-        decoder.insert(0xCBD8 as u16, (Cpu::instr_SET_0xCBD8, Cpu::disp_SET_0xCBD8));
-        decoder.insert(0xCBD9 as u16, (Cpu::instr_SET_0xCBD9, Cpu::disp_SET_0xCBD9));
-        decoder.insert(0xCBDA as u16, (Cpu::instr_SET_0xCBDA, Cpu::disp_SET_0xCBDA));
-        decoder.insert(0xCBDB as u16, (Cpu::instr_SET_0xCBDB, Cpu::disp_SET_0xCBDB));
-        decoder.insert(0xCBDC as u16, (Cpu::instr_SET_0xCBDC, Cpu::disp_SET_0xCBDC));
-        decoder.insert(0xCBDD as u16, (Cpu::instr_SET_0xCBDD, Cpu::disp_SET_0xCBDD));
-        decoder.insert(0xCBDE as u16, (Cpu::instr_SET_0xCBDE, Cpu::disp_SET_0xCBDE));
-        decoder.insert(0xCBDF as u16, (Cpu::instr_SET_0xCBDF, Cpu::disp_SET_0xCBDF));
-        decoder.insert(0xCBE0 as u16, (Cpu::instr_SET_0xCBE0, Cpu::disp_SET_0xCBE0));
-        decoder.insert(0xCBE1 as u16, (Cpu::instr_SET_0xCBE1, Cpu::disp_SET_0xCBE1));
-        decoder.insert(0xCBE2 as u16, (Cpu::instr_SET_0xCBE2, Cpu::disp_SET_0xCBE2));
-        decoder.insert(0xCBE3 as u16, (Cpu::instr_SET_0xCBE3, Cpu::disp_SET_0xCBE3));
-        decoder.insert(0xCBE4 as u16, (Cpu::instr_SET_0xCBE4, Cpu::disp_SET_0xCBE4));
-        decoder.insert(0xCBE5 as u16, (Cpu::instr_SET_0xCBE5, Cpu::disp_SET_0xCBE5));
-        decoder.insert(0xCBE6 as u16, (Cpu::instr_SET_0xCBE6, Cpu::disp_SET_0xCBE6));
-        decoder.insert(0xCBE7 as u16, (Cpu::instr_SET_0xCBE7, Cpu::disp_SET_0xCBE7));
-        decoder.insert(0xCBE8 as u16, (Cpu::instr_SET_0xCBE8, Cpu::disp_SET_0xCBE8));
-        decoder.insert(0xCBE9 as u16, (Cpu::instr_SET_0xCBE9, Cpu::disp_SET_0xCBE9));
-        decoder.insert(0xCBEA as u16, (Cpu::instr_SET_0xCBEA, Cpu::disp_SET_0xCBEA));
-        decoder.insert(0xCBEB as u16, (Cpu::instr_SET_0xCBEB, Cpu::disp_SET_0xCBEB));
-        decoder.insert(0xCBEC as u16, (Cpu::instr_SET_0xCBEC, Cpu::disp_SET_0xCBEC));
-        decoder.insert(0xCBED as u16, (Cpu::instr_SET_0xCBED, Cpu::disp_SET_0xCBED));
-        decoder.insert(0xCBEE as u16, (Cpu::instr_SET_0xCBEE, Cpu::disp_SET_0xCBEE));
-        decoder.insert(0xCBEF as u16, (Cpu::instr_SET_0xCBEF, Cpu::disp_SET_0xCBEF));
-        decoder.insert(0xCBF0 as u16, (Cpu::instr_SET_0xCBF0, Cpu::disp_SET_0xCBF0));
-        decoder.insert(0xCBF1 as u16, (Cpu::instr_SET_0xCBF1, Cpu::disp_SET_0xCBF1));
-        decoder.insert(0xCBF2 as u16, (Cpu::instr_SET_0xCBF2, Cpu::disp_SET_0xCBF2));
-        decoder.insert(0xCBF3 as u16, (Cpu::instr_SET_0xCBF3, Cpu::disp_SET_0xCBF3));
-        decoder.insert(0xCBF4 as u16, (Cpu::instr_SET_0xCBF4, Cpu::disp_SET_0xCBF4));
-        decoder.insert(0xCBF5 as u16, (Cpu::instr_SET_0xCBF5, Cpu::disp_SET_0xCBF5));
-        decoder.insert(0xCBF6 as u16, (Cpu::instr_SET_0xCBF6, Cpu::disp_SET_0xCBF6));
-        decoder.insert(0xCBF7 as u16, (Cpu::instr_SET_0xCBF7, Cpu::disp_SET_0xCBF7));
-        decoder.insert(0xCBF8 as u16, (Cpu::instr_SET_0xCBF8, Cpu::disp_SET_0xCBF8));
-        decoder.insert(0xCBF9 as u16, (Cpu::instr_SET_0xCBF9, Cpu::disp_SET_0xCBF9));
-        decoder.insert(0xCBFA as u16, (Cpu::instr_SET_0xCBFA, Cpu::disp_SET_0xCBFA));
-        decoder.insert(0xCBFB as u16, (Cpu::instr_SET_0xCBFB, Cpu::disp_SET_0xCBFB));
-        decoder.insert(0xCBFC as u16, (Cpu::instr_SET_0xCBFC, Cpu::disp_SET_0xCBFC));
-        decoder.insert(0xCBFD as u16, (Cpu::instr_SET_0xCBFD, Cpu::disp_SET_0xCBFD));
-        decoder.insert(0xCBFE as u16, (Cpu::instr_SET_0xCBFE, Cpu::disp_SET_0xCBFE));
-        decoder.insert(0xCBFF as u16, (Cpu::instr_SET_0xCBFF, Cpu::disp_SET_0xCBFF));
-        decoder
-    };
-}
@@ -1,4 +1,11 @@
 use super::super::*;
+use bus::tests::{Access, FlatBus};
+use mmu;
+use test::Bencher;
+use std::cell::RefCell;
+use std::io;
+use std::io::Write;
+use std::rc::Rc;
 
 #[test]
 fn new() {
@@ -22,7 +29,7 @@ fn new() {
 #[test]
 fn reset() {
     // Check that after a reset, every register is set to 0.
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.a =  1;
     c.b =  2;
@@ -55,7 +62,7 @@ fn reset() {
 
 #[test]
 fn instr_RST_0xE7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -68,7 +75,7 @@ fn instr_RST_0xE7() {
 
 #[test]
 fn instr_RST_0xDF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -81,7 +88,7 @@ fn instr_RST_0xDF() {
 
 #[test]
 fn instr_RST_0xD7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -94,7 +101,7 @@ fn instr_RST_0xD7() {
 
 #[test]
 fn instr_RST_0xEF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -107,7 +114,7 @@ fn instr_RST_0xEF() {
 
 #[test]
 fn instr_RST_0xF7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -120,7 +127,7 @@ fn instr_RST_0xF7() {
 
 #[test]
 fn instr_RST_0xFF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -133,7 +140,7 @@ fn instr_RST_0xFF() {
 
 #[test]
 fn instr_RST_0xC7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -146,7 +153,7 @@ fn instr_RST_0xC7() {
 
 #[test]
 fn instr_RST_0xCF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -159,7 +166,7 @@ fn instr_RST_0xCF() {
 
 #[test]
 fn instr_PUSH_0xD5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.d  = 0x01;
@@ -176,7 +183,7 @@ fn instr_PUSH_0xD5() {
 
 #[test]
 fn instr_PUSH_0xE5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.h  = 0x01;
@@ -193,7 +200,7 @@ fn instr_PUSH_0xE5() {
 
 #[test]
 fn instr_PUSH_0xF5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a  = 0x01;
@@ -210,7 +217,7 @@ fn instr_PUSH_0xF5() {
 
 #[test]
 fn instr_PUSH_0xC5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.b  = 0x01;
@@ -227,7 +234,7 @@ fn instr_PUSH_0xC5() {
 
 #[test]
 fn instr_POP_0xD1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -242,7 +249,7 @@ fn instr_POP_0xD1() {
 
 #[test]
 fn instr_POP_0xE1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -257,22 +264,37 @@ fn instr_POP_0xE1() {
 
 #[test]
 fn instr_POP_0xF1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
     c.sp = 10;
-    c.mmu.write16(10, 0x0102);
+    c.mmu.write16(10, 0x01A0);
     c.instr_POP_0xF1();
     assert_eq!(c.a,  0x01);
-    assert_eq!(c.f,  0x02);
+    assert_eq!(c.f,  0xA0);
     assert_eq!(c.pc, 6);
     assert_eq!(c.sp, 12);
+
+    // The low nibble of f is not writable
+    c.mmu.write16(12, 0x02FF);
+    c.instr_POP_0xF1();
+    assert_eq!(c.a,  0x02);
+    assert_eq!(c.f,  0xF0);
+    assert_eq!(c.sp, 14);
+
+    // Popped flags are kept when sp wraps around
+    c.sp = 0xFFFE;
+    c.mmu.write16(0xFFFE, 0x0350);
+    c.instr_POP_0xF1();
+    assert_eq!(c.a,  0x03);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::Carry as u8);
+    assert_eq!(c.sp, 0x0000);
 }
 
 #[test]
 fn instr_POP_0xC1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.pc = 5;
@@ -287,7 +309,7 @@ fn instr_POP_0xC1() {
 
 #[test]
 fn instr_AND_0xE6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -308,7 +330,7 @@ fn instr_AND_0xE6() {
 
 #[test]
 fn instr_AND_0xA7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -327,7 +349,7 @@ fn instr_AND_0xA7() {
 
 #[test]
 fn instr_AND_0xA6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -352,7 +374,7 @@ fn instr_AND_0xA6() {
 
 #[test]
 fn instr_AND_0xA5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -373,7 +395,7 @@ fn instr_AND_0xA5() {
 
 #[test]
 fn instr_AND_0xA4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -394,7 +416,7 @@ fn instr_AND_0xA4() {
 
 #[test]
 fn instr_AND_0xA3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -415,7 +437,7 @@ fn instr_AND_0xA3() {
 
 #[test]
 fn instr_AND_0xA2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -436,7 +458,7 @@ fn instr_AND_0xA2() {
 
 #[test]
 fn instr_AND_0xA1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -457,7 +479,7 @@ fn instr_AND_0xA1() {
 
 #[test]
 fn instr_AND_0xA0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -478,7 +500,7 @@ fn instr_AND_0xA0() {
 
 #[test]
 fn instr_XOR_0xA9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -499,7 +521,7 @@ fn instr_XOR_0xA9() {
 
 #[test]
 fn instr_XOR_0xA8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -520,7 +542,7 @@ fn instr_XOR_0xA8() {
 
 #[test]
 fn instr_XOR_0xAF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -532,7 +554,7 @@ fn instr_XOR_0xAF() {
 
 #[test]
 fn instr_XOR_0xAE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -557,7 +579,7 @@ fn instr_XOR_0xAE() {
 
 #[test]
 fn instr_XOR_0xAD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -578,7 +600,7 @@ fn instr_XOR_0xAD() {
 
 #[test]
 fn instr_XOR_0xAC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -599,7 +621,7 @@ fn instr_XOR_0xAC() {
 
 #[test]
 fn instr_XOR_0xAB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -620,7 +642,7 @@ fn instr_XOR_0xAB() {
 
 #[test]
 fn instr_XOR_0xAA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -641,7 +663,7 @@ fn instr_XOR_0xAA() {
 
 #[test]
 fn instr_XOR_0xEE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -662,7 +684,7 @@ fn instr_XOR_0xEE() {
 
 #[test]
 fn instr_NOP_0x00() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_NOP_0x00();
@@ -684,7 +706,7 @@ fn instr_NOP_0x00() {
 
 #[test]
 fn instr_OR_0xB2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -705,7 +727,7 @@ fn instr_OR_0xB2() {
 
 #[test]
 fn instr_OR_0xB3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -726,7 +748,7 @@ fn instr_OR_0xB3() {
 
 #[test]
 fn instr_OR_0xB0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -747,7 +769,7 @@ fn instr_OR_0xB0() {
 
 #[test]
 fn instr_OR_0xB1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -768,7 +790,7 @@ fn instr_OR_0xB1() {
 
 #[test]
 fn instr_OR_0xB6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -793,7 +815,7 @@ fn instr_OR_0xB6() {
 
 #[test]
 fn instr_OR_0xB7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -812,7 +834,7 @@ fn instr_OR_0xB7() {
 
 #[test]
 fn instr_OR_0xB4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -833,7 +855,7 @@ fn instr_OR_0xB4() {
 
 #[test]
 fn instr_OR_0xB5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -854,7 +876,7 @@ fn instr_OR_0xB5() {
 
 #[test]
 fn instr_OR_0xF6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b01010101 as u8;
@@ -875,7 +897,7 @@ fn instr_OR_0xF6() {
 
 #[test]
 fn instr_JR_0x28() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.f = Flag::None as u8;
@@ -889,7 +911,7 @@ fn instr_JR_0x28() {
     c.f = Flag::Zero as u8;
     c.mmu.write8(1, 5);
     c.instr_JR_0x28();
-    assert_eq!(c.pc, 5);
+    assert_eq!(c.pc, 7);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 
@@ -898,14 +920,14 @@ fn instr_JR_0x28() {
     c.f = Flag::Zero as u8;
     c.mmu.write8(6, -5 as u8);
     c.instr_JR_0x28();
-    assert_eq!(c.pc, 0);
+    assert_eq!(c.pc, 2);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 }
 
 #[test]
 fn instr_JR_0x20() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.f = Flag::Zero as u8;
@@ -919,7 +941,7 @@ fn instr_JR_0x20() {
     c.f = Flag::None as u8;
     c.mmu.write8(1, 5);
     c.instr_JR_0x20();
-    assert_eq!(c.pc, 5);
+    assert_eq!(c.pc, 7);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 
@@ -928,14 +950,14 @@ fn instr_JR_0x20() {
     c.f = Flag::None as u8;
     c.mmu.write8(6, -5 as u8);
     c.instr_JR_0x20();
-    assert_eq!(c.pc, 0);
+    assert_eq!(c.pc, 2);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 }
 
 #[test]
 fn instr_JR_0x30() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.f = Flag::Carry as u8;
@@ -949,7 +971,7 @@ fn instr_JR_0x30() {
     c.f = Flag::None as u8;
     c.mmu.write8(1, 5);
     c.instr_JR_0x30();
-    assert_eq!(c.pc, 5);
+    assert_eq!(c.pc, 7);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 
@@ -958,14 +980,14 @@ fn instr_JR_0x30() {
     c.f = Flag::None as u8;
     c.mmu.write8(6, -5 as u8);
     c.instr_JR_0x30();
-    assert_eq!(c.pc, 0);
+    assert_eq!(c.pc, 2);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 }
 
 #[test]
 fn instr_JR_0x38() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.f = Flag::None as u8;
@@ -979,7 +1001,7 @@ fn instr_JR_0x38() {
     c.f = Flag::Carry as u8;
     c.mmu.write8(1, 5);
     c.instr_JR_0x38();
-    assert_eq!(c.pc, 5);
+    assert_eq!(c.pc, 7);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 
@@ -988,19 +1010,19 @@ fn instr_JR_0x38() {
     c.f = Flag::Carry as u8;
     c.mmu.write8(6, -5 as u8);
     c.instr_JR_0x38();
-    assert_eq!(c.pc, 0);
+    assert_eq!(c.pc, 2);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 }
 
 #[test]
 fn instr_JR_0x18() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.mmu.write8(1, 5);
     c.instr_JR_0x18();
-    assert_eq!(c.pc, 5);
+    assert_eq!(c.pc, 7);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 
@@ -1008,14 +1030,14 @@ fn instr_JR_0x18() {
     c.pc = 5;
     c.mmu.write8(6, -5 as u8);
     c.instr_JR_0x18();
-    assert_eq!(c.pc, 0);
+    assert_eq!(c.pc, 2);
     assert_eq!(c.m, 3);
     assert_eq!(c.t, 12);
 }
 
 #[test]
 fn instr_SET_0xCBC0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.b = 0b00000000;
@@ -1026,7 +1048,7 @@ fn instr_SET_0xCBC0() {
 
 #[test]
 fn instr_SET_0xCBC1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.c = 0b00000000;
@@ -1037,7 +1059,7 @@ fn instr_SET_0xCBC1() {
 
 #[test]
 fn instr_SET_0xCBC3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.e = 0b00000000;
@@ -1048,7 +1070,7 @@ fn instr_SET_0xCBC3() {
 
 #[test]
 fn instr_SET_0xCBC4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.h = 0b00000000;
@@ -1059,7 +1081,7 @@ fn instr_SET_0xCBC4() {
 
 #[test]
 fn instr_SET_0xCBC5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.l = 0b00000000;
@@ -1070,7 +1092,7 @@ fn instr_SET_0xCBC5() {
 
 #[test]
 fn instr_SET_0xCBC7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 0b00000000;
@@ -1081,7 +1103,7 @@ fn instr_SET_0xCBC7() {
 
 #[test]
 fn instr_SET_0xCBC6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.mmu.write8(0x0005, 0b00000000);
@@ -1094,7 +1116,7 @@ fn instr_SET_0xCBC6() {
 
 #[test]
 fn instr_SUB_0x95() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1102,7 +1124,7 @@ fn instr_SUB_0x95() {
     c.instr_SUB_0x95();
     assert_eq!(c.a, 10);
     assert_eq!(c.l, 42);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1129,13 +1151,13 @@ fn instr_SUB_0x95() {
     c.instr_SUB_0x95();
     assert_eq!(c.a, 255);
     assert_eq!(c.l, 11);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn instr_SUB_0x94() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1143,7 +1165,7 @@ fn instr_SUB_0x94() {
     c.instr_SUB_0x94();
     assert_eq!(c.a, 10);
     assert_eq!(c.h, 42);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1170,13 +1192,13 @@ fn instr_SUB_0x94() {
     c.instr_SUB_0x94();
     assert_eq!(c.a, 255);
     assert_eq!(c.h, 11);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn instr_SUB_0x90() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1184,7 +1206,7 @@ fn instr_SUB_0x90() {
     c.instr_SUB_0x90();
     assert_eq!(c.a, 10);
     assert_eq!(c.b, 42);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1211,13 +1233,13 @@ fn instr_SUB_0x90() {
     c.instr_SUB_0x90();
     assert_eq!(c.a, 255);
     assert_eq!(c.b, 11);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn instr_SUB_0x91() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1225,7 +1247,7 @@ fn instr_SUB_0x91() {
     c.instr_SUB_0x91();
     assert_eq!(c.a, 10);
     assert_eq!(c.c, 42);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1252,13 +1274,13 @@ fn instr_SUB_0x91() {
     c.instr_SUB_0x91();
     assert_eq!(c.a, 255);
     assert_eq!(c.c, 11);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn instr_SUB_0x92() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1266,7 +1288,7 @@ fn instr_SUB_0x92() {
     c.instr_SUB_0x92();
     assert_eq!(c.a, 10);
     assert_eq!(c.d, 42);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1293,13 +1315,13 @@ fn instr_SUB_0x92() {
     c.instr_SUB_0x92();
     assert_eq!(c.a, 255);
     assert_eq!(c.d, 11);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn instr_SUB_0x97() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 10;
@@ -1312,14 +1334,14 @@ fn instr_SUB_0x97() {
 
 #[test]
 fn instr_SUB_0xD6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
     c.mmu.write8(1, 42);
     c.instr_SUB_0xD6();
     assert_eq!(c.a, 10);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 2);
 
     c.reset();
@@ -1343,13 +1365,13 @@ fn instr_SUB_0xD6() {
     c.mmu.write8(1, 11);
     c.instr_SUB_0xD6();
     assert_eq!(c.a, 255);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 2);
 }
 
 #[test]
 fn instr_SUB_0x96() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.a = 52;
@@ -1358,7 +1380,7 @@ fn instr_SUB_0x96() {
     c.l = 0x37;
     c.instr_SUB_0x96();
     assert_eq!(c.a, 10);
-    assert_eq!(c.f, Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 
     c.reset();
@@ -1388,146 +1410,271 @@ fn instr_SUB_0x96() {
     c.l = 0x37;
     c.instr_SUB_0x96();
     assert_eq!(c.a, 255);
-    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8);
+    assert_eq!(c.f, Flag::Carry as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
     assert_eq!(c.pc, 1);
 }
 
 #[test]
 fn sum_ints() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
     // Load program in memory
     c.mmu.write8(0, 0x06);          // 00 LD    B,  5
     c.mmu.write8(1,   10);          // 01 ^5
     c.mmu.write8(2, 0xAF);          // 02 XOR   A,  A
     c.mmu.write8(3, 0x80);          // 03 ADD   A,  B
     c.mmu.write8(4, 0x05);          // 04 DEC   B
-    c.mmu.write8(5, 0x20);          // 05 JRNZ -2
-    c.mmu.write8(6,   -2 as u8);    // 06 ^-2
+    c.mmu.write8(5, 0x20);          // 05 JRNZ -4
+    c.mmu.write8(6,   -4 as u8);    // 06 ^-4
     c.mmu.write8(7, 0x10);          // 07 STOP
     // Run it
     c.run();
+    assert_eq!(c.a, 55);
 }
 
 #[test]
 fn instr_ADC_0x88() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.b  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x88();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x89() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.c  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x89();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.d  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.e  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.h  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.l  = 0x0F;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x0F);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0x8F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x88;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0x8F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x11);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_ADC_0xCE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xE1;
+    c.mmu.write8(6, 0x0F);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADC_0xCE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF1);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_ADD_0x09() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x0F;
+    c.l  = 0xFF;
+    c.b  = 0x00;
+    c.c  = 0x01;
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8 | Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADD_0x09();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x10);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.h  = 0xF0;
+    c.l  = 0x00;
+    c.b  = 0x10;
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.instr_ADD_0x09();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Carry as u8);
 }
 
 #[test]
 fn instr_ADD_0x19() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x0F;
+    c.l  = 0xFF;
+    c.d  = 0x00;
+    c.e  = 0x01;
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8 | Flag::Carry as u8;
+    c.pc = 5;
     c.instr_ADD_0x19();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x10);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.h  = 0xF0;
+    c.l  = 0x00;
+    c.d  = 0x10;
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.instr_ADD_0x19();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Carry as u8);
 }
 
 #[test]
 fn instr_ADD_0x29() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x88;
+    c.l  = 0x00;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
     c.instr_ADD_0x29();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x10);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.h  = 0x01;
+    c.l  = 0x01;
+    c.instr_ADD_0x29();
+    assert_eq!(c.h,  0x02);
+    assert_eq!(c.l,  0x02);
+    assert_eq!(c.f,  Flag::None as u8);
 }
 
 #[test]
 fn instr_ADD_0x39() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x12;
+    c.l  = 0x34;
+    c.sp = 0x0FCC;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
     c.instr_ADD_0x39();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x22);
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.sp, 0x0FCC);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.h  = 0xFF;
+    c.l  = 0xFF;
+    c.sp = 0x0002;
+    c.instr_ADD_0x39();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.l,  0x01);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
 }
 
 #[test]
 fn instr_ADD_0x80() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x80();
@@ -1536,7 +1683,7 @@ fn instr_ADD_0x80() {
 
 #[test]
 fn instr_ADD_0x81() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x81();
@@ -1545,7 +1692,7 @@ fn instr_ADD_0x81() {
 
 #[test]
 fn instr_ADD_0x82() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x82();
@@ -1554,7 +1701,7 @@ fn instr_ADD_0x82() {
 
 #[test]
 fn instr_ADD_0x83() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x83();
@@ -1563,7 +1710,7 @@ fn instr_ADD_0x83() {
 
 #[test]
 fn instr_ADD_0x84() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x84();
@@ -1572,7 +1719,7 @@ fn instr_ADD_0x84() {
 
 #[test]
 fn instr_ADD_0x85() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x85();
@@ -1581,7 +1728,7 @@ fn instr_ADD_0x85() {
 
 #[test]
 fn instr_ADD_0x86() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x86();
@@ -1590,7 +1737,7 @@ fn instr_ADD_0x86() {
 
 #[test]
 fn instr_ADD_0x87() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0x87();
@@ -1599,7 +1746,7 @@ fn instr_ADD_0x87() {
 
 #[test]
 fn instr_ADD_0xC6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_ADD_0xC6();
@@ -1608,556 +1755,1273 @@ fn instr_ADD_0xC6() {
 
 #[test]
 fn instr_ADD_0xE8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.sp = 0x00FF;
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8;
+    c.pc = 5;
+    c.mmu.memory[6] = 0x01;
     c.instr_ADD_0xE8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.sp, 0x0100);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    // Carries come from the low byte, even for negative offsets
+    c.sp = 0x1000;
+    c.pc = 5;
+    c.mmu.memory[6] = 0xFF;
+    c.instr_ADD_0xE8();
+    assert_eq!(c.sp, 0x0FFF);
+    assert_eq!(c.f,  Flag::None as u8);
+
+    c.sp = 0x1008;
+    c.pc = 5;
+    c.mmu.memory[6] = 0xF8;
+    c.instr_ADD_0xE8();
+    assert_eq!(c.sp, 0x1000);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    // Zero is never set
+    c.sp = 0xFFF8;
+    c.pc = 5;
+    c.mmu.memory[6] = 0x08;
+    c.instr_ADD_0xE8();
+    assert_eq!(c.sp, 0x0000);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB40() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB40();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB40();
+    assert_eq!(c.b,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB41() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB41();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB41();
+    assert_eq!(c.c,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB42() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB42();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB42();
+    assert_eq!(c.d,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB43() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB43();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB43();
+    assert_eq!(c.e,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB44() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB44();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB44();
+    assert_eq!(c.h,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB45() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB45();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB45();
+    assert_eq!(c.l,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB46() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x01);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB46();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFE);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB46();
+    assert_eq!(c.mmu.read8(0x0100), 0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB47() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB47();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xFE;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB47();
+    assert_eq!(c.a,  0xFE);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB48() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB48();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB48();
+    assert_eq!(c.b,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB49() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB49();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB49();
+    assert_eq!(c.c,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4A();
+    assert_eq!(c.d,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4B();
+    assert_eq!(c.e,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4C();
+    assert_eq!(c.h,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4D();
+    assert_eq!(c.l,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x02);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFD);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4E();
+    assert_eq!(c.mmu.read8(0x0100), 0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB4F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x02;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB4F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xFD;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB4F();
+    assert_eq!(c.a,  0xFD);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB50() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB50();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB50();
+    assert_eq!(c.b,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB51() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB51();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB51();
+    assert_eq!(c.c,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB52() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB52();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB52();
+    assert_eq!(c.d,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB53() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB53();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB53();
+    assert_eq!(c.e,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB54() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB54();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB54();
+    assert_eq!(c.h,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB55() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB55();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB55();
+    assert_eq!(c.l,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB56() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x04);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB56();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFB);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB56();
+    assert_eq!(c.mmu.read8(0x0100), 0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB57() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x04;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB57();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xFB;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB57();
+    assert_eq!(c.a,  0xFB);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB58() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB58();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB58();
+    assert_eq!(c.b,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB59() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB59();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB59();
+    assert_eq!(c.c,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5A();
+    assert_eq!(c.d,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5B();
+    assert_eq!(c.e,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5C();
+    assert_eq!(c.h,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5D();
+    assert_eq!(c.l,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x08);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xF7);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5E();
+    assert_eq!(c.mmu.read8(0x0100), 0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB5F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x08;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB5F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xF7;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB5F();
+    assert_eq!(c.a,  0xF7);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB60() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB60();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB60();
+    assert_eq!(c.b,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB61() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB61();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB61();
+    assert_eq!(c.c,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB62() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB62();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB62();
+    assert_eq!(c.d,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB63() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB63();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB63();
+    assert_eq!(c.e,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB64() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB64();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB64();
+    assert_eq!(c.h,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB65() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB65();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB65();
+    assert_eq!(c.l,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB66() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x10);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB66();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xEF);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB66();
+    assert_eq!(c.mmu.read8(0x0100), 0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB67() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB67();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xEF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB67();
+    assert_eq!(c.a,  0xEF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB68() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB68();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB68();
+    assert_eq!(c.b,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB69() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB69();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB69();
+    assert_eq!(c.c,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6A();
+    assert_eq!(c.d,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6B();
+    assert_eq!(c.e,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6C();
+    assert_eq!(c.h,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6D();
+    assert_eq!(c.l,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x20);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xDF);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6E();
+    assert_eq!(c.mmu.read8(0x0100), 0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB6F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x20;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB6F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xDF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB6F();
+    assert_eq!(c.a,  0xDF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB70() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB70();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB70();
+    assert_eq!(c.b,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB71() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB71();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB71();
+    assert_eq!(c.c,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB72() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB72();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB72();
+    assert_eq!(c.d,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB73() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB73();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB73();
+    assert_eq!(c.e,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB74() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB74();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB74();
+    assert_eq!(c.h,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB75() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB75();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB75();
+    assert_eq!(c.l,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB76() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x40);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB76();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xBF);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB76();
+    assert_eq!(c.mmu.read8(0x0100), 0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB77() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x40;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB77();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0xBF;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB77();
+    assert_eq!(c.a,  0xBF);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB78() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB78();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB78();
+    assert_eq!(c.b,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB79() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB79();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB79();
+    assert_eq!(c.c,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB7A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB7A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB7A();
+    assert_eq!(c.d,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB7B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB7B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB7B();
+    assert_eq!(c.e,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB7C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_BIT_0xCB7C();
@@ -2166,169 +3030,323 @@ fn instr_BIT_0xCB7C() {
 
 #[test]
 fn instr_BIT_0xCB7D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB7D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB7D();
+    assert_eq!(c.l,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB7E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x80);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB7E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x7F);
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB7E();
+    assert_eq!(c.mmu.read8(0x0100), 0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_BIT_0xCB7F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x80;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_BIT_0xCB7F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x7F;
+    c.f  = Flag::Operation as u8;
+    c.pc = 5;
+    c.instr_BIT_0xCB7F();
+    assert_eq!(c.a,  0x7F);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8);
 }
 
 #[test]
 fn instr_CALL_0xC4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
+    c.sp = 10;
     c.instr_CALL_0xC4();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 8);
+    assert_eq!(c.sp, 10);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 10;
+    c.instr_CALL_0xC4();
+    assert_eq!(c.mmu.read16(8), 8);
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 8);
 }
 
 #[test]
 fn instr_CALL_0xCC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 10;
     c.instr_CALL_0xCC();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 8);
+    assert_eq!(c.sp, 10);
+
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
+    c.sp = 10;
+    c.instr_CALL_0xCC();
+    assert_eq!(c.mmu.read16(8), 8);
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 8);
 }
 
 #[test]
 fn instr_CALL_0xCD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.pc = 5;
+    c.sp = 10;
     c.instr_CALL_0xCD();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read16(8), 8);
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 8);
 }
 
 #[test]
 fn instr_CALL_0xD4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.sp = 10;
     c.instr_CALL_0xD4();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 8);
+    assert_eq!(c.sp, 10);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 10;
+    c.instr_CALL_0xD4();
+    assert_eq!(c.mmu.read16(8), 8);
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 8);
 }
 
 #[test]
 fn instr_CALL_0xDC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 10;
     c.instr_CALL_0xDC();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 8);
+    assert_eq!(c.sp, 10);
+
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.sp = 10;
+    c.instr_CALL_0xDC();
+    assert_eq!(c.mmu.read16(8), 8);
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 8);
 }
 
 #[test]
 fn instr_CCF_0x3F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8;
+    c.pc = 5;
     c.instr_CCF_0x3F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.instr_CCF_0x3F();
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_CP_0xB8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.b  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xB8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xB9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.c  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xB9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.d  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBA();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.e  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBB();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.h  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBC();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.l  = 0x2F;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBD();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x2F);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xBF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_CP_0xBF();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_CP_0xFE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x3C;
+    c.mmu.write8(6, 0x2F);
+    c.pc = 5;
     c.instr_CP_0xFE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x3C);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_CPL_0x2F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_CPL_0x2F();
@@ -2337,25 +3355,100 @@ fn instr_CPL_0x2F() {
 
 #[test]
 fn instr_DAA_0x27() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    // 0x45 + 0x38 = 0x7D
+    c.a  = 0x7D;
     c.instr_DAA_0x27();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x83);
+    assert_eq!(c.f,  Flag::None as u8);
+
+    // 0x83 - 0x38 = 0x4B
+    c.a  = 0x4B;
+    c.f  = Flag::Operation as u8 | Flag::HalfCarry as u8;
+    c.instr_DAA_0x27();
+    assert_eq!(c.a,  0x45);
+    assert_eq!(c.f,  Flag::Operation as u8);
+
+    // 0x99 + 0x01 = 0x9A
+    c.a  = 0x9A;
+    c.f  = Flag::None as u8;
+    c.instr_DAA_0x27();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Carry as u8);
+}
+
+#[test]
+fn daa_after_add() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    // 19 + 28 = 47, carrying out of the low digit
+    c.reset();
+    c.a = 0x19;
+    c.b = 0x28;
+    c.instr_ADD_0x80();
+    assert_eq!(c.a, 0x41);
+    assert_eq!(c.f, Flag::HalfCarry as u8);
+    c.instr_DAA_0x27();
+    assert_eq!(c.a, 0x47);
+    assert_eq!(c.f, Flag::None as u8);
+
+    // 65 + 35 = 100
+    c.a = 0x65;
+    c.b = 0x35;
+    c.instr_ADD_0x80();
+    c.instr_DAA_0x27();
+    assert_eq!(c.a, 0x00);
+    assert_eq!(c.f, Flag::Zero as u8 | Flag::Carry as u8);
+}
+
+#[test]
+fn daa_after_sub() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    // 42 - 19 = 23, borrowing from the high digit
+    c.reset();
+    c.a = 0x42;
+    c.b = 0x19;
+    c.instr_SUB_0x90();
+    assert_eq!(c.a, 0x29);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::HalfCarry as u8);
+    c.instr_DAA_0x27();
+    assert_eq!(c.a, 0x23);
+    assert_eq!(c.f, Flag::Operation as u8);
+
+    // 10 - 20 = 90, borrowing 100
+    c.a = 0x10;
+    c.b = 0x20;
+    c.instr_SUB_0x90();
+    c.instr_DAA_0x27();
+    assert_eq!(c.a, 0x90);
+    assert_eq!(c.f, Flag::Operation as u8 | Flag::Carry as u8);
 }
 
 #[test]
 fn instr_DEC_0x05() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_DEC_0x05();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x0F);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.b  = 0x01;
+    c.instr_DEC_0x05();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 | Flag::Carry as u8);
 }
 
 #[test]
 fn instr_DEC_0x0B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x0B();
@@ -2364,7 +3457,7 @@ fn instr_DEC_0x0B() {
 
 #[test]
 fn instr_DEC_0x0D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x0D();
@@ -2373,7 +3466,7 @@ fn instr_DEC_0x0D() {
 
 #[test]
 fn instr_DEC_0x15() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x15();
@@ -2382,7 +3475,7 @@ fn instr_DEC_0x15() {
 
 #[test]
 fn instr_DEC_0x1B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x1B();
@@ -2391,7 +3484,7 @@ fn instr_DEC_0x1B() {
 
 #[test]
 fn instr_DEC_0x1D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x1D();
@@ -2400,7 +3493,7 @@ fn instr_DEC_0x1D() {
 
 #[test]
 fn instr_DEC_0x25() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x25();
@@ -2409,7 +3502,7 @@ fn instr_DEC_0x25() {
 
 #[test]
 fn instr_DEC_0x2B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x2B();
@@ -2418,7 +3511,7 @@ fn instr_DEC_0x2B() {
 
 #[test]
 fn instr_DEC_0x2D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x2D();
@@ -2427,16 +3520,22 @@ fn instr_DEC_0x2D() {
 
 #[test]
 fn instr_DEC_0x35() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xC0;
+    c.l  = 0x00;
+    c.mmu.memory[0xC000] = 0x30;
+    c.pc = 5;
     c.instr_DEC_0x35();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.memory[0xC000], 0x2F);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_DEC_0x3B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x3B();
@@ -2445,7 +3544,7 @@ fn instr_DEC_0x3B() {
 
 #[test]
 fn instr_DEC_0x3D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_DEC_0x3D();
@@ -2454,34 +3553,42 @@ fn instr_DEC_0x3D() {
 
 #[test]
 fn instr_DI_0xF3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.ime = true;
+    c.pc = 5;
     c.instr_DI_0xF3();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.ime, false);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_EI_0xFB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.pc = 5;
     c.instr_EI_0xFB();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.ime, false);
+    assert_eq!(c.ime_pending, true);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_HALT_0x76() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.pc = 5;
     c.instr_HALT_0x76();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.halt, true);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_INC_0x03() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x03();
@@ -2490,16 +3597,30 @@ fn instr_INC_0x03() {
 
 #[test]
 fn instr_INC_0x04() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x0F;
+    c.f  = Flag::Operation as u8 | Flag::Carry as u8;
+    c.pc = 5;
     c.instr_INC_0x04();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x10);
+    assert_eq!(c.f,  Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.b  = 0xFF;
+    c.instr_INC_0x04();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.instr_INC_0x04();
+    assert_eq!(c.b,  0x01);
+    assert_eq!(c.f,  Flag::Carry as u8);
 }
 
 #[test]
 fn instr_INC_0x0C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x0C();
@@ -2508,7 +3629,7 @@ fn instr_INC_0x0C() {
 
 #[test]
 fn instr_INC_0x13() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x13();
@@ -2517,7 +3638,7 @@ fn instr_INC_0x13() {
 
 #[test]
 fn instr_INC_0x14() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x14();
@@ -2526,7 +3647,7 @@ fn instr_INC_0x14() {
 
 #[test]
 fn instr_INC_0x1C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x1C();
@@ -2535,7 +3656,7 @@ fn instr_INC_0x1C() {
 
 #[test]
 fn instr_INC_0x23() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x23();
@@ -2544,7 +3665,7 @@ fn instr_INC_0x23() {
 
 #[test]
 fn instr_INC_0x24() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x24();
@@ -2553,7 +3674,7 @@ fn instr_INC_0x24() {
 
 #[test]
 fn instr_INC_0x2C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x2C();
@@ -2562,7 +3683,7 @@ fn instr_INC_0x2C() {
 
 #[test]
 fn instr_INC_0x33() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x33();
@@ -2571,16 +3692,22 @@ fn instr_INC_0x33() {
 
 #[test]
 fn instr_INC_0x34() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xC0;
+    c.l  = 0x00;
+    c.mmu.memory[0xC000] = 0x2F;
+    c.pc = 5;
     c.instr_INC_0x34();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.memory[0xC000], 0x30);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_INC_0x3C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_INC_0x3C();
@@ -2589,61 +3716,98 @@ fn instr_INC_0x3C() {
 
 #[test]
 fn instr_JP_0xC2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_JP_0xC2();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
+    c.instr_JP_0xC2();
+    assert_eq!(c.pc, 8);
 }
 
 #[test]
 fn instr_JP_0xC3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.pc = 5;
     c.instr_JP_0xC3();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
 }
 
 #[test]
 fn instr_JP_0xCA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
     c.instr_JP_0xCA();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_JP_0xCA();
+    assert_eq!(c.pc, 8);
 }
 
 #[test]
 fn instr_JP_0xD2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
     c.instr_JP_0xD2();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.instr_JP_0xD2();
+    assert_eq!(c.pc, 8);
 }
 
 #[test]
 fn instr_JP_0xDA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(6, 0x1234);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_JP_0xDA();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_JP_0xDA();
+    assert_eq!(c.pc, 8);
 }
 
 #[test]
 fn instr_JP_0xE9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x12;
+    c.l  = 0x34;
+    c.pc = 5;
     c.instr_JP_0xE9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
 }
 
 #[test]
 fn instr_LD_0x01() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x01();
@@ -2652,7 +3816,7 @@ fn instr_LD_0x01() {
 
 #[test]
 fn instr_LD_0x02() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x02();
@@ -2661,7 +3825,7 @@ fn instr_LD_0x02() {
 
 #[test]
 fn instr_LD_0x06() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x06();
@@ -2670,7 +3834,7 @@ fn instr_LD_0x06() {
 
 #[test]
 fn instr_LD_0x08() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x08();
@@ -2679,7 +3843,7 @@ fn instr_LD_0x08() {
 
 #[test]
 fn instr_LD_0x0A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x0A();
@@ -2688,7 +3852,7 @@ fn instr_LD_0x0A() {
 
 #[test]
 fn instr_LD_0x0E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x0E();
@@ -2697,7 +3861,7 @@ fn instr_LD_0x0E() {
 
 #[test]
 fn instr_LD_0x11() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x11();
@@ -2706,7 +3870,7 @@ fn instr_LD_0x11() {
 
 #[test]
 fn instr_LD_0x12() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x12();
@@ -2715,7 +3879,7 @@ fn instr_LD_0x12() {
 
 #[test]
 fn instr_LD_0x16() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x16();
@@ -2724,7 +3888,7 @@ fn instr_LD_0x16() {
 
 #[test]
 fn instr_LD_0x1A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x1A();
@@ -2733,7 +3897,7 @@ fn instr_LD_0x1A() {
 
 #[test]
 fn instr_LD_0x1E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x1E();
@@ -2742,7 +3906,7 @@ fn instr_LD_0x1E() {
 
 #[test]
 fn instr_LD_0x21() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x21();
@@ -2751,7 +3915,7 @@ fn instr_LD_0x21() {
 
 #[test]
 fn instr_LD_0x22() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x22();
@@ -2760,7 +3924,7 @@ fn instr_LD_0x22() {
 
 #[test]
 fn instr_LD_0x26() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x26();
@@ -2769,7 +3933,7 @@ fn instr_LD_0x26() {
 
 #[test]
 fn instr_LD_0x2A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x2A();
@@ -2778,7 +3942,7 @@ fn instr_LD_0x2A() {
 
 #[test]
 fn instr_LD_0x2E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x2E();
@@ -2787,7 +3951,7 @@ fn instr_LD_0x2E() {
 
 #[test]
 fn instr_LD_0x31() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x31();
@@ -2796,7 +3960,7 @@ fn instr_LD_0x31() {
 
 #[test]
 fn instr_LD_0x32() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x32();
@@ -2805,7 +3969,7 @@ fn instr_LD_0x32() {
 
 #[test]
 fn instr_LD_0x36() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x36();
@@ -2814,7 +3978,7 @@ fn instr_LD_0x36() {
 
 #[test]
 fn instr_LD_0x3A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x3A();
@@ -2823,7 +3987,7 @@ fn instr_LD_0x3A() {
 
 #[test]
 fn instr_LD_0x3E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x3E();
@@ -2832,7 +3996,7 @@ fn instr_LD_0x3E() {
 
 #[test]
 fn instr_LD_0x40() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x40();
@@ -2841,7 +4005,7 @@ fn instr_LD_0x40() {
 
 #[test]
 fn instr_LD_0x41() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x41();
@@ -2850,7 +4014,7 @@ fn instr_LD_0x41() {
 
 #[test]
 fn instr_LD_0x42() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x42();
@@ -2859,7 +4023,7 @@ fn instr_LD_0x42() {
 
 #[test]
 fn instr_LD_0x43() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x43();
@@ -2868,7 +4032,7 @@ fn instr_LD_0x43() {
 
 #[test]
 fn instr_LD_0x44() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x44();
@@ -2877,7 +4041,7 @@ fn instr_LD_0x44() {
 
 #[test]
 fn instr_LD_0x45() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x45();
@@ -2886,7 +4050,7 @@ fn instr_LD_0x45() {
 
 #[test]
 fn instr_LD_0x46() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x46();
@@ -2895,7 +4059,7 @@ fn instr_LD_0x46() {
 
 #[test]
 fn instr_LD_0x47() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x47();
@@ -2904,7 +4068,7 @@ fn instr_LD_0x47() {
 
 #[test]
 fn instr_LD_0x48() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x48();
@@ -2913,7 +4077,7 @@ fn instr_LD_0x48() {
 
 #[test]
 fn instr_LD_0x49() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x49();
@@ -2922,7 +4086,7 @@ fn instr_LD_0x49() {
 
 #[test]
 fn instr_LD_0x4A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4A();
@@ -2931,7 +4095,7 @@ fn instr_LD_0x4A() {
 
 #[test]
 fn instr_LD_0x4B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4B();
@@ -2940,7 +4104,7 @@ fn instr_LD_0x4B() {
 
 #[test]
 fn instr_LD_0x4C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4C();
@@ -2949,7 +4113,7 @@ fn instr_LD_0x4C() {
 
 #[test]
 fn instr_LD_0x4D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4D();
@@ -2958,7 +4122,7 @@ fn instr_LD_0x4D() {
 
 #[test]
 fn instr_LD_0x4E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4E();
@@ -2967,7 +4131,7 @@ fn instr_LD_0x4E() {
 
 #[test]
 fn instr_LD_0x4F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x4F();
@@ -2976,7 +4140,7 @@ fn instr_LD_0x4F() {
 
 #[test]
 fn instr_LD_0x50() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x50();
@@ -2985,7 +4149,7 @@ fn instr_LD_0x50() {
 
 #[test]
 fn instr_LD_0x51() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x51();
@@ -2994,7 +4158,7 @@ fn instr_LD_0x51() {
 
 #[test]
 fn instr_LD_0x52() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x52();
@@ -3003,7 +4167,7 @@ fn instr_LD_0x52() {
 
 #[test]
 fn instr_LD_0x53() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x53();
@@ -3012,7 +4176,7 @@ fn instr_LD_0x53() {
 
 #[test]
 fn instr_LD_0x54() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x54();
@@ -3021,7 +4185,7 @@ fn instr_LD_0x54() {
 
 #[test]
 fn instr_LD_0x55() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x55();
@@ -3030,7 +4194,7 @@ fn instr_LD_0x55() {
 
 #[test]
 fn instr_LD_0x56() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x56();
@@ -3039,7 +4203,7 @@ fn instr_LD_0x56() {
 
 #[test]
 fn instr_LD_0x57() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x57();
@@ -3048,7 +4212,7 @@ fn instr_LD_0x57() {
 
 #[test]
 fn instr_LD_0x58() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x58();
@@ -3057,7 +4221,7 @@ fn instr_LD_0x58() {
 
 #[test]
 fn instr_LD_0x59() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x59();
@@ -3066,7 +4230,7 @@ fn instr_LD_0x59() {
 
 #[test]
 fn instr_LD_0x5A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5A();
@@ -3075,7 +4239,7 @@ fn instr_LD_0x5A() {
 
 #[test]
 fn instr_LD_0x5B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5B();
@@ -3084,7 +4248,7 @@ fn instr_LD_0x5B() {
 
 #[test]
 fn instr_LD_0x5C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5C();
@@ -3093,7 +4257,7 @@ fn instr_LD_0x5C() {
 
 #[test]
 fn instr_LD_0x5D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5D();
@@ -3102,7 +4266,7 @@ fn instr_LD_0x5D() {
 
 #[test]
 fn instr_LD_0x5E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5E();
@@ -3111,7 +4275,7 @@ fn instr_LD_0x5E() {
 
 #[test]
 fn instr_LD_0x5F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x5F();
@@ -3120,7 +4284,7 @@ fn instr_LD_0x5F() {
 
 #[test]
 fn instr_LD_0x60() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x60();
@@ -3129,7 +4293,7 @@ fn instr_LD_0x60() {
 
 #[test]
 fn instr_LD_0x61() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x61();
@@ -3138,7 +4302,7 @@ fn instr_LD_0x61() {
 
 #[test]
 fn instr_LD_0x62() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x62();
@@ -3147,7 +4311,7 @@ fn instr_LD_0x62() {
 
 #[test]
 fn instr_LD_0x63() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x63();
@@ -3156,7 +4320,7 @@ fn instr_LD_0x63() {
 
 #[test]
 fn instr_LD_0x64() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x64();
@@ -3165,7 +4329,7 @@ fn instr_LD_0x64() {
 
 #[test]
 fn instr_LD_0x65() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x65();
@@ -3174,7 +4338,7 @@ fn instr_LD_0x65() {
 
 #[test]
 fn instr_LD_0x66() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x66();
@@ -3183,7 +4347,7 @@ fn instr_LD_0x66() {
 
 #[test]
 fn instr_LD_0x67() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x67();
@@ -3192,7 +4356,7 @@ fn instr_LD_0x67() {
 
 #[test]
 fn instr_LD_0x68() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x68();
@@ -3201,7 +4365,7 @@ fn instr_LD_0x68() {
 
 #[test]
 fn instr_LD_0x69() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x69();
@@ -3210,7 +4374,7 @@ fn instr_LD_0x69() {
 
 #[test]
 fn instr_LD_0x6A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6A();
@@ -3219,7 +4383,7 @@ fn instr_LD_0x6A() {
 
 #[test]
 fn instr_LD_0x6B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6B();
@@ -3228,7 +4392,7 @@ fn instr_LD_0x6B() {
 
 #[test]
 fn instr_LD_0x6C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6C();
@@ -3237,7 +4401,7 @@ fn instr_LD_0x6C() {
 
 #[test]
 fn instr_LD_0x6D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6D();
@@ -3246,7 +4410,7 @@ fn instr_LD_0x6D() {
 
 #[test]
 fn instr_LD_0x6E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6E();
@@ -3255,7 +4419,7 @@ fn instr_LD_0x6E() {
 
 #[test]
 fn instr_LD_0x6F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x6F();
@@ -3264,7 +4428,7 @@ fn instr_LD_0x6F() {
 
 #[test]
 fn instr_LD_0x70() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x70();
@@ -3273,7 +4437,7 @@ fn instr_LD_0x70() {
 
 #[test]
 fn instr_LD_0x71() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x71();
@@ -3282,7 +4446,7 @@ fn instr_LD_0x71() {
 
 #[test]
 fn instr_LD_0x72() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x72();
@@ -3291,7 +4455,7 @@ fn instr_LD_0x72() {
 
 #[test]
 fn instr_LD_0x73() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x73();
@@ -3300,7 +4464,7 @@ fn instr_LD_0x73() {
 
 #[test]
 fn instr_LD_0x74() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x74();
@@ -3309,7 +4473,7 @@ fn instr_LD_0x74() {
 
 #[test]
 fn instr_LD_0x75() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x75();
@@ -3318,7 +4482,7 @@ fn instr_LD_0x75() {
 
 #[test]
 fn instr_LD_0x77() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x77();
@@ -3327,7 +4491,7 @@ fn instr_LD_0x77() {
 
 #[test]
 fn instr_LD_0x78() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x78();
@@ -3336,7 +4500,7 @@ fn instr_LD_0x78() {
 
 #[test]
 fn instr_LD_0x79() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x79();
@@ -3345,7 +4509,7 @@ fn instr_LD_0x79() {
 
 #[test]
 fn instr_LD_0x7A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7A();
@@ -3354,7 +4518,7 @@ fn instr_LD_0x7A() {
 
 #[test]
 fn instr_LD_0x7B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7B();
@@ -3363,7 +4527,7 @@ fn instr_LD_0x7B() {
 
 #[test]
 fn instr_LD_0x7C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7C();
@@ -3372,7 +4536,7 @@ fn instr_LD_0x7C() {
 
 #[test]
 fn instr_LD_0x7D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7D();
@@ -3381,7 +4545,7 @@ fn instr_LD_0x7D() {
 
 #[test]
 fn instr_LD_0x7E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7E();
@@ -3390,7 +4554,7 @@ fn instr_LD_0x7E() {
 
 #[test]
 fn instr_LD_0x7F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0x7F();
@@ -3399,7 +4563,7 @@ fn instr_LD_0x7F() {
 
 #[test]
 fn instr_LD_0xE2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0xE2();
@@ -3408,7 +4572,7 @@ fn instr_LD_0xE2() {
 
 #[test]
 fn instr_LD_0xEA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0xEA();
@@ -3417,7 +4581,7 @@ fn instr_LD_0xEA() {
 
 #[test]
 fn instr_LD_0xF2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0xF2();
@@ -3426,16 +4590,40 @@ fn instr_LD_0xF2() {
 
 #[test]
 fn instr_LD_0xF8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.sp = 0xC00F;
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8;
+    c.pc = 5;
+    c.mmu.memory[6] = 0x01;
     c.instr_LD_0xF8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xC0);
+    assert_eq!(c.l,  0x10);
+    assert_eq!(c.sp, 0xC00F);
+    assert_eq!(c.f,  Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.sp = 0xC0F0;
+    c.pc = 5;
+    c.mmu.memory[6] = 0xFE;
+    c.instr_LD_0xF8();
+    assert_eq!(c.h,  0xC0);
+    assert_eq!(c.l,  0xEE);
+    assert_eq!(c.f,  Flag::Carry as u8);
+
+    c.sp = 0x0000;
+    c.pc = 5;
+    c.mmu.memory[6] = 0xFF;
+    c.instr_LD_0xF8();
+    assert_eq!(c.h,  0xFF);
+    assert_eq!(c.l,  0xFF);
+    assert_eq!(c.f,  Flag::None as u8);
 }
 
 #[test]
 fn instr_LD_0xF9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0xF9();
@@ -3444,7 +4632,7 @@ fn instr_LD_0xF9() {
 
 #[test]
 fn instr_LD_0xFA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LD_0xFA();
@@ -3453,7 +4641,7 @@ fn instr_LD_0xFA() {
 
 #[test]
 fn instr_LDH_0xE0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LDH_0xE0();
@@ -3462,7 +4650,7 @@ fn instr_LDH_0xE0() {
 
 #[test]
 fn instr_LDH_0xF0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_LDH_0xF0();
@@ -3471,799 +4659,1338 @@ fn instr_LDH_0xF0() {
 
 #[test]
 fn instr_PREFIX_0xCB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write8(5, 0xCB);
+    c.mmu.write8(6, 0x37);          // SWAP A
+    c.a  = 0x12;
+    c.pc = 5;
     c.instr_PREFIX_0xCB();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x21);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB80() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB80();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB81() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB81();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB82() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB82();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB83() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB83();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB84() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB84();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB85() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB85();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB86() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCB86();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB87() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB87();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xFE);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB88() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB88();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB89() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB89();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB8A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB8B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB8C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB8D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCB8E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB8F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB8F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xFD);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB90() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB90();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB91() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB91();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB92() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB92();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB93() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB93();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB94() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB94();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB95() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB95();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB96() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCB96();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB97() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB97();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xFB);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB98() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB98();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB99() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB99();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB9A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB9B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB9C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB9D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCB9E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCB9F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCB9F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xF7);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA0();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA1();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA2();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA3();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA4();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA5();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCBA6();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA7();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xEF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBA9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBA9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBAA();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBAB();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBAC();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBAD();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCBAE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBAF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBAF();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xDF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB0();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB1();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB2();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB3();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB4();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB5();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCBB6();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB7();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xBF);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBB9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBB9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBBA();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBBB();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBBC();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBBD();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xFF);
+    c.pc = 5;
     c.instr_RES_0xCBBE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RES_0xCBBF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xFF;
+    c.pc = 5;
     c.instr_RES_0xCBBF();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x7F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
 }
 
 #[test]
 fn instr_RET_0xC0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RET_0xC0();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 6);
+    assert_eq!(c.sp, 8);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 8;
+    c.instr_RET_0xC0();
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
 }
 
 #[test]
 fn instr_RET_0xC8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RET_0xC8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 6);
+    assert_eq!(c.sp, 8);
+
+    c.f  = Flag::Zero as u8;
+    c.pc = 5;
+    c.sp = 8;
+    c.instr_RET_0xC8();
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
 }
 
 #[test]
 fn instr_RET_0xC9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RET_0xC9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
 }
 
 #[test]
 fn instr_RET_0xD0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RET_0xD0();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 6);
+    assert_eq!(c.sp, 8);
+
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 8;
+    c.instr_RET_0xD0();
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
 }
 
 #[test]
 fn instr_RET_0xD8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RET_0xD8();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 6);
+    assert_eq!(c.sp, 8);
+
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.sp = 8;
+    c.instr_RET_0xD8();
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
 }
 
 #[test]
 fn instr_RETI_0xD9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.mmu.write16(8, 0x1234);
+    c.pc = 5;
+    c.sp = 8;
     c.instr_RETI_0xD9();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.sp, 10);
+    assert_eq!(c.ime, true);
 }
 
 #[test]
 fn instr_RL_0xCB10() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB10();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB10();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB11() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB11();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB11();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB12() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB12();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB12();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB13() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB13();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB13();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB14() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB14();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB14();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB15() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB15();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB15();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB16() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB16();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB16();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RL_0xCB17() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RL_0xCB17();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RL_0xCB17();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLA_0x17() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLA_0x17();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_RLC_0xCB00() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB00();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB00();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB01() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB01();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB01();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB02() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB02();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB02();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB03() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB03();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB03();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB04() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB04();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB04();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB05() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB05();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB05();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB06() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB06();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB06();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLC_0xCB07() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RLC_0xCB07();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x2B);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RLC_0xCB07();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RLCA_0x07() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_RLCA_0x07();
@@ -4272,259 +5999,644 @@ fn instr_RLCA_0x07() {
 
 #[test]
 fn instr_RR_0xCB18() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB18();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB18();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB19() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB19();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB19();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1A();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1B();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1C();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1D();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1E();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RR_0xCB1F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RR_0xCB1F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RR_0xCB1F();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRA_0x1F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRA_0x1F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_RRC_0xCB08() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB08();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB08();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB09() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB09();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB09();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0A();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0B();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0C();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0D();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0E();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRC_0xCB0F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRC_0xCB0F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_RRC_0xCB0F();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_RRCA_0x0F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_RRCA_0x0F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_SBC_0x98() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.b  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x98();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.b,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.b  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x98();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.b  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x98();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x99() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.c  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x99();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.c,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.c  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x99();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.c  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x99();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x9A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.d  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.d,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.d  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x9A();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.d  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x9A();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x9B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.e  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.e,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.e  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x9B();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.e  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x9B();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x9C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.h  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.h,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.h  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x9C();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.h  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x9C();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x9D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.l  = 0x01;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.l,  0x01);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
+
+    // The carry borrows past 0xFF
+    c.a  = 0x00;
+    c.l  = 0xFF;
+    c.f  = Flag::Carry as u8;
+    c.instr_SBC_0x9D();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
+
+    c.a  = 0x3E;
+    c.l  = 0x0E;
+    c.f  = Flag::Zero as u8 | Flag::HalfCarry as u8;
+    c.instr_SBC_0x9D();
+    assert_eq!(c.a,  0x30);
+    assert_eq!(c.f,  Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0x9E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x0F);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_SBC_0x9F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x42;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SBC_0x9F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xFF);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
+
+    c.a  = 0x42;
+    c.f  = Flag::None as u8;
+    c.instr_SBC_0x9F();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8);
 }
 
 #[test]
 fn instr_SBC_0xDE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x10;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.mmu.memory[6] = 0x01;
     c.instr_SBC_0xDE();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0E);
+    assert_eq!(c.f,  Flag::Operation as u8 | Flag::HalfCarry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
+    c.mmu.memory[6] = 0xFF;
+    c.instr_SBC_0xDE();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Operation as u8 |
+                     Flag::HalfCarry as u8 | Flag::Carry as u8);
 }
 
 #[test]
 fn instr_SCF_0x37() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.f  = Flag::Zero as u8 | Flag::Operation as u8 | Flag::HalfCarry as u8;
+    c.pc = 5;
     c.instr_SCF_0x37();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.f,  Flag::Zero as u8 | Flag::Carry as u8);
+    assert_eq!(c.pc, 6);
 }
 
 #[test]
 fn instr_SET_0xCBC2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBC2();
@@ -4533,7 +6645,7 @@ fn instr_SET_0xCBC2() {
 
 #[test]
 fn instr_SET_0xCBC8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBC8();
@@ -4542,7 +6654,7 @@ fn instr_SET_0xCBC8() {
 
 #[test]
 fn instr_SET_0xCBC9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBC9();
@@ -4551,7 +6663,7 @@ fn instr_SET_0xCBC9() {
 
 #[test]
 fn instr_SET_0xCBCA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCA();
@@ -4560,7 +6672,7 @@ fn instr_SET_0xCBCA() {
 
 #[test]
 fn instr_SET_0xCBCB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCB();
@@ -4569,7 +6681,7 @@ fn instr_SET_0xCBCB() {
 
 #[test]
 fn instr_SET_0xCBCC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCC();
@@ -4578,7 +6690,7 @@ fn instr_SET_0xCBCC() {
 
 #[test]
 fn instr_SET_0xCBCD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCD();
@@ -4587,7 +6699,7 @@ fn instr_SET_0xCBCD() {
 
 #[test]
 fn instr_SET_0xCBCE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCE();
@@ -4596,7 +6708,7 @@ fn instr_SET_0xCBCE() {
 
 #[test]
 fn instr_SET_0xCBCF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBCF();
@@ -4605,7 +6717,7 @@ fn instr_SET_0xCBCF() {
 
 #[test]
 fn instr_SET_0xCBD0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD0();
@@ -4614,7 +6726,7 @@ fn instr_SET_0xCBD0() {
 
 #[test]
 fn instr_SET_0xCBD1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD1();
@@ -4623,7 +6735,7 @@ fn instr_SET_0xCBD1() {
 
 #[test]
 fn instr_SET_0xCBD2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD2();
@@ -4632,7 +6744,7 @@ fn instr_SET_0xCBD2() {
 
 #[test]
 fn instr_SET_0xCBD3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD3();
@@ -4641,7 +6753,7 @@ fn instr_SET_0xCBD3() {
 
 #[test]
 fn instr_SET_0xCBD4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD4();
@@ -4650,7 +6762,7 @@ fn instr_SET_0xCBD4() {
 
 #[test]
 fn instr_SET_0xCBD5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD5();
@@ -4659,7 +6771,7 @@ fn instr_SET_0xCBD5() {
 
 #[test]
 fn instr_SET_0xCBD6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD6();
@@ -4668,7 +6780,7 @@ fn instr_SET_0xCBD6() {
 
 #[test]
 fn instr_SET_0xCBD7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD7();
@@ -4677,7 +6789,7 @@ fn instr_SET_0xCBD7() {
 
 #[test]
 fn instr_SET_0xCBD8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD8();
@@ -4686,7 +6798,7 @@ fn instr_SET_0xCBD8() {
 
 #[test]
 fn instr_SET_0xCBD9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBD9();
@@ -4695,7 +6807,7 @@ fn instr_SET_0xCBD9() {
 
 #[test]
 fn instr_SET_0xCBDA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDA();
@@ -4704,7 +6816,7 @@ fn instr_SET_0xCBDA() {
 
 #[test]
 fn instr_SET_0xCBDB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDB();
@@ -4713,7 +6825,7 @@ fn instr_SET_0xCBDB() {
 
 #[test]
 fn instr_SET_0xCBDC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDC();
@@ -4722,7 +6834,7 @@ fn instr_SET_0xCBDC() {
 
 #[test]
 fn instr_SET_0xCBDD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDD();
@@ -4731,7 +6843,7 @@ fn instr_SET_0xCBDD() {
 
 #[test]
 fn instr_SET_0xCBDE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDE();
@@ -4740,7 +6852,7 @@ fn instr_SET_0xCBDE() {
 
 #[test]
 fn instr_SET_0xCBDF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBDF();
@@ -4749,7 +6861,7 @@ fn instr_SET_0xCBDF() {
 
 #[test]
 fn instr_SET_0xCBE0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE0();
@@ -4758,7 +6870,7 @@ fn instr_SET_0xCBE0() {
 
 #[test]
 fn instr_SET_0xCBE1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE1();
@@ -4767,7 +6879,7 @@ fn instr_SET_0xCBE1() {
 
 #[test]
 fn instr_SET_0xCBE2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE2();
@@ -4776,7 +6888,7 @@ fn instr_SET_0xCBE2() {
 
 #[test]
 fn instr_SET_0xCBE3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE3();
@@ -4785,7 +6897,7 @@ fn instr_SET_0xCBE3() {
 
 #[test]
 fn instr_SET_0xCBE4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE4();
@@ -4794,7 +6906,7 @@ fn instr_SET_0xCBE4() {
 
 #[test]
 fn instr_SET_0xCBE5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE5();
@@ -4803,7 +6915,7 @@ fn instr_SET_0xCBE5() {
 
 #[test]
 fn instr_SET_0xCBE6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE6();
@@ -4812,7 +6924,7 @@ fn instr_SET_0xCBE6() {
 
 #[test]
 fn instr_SET_0xCBE7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE7();
@@ -4821,7 +6933,7 @@ fn instr_SET_0xCBE7() {
 
 #[test]
 fn instr_SET_0xCBE8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE8();
@@ -4830,7 +6942,7 @@ fn instr_SET_0xCBE8() {
 
 #[test]
 fn instr_SET_0xCBE9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBE9();
@@ -4839,7 +6951,7 @@ fn instr_SET_0xCBE9() {
 
 #[test]
 fn instr_SET_0xCBEA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBEA();
@@ -4848,7 +6960,7 @@ fn instr_SET_0xCBEA() {
 
 #[test]
 fn instr_SET_0xCBEB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBEB();
@@ -4857,7 +6969,7 @@ fn instr_SET_0xCBEB() {
 
 #[test]
 fn instr_SET_0xCBEC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBEC();
@@ -4866,7 +6978,7 @@ fn instr_SET_0xCBEC() {
 
 #[test]
 fn instr_SET_0xCBED() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBED();
@@ -4875,7 +6987,7 @@ fn instr_SET_0xCBED() {
 
 #[test]
 fn instr_SET_0xCBEE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBEE();
@@ -4884,7 +6996,7 @@ fn instr_SET_0xCBEE() {
 
 #[test]
 fn instr_SET_0xCBEF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBEF();
@@ -4893,7 +7005,7 @@ fn instr_SET_0xCBEF() {
 
 #[test]
 fn instr_SET_0xCBF0() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF0();
@@ -4902,7 +7014,7 @@ fn instr_SET_0xCBF0() {
 
 #[test]
 fn instr_SET_0xCBF1() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF1();
@@ -4911,7 +7023,7 @@ fn instr_SET_0xCBF1() {
 
 #[test]
 fn instr_SET_0xCBF2() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF2();
@@ -4920,7 +7032,7 @@ fn instr_SET_0xCBF2() {
 
 #[test]
 fn instr_SET_0xCBF3() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF3();
@@ -4929,7 +7041,7 @@ fn instr_SET_0xCBF3() {
 
 #[test]
 fn instr_SET_0xCBF4() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF4();
@@ -4938,7 +7050,7 @@ fn instr_SET_0xCBF4() {
 
 #[test]
 fn instr_SET_0xCBF5() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF5();
@@ -4947,7 +7059,7 @@ fn instr_SET_0xCBF5() {
 
 #[test]
 fn instr_SET_0xCBF6() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF6();
@@ -4956,7 +7068,7 @@ fn instr_SET_0xCBF6() {
 
 #[test]
 fn instr_SET_0xCBF7() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF7();
@@ -4965,7 +7077,7 @@ fn instr_SET_0xCBF7() {
 
 #[test]
 fn instr_SET_0xCBF8() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF8();
@@ -4974,7 +7086,7 @@ fn instr_SET_0xCBF8() {
 
 #[test]
 fn instr_SET_0xCBF9() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBF9();
@@ -4983,7 +7095,7 @@ fn instr_SET_0xCBF9() {
 
 #[test]
 fn instr_SET_0xCBFA() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFA();
@@ -4992,7 +7104,7 @@ fn instr_SET_0xCBFA() {
 
 #[test]
 fn instr_SET_0xCBFB() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFB();
@@ -5001,7 +7113,7 @@ fn instr_SET_0xCBFB() {
 
 #[test]
 fn instr_SET_0xCBFC() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFC();
@@ -5010,7 +7122,7 @@ fn instr_SET_0xCBFC() {
 
 #[test]
 fn instr_SET_0xCBFD() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFD();
@@ -5019,7 +7131,7 @@ fn instr_SET_0xCBFD() {
 
 #[test]
 fn instr_SET_0xCBFE() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFE();
@@ -5028,7 +7140,7 @@ fn instr_SET_0xCBFE() {
 
 #[test]
 fn instr_SET_0xCBFF() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SET_0xCBFF();
@@ -5037,223 +7149,523 @@ fn instr_SET_0xCBFF() {
 
 #[test]
 fn instr_SLA_0xCB20() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB20();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB20();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB21() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB21();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB21();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB22() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB22();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB22();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB23() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB23();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB23();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB24() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB24();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB24();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB25() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB25();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB25();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB26() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB26();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB26();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SLA_0xCB27() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SLA_0xCB27();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x2A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SLA_0xCB27();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB28() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB28();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB28();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB29() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB29();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB29();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2A();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2B();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2C();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2D();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2E();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRA_0xCB2F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRA_0xCB2F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0xCA);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRA_0xCB2F();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB38() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB38();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB38();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB39() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB39();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB39();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3A() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3A();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3A();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3B() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3B();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3B();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3C() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3C();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3C();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3D() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3D();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3D();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3E() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x95);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3E();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3E();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SRL_0xCB3F() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0x95;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SRL_0xCB3F();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x4A);
+    assert_eq!(c.f,  Flag::Carry as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SRL_0xCB3F();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_STOP_0x10() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_STOP_0x10();
@@ -5262,7 +7674,7 @@ fn instr_STOP_0x10() {
 
 #[test]
 fn instr_SUB_0x93() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
     c.instr_SUB_0x93();
@@ -5271,73 +7683,904 @@ fn instr_SUB_0x93() {
 
 #[test]
 fn instr_SWAP_0xCB30() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.b  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB30();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.b,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.b  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB30();
+    assert_eq!(c.b,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB31() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.c  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB31();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.c,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.c  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB31();
+    assert_eq!(c.c,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB32() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.d  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB32();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.d,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.d  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB32();
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB33() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.e  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB33();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.e,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.e  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB33();
+    assert_eq!(c.e,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB34() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB34();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.h,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB34();
+    assert_eq!(c.h,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB35() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.l  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB35();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.l,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.l  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB35();
+    assert_eq!(c.l,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB36() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0xF0);
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB36();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.mmu.read8(0x0100), 0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.h  = 0x01;
+    c.l  = 0x00;
+    c.mmu.write8(0x0100, 0x00);
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB36();
+    assert_eq!(c.mmu.read8(0x0100), 0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
 #[test]
 fn instr_SWAP_0xCB37() {
-    let mut c = Cpu::new();
+    let mut c = Cpu::with_bus(FlatBus::new());
 
     c.reset();
+    c.a  = 0xF0;
+    c.f  = Flag::Carry as u8;
+    c.pc = 5;
     c.instr_SWAP_0xCB37();
-    assert_eq!(true, true); // Unimplemented
+    assert_eq!(c.a,  0x0F);
+    assert_eq!(c.f,  Flag::None as u8);
+    assert_eq!(c.pc, 7);
+
+    c.a  = 0x00;
+    c.f  = Flag::None as u8;
+    c.pc = 5;
+    c.instr_SWAP_0xCB37();
+    assert_eq!(c.a,  0x00);
+    assert_eq!(c.f,  Flag::Zero as u8);
 }
 
+
+#[test]
+fn interrupt_dispatch() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.ime = true;
+    c.pc = 0x1234;
+    c.sp = 10;
+    c.mmu.write8(0xFFFF, Interrupt::Timer as u8);
+    c.request_interrupt(Interrupt::Timer);
+    c.step().unwrap();
+    assert_eq!(c.mmu.read16(8), 0x1234);
+    assert_eq!(c.pc, 0x50);
+    assert_eq!(c.sp, 8);
+    assert_eq!(c.ime, false);
+    assert_eq!(c.mmu.read8(0xFF0F) & 0x1F, 0);
+    assert_eq!(c.t,  20);
+}
+
+#[test]
+fn interrupt_priority() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.ime = true;
+    c.sp = 10;
+    c.mmu.write8(0xFFFF, 0x1F);
+    c.request_interrupt(Interrupt::Joypad);
+    c.request_interrupt(Interrupt::LcdStat);
+    c.request_interrupt(Interrupt::Serial);
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x48);
+    assert_eq!(c.mmu.read8(0xFF0F) & 0x1F,
+               Interrupt::Joypad as u8 | Interrupt::Serial as u8);
+}
+
+#[test]
+fn interrupt_disabled() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.ime = true;
+    c.request_interrupt(Interrupt::VBlank);
+    c.step().unwrap();
+    assert_eq!(c.pc, 1);
+
+    c.mmu.write8(0xFFFF, Interrupt::VBlank as u8);
+    c.ime = false;
+    c.step().unwrap();
+    assert_eq!(c.pc, 2);
+}
+
+#[test]
+fn interrupt_ei_delay() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.sp = 10;
+    c.mmu.write8(0, 0xFB);          // 00 EI
+    c.mmu.write8(1, 0x00);          // 01 NOP
+    c.mmu.write8(0xFFFF, Interrupt::VBlank as u8);
+    c.request_interrupt(Interrupt::VBlank);
+    c.step().unwrap();
+    assert_eq!(c.pc, 1);
+    c.step().unwrap();
+    assert_eq!(c.pc, 2);
+    assert_eq!(c.ime, true);
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x40);
+    assert_eq!(c.mmu.read16(8), 2);
+}
+
+#[test]
+fn interrupt_ei_di() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0, 0xFB);          // 00 EI
+    c.mmu.write8(1, 0xF3);          // 01 DI
+    c.step().unwrap();
+    c.step().unwrap();
+    assert_eq!(c.ime, false);
+    assert_eq!(c.ime_pending, false);
+}
+
+#[test]
+fn interrupt_reti() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.sp = 8;
+    c.mmu.write16(8, 0x1234);
+    c.mmu.write8(0, 0xD9);          // 00 RETI
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x1234);
+    assert_eq!(c.ime, true);
+}
+
+#[test]
+fn interrupt_halt_wakeup() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.ime = true;
+    c.sp = 10;
+    c.mmu.write8(0, 0x76);          // 00 HALT
+    c.mmu.write8(0xFFFF, Interrupt::Serial as u8);
+    c.step().unwrap();
+    assert_eq!(c.halt, true);
+    c.step().unwrap();
+    c.step().unwrap();
+    assert_eq!(c.pc, 1);
+    c.request_interrupt(Interrupt::Serial);
+    c.step().unwrap();
+    assert_eq!(c.halt, false);
+    assert_eq!(c.pc, 0x58);
+    assert_eq!(c.mmu.read16(8), 1);
+}
+
+#[test]
+fn interrupt_halt_wakeup_ime_unset() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0, 0x76);          // 00 HALT
+    c.mmu.write8(1, 0x3C);          // 01 INC A
+    c.mmu.write8(0xFFFF, Interrupt::Joypad as u8);
+    c.step().unwrap();
+    assert_eq!(c.halt, true);
+    c.request_interrupt(Interrupt::Joypad);
+    c.step().unwrap();
+    assert_eq!(c.halt, false);
+    assert_eq!(c.pc, 2);
+    assert_eq!(c.a,  1);
+}
+
+#[test]
+fn interrupt_halt_bug() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0, 0x76);          // 00 HALT
+    c.mmu.write8(1, 0x3C);          // 01 INC A
+    c.mmu.write8(2, 0x00);          // 02 NOP
+    c.mmu.write8(0xFFFF, Interrupt::Timer as u8);
+    c.request_interrupt(Interrupt::Timer);
+    c.step().unwrap();
+    assert_eq!(c.halt, false);
+    assert_eq!(c.pc, 1);
+    // INC A is executed twice
+    c.step().unwrap();
+    assert_eq!(c.pc, 1);
+    c.step().unwrap();
+    assert_eq!(c.pc, 2);
+    assert_eq!(c.a,  2);
+}
+
+#[test]
+fn step_cycles() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.sp = 10;
+    c.mmu.write8(0, 0x00);          // 00 NOP
+    c.mmu.write8(1, 0x20);          // 01 JR NZ, 1
+    c.mmu.write8(2,    1);          // 02 ^1
+    c.mmu.write8(4, 0xCC);          // 04 CALL Z, 0x0010
+    c.mmu.write16(5, 0x0010);       // 05 ^0x0010
+    c.mmu.write8(7, 0xC4);          // 07 CALL NZ, 0x0010
+    c.mmu.write16(8, 0x0010);       // 08 ^0x0010
+    c.mmu.write8(0x10, 0xC8);       // 10 RET Z
+    c.mmu.write8(0x11, 0xC0);       // 11 RET NZ
+    assert_eq!(c.step(), Ok(4));
+    assert_eq!(c.step(), Ok(12));
+    assert_eq!(c.pc, 4);
+    assert_eq!(c.step(), Ok(12));
+    assert_eq!(c.step(), Ok(24));
+    assert_eq!(c.pc, 0x10);
+    assert_eq!(c.step(), Ok(8));
+    assert_eq!(c.step(), Ok(20));
+    assert_eq!(c.pc, 0x0A);
+    assert_eq!(c.cycles(), 80);
+}
+
+#[test]
+fn cycles_overflow() {
+    // Clocks keep counting past 8 bits
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    for _ in 0..1000 {
+        c.step().unwrap();
+    }
+    assert_eq!(c.pc, 1000);
+    assert_eq!(c.m,  1000);
+    assert_eq!(c.t,  4000);
+    assert_eq!(c.cycles(), 4000);
+}
+
+#[test]
+fn wrap_pc() {
+    // Execution wraps from 0xFFFF to 0x0000
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.pc = 0xFFFF;
+    c.mmu.write8(0xFFFF, 0x00);     // FFFF NOP
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x0000);
+
+    // Operands are read across the boundary
+    c.pc = 0xFFFE;
+    c.mmu.write8(0xFFFE, 0x01);     // FFFE LD BC, 0x1337
+    c.mmu.write8(0xFFFF, 0x37);     // FFFF ^0x37
+    c.mmu.write8(0x0000, 0x13);     // 0000 ^0x13
+    c.step().unwrap();
+    assert_eq!(c.b,  0x13);
+    assert_eq!(c.c,  0x37);
+    assert_eq!(c.pc, 0x0001);
+}
+
+#[test]
+fn wrap_jumps() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    // Relative jumps wrap both ways
+    c.reset();
+    c.mmu.write8(0x0000, 0x18);     // 0000 JR -128
+    c.mmu.write8(0x0001, 0x80);     // 0001 ^0x80
+    c.step().unwrap();
+    assert_eq!(c.pc, 0xFF82);
+    c.mmu.write8(0xFF82, 0x18);     // FF82 JR 127
+    c.mmu.write8(0xFF83, 0x7F);     // FF83 ^0x7F
+    c.step().unwrap();
+    assert_eq!(c.pc, 0xFF84u16.wrapping_add(0x7F));
+    c.pc = 0xFFF0;
+    c.mmu.write8(0xFFF0, 0x18);     // FFF0 JR 0x20
+    c.mmu.write8(0xFFF1, 0x20);     // FFF1 ^0x20
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x0012);
+
+    // Return addresses wrap too
+    c.pc = 0xFFFF;
+    c.sp = 0xD000;
+    c.mmu.write8(0xFFFF, 0xFF);     // FFFF RST 38H
+    c.step().unwrap();
+    assert_eq!(c.pc, 0x0038);
+    assert_eq!(c.mmu.read16(0xCFFE), 0x0000);
+}
+
+#[test]
+fn jr_offset_base() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    // Offsets count from the instruction following JR, as on hardware:
+    // JR 0 falls through, and JR -2 jumps back onto itself
+    c.reset();
+    c.pc = 0xC000;
+    c.mmu.write8(0xC000, 0x18);     // C000 JR 0
+    c.mmu.write8(0xC001, 0x00);     // C001 ^0x00
+    c.step().unwrap();
+    assert_eq!(c.pc, 0xC002);
+    c.mmu.write8(0xC002, 0x18);     // C002 JR -2
+    c.mmu.write8(0xC003, 0xFE);     // C003 ^0xFE
+    c.step().unwrap();
+    assert_eq!(c.pc, 0xC002);
+
+    // Same for conditional jumps, when taken
+    for opcode in [0x20u8, 0x28, 0x30, 0x38].iter() {
+        c.f = match *opcode {
+            0x20 => Flag::None as u8,
+            0x28 => Flag::Zero as u8,
+            0x30 => Flag::None as u8,
+            _    => Flag::Carry as u8,
+        };
+        c.pc = 0xC010;
+        c.mmu.write8(0xC010, *opcode);  // C010 JR cc, -2
+        c.mmu.write8(0xC011, 0xFE);     // C011 ^0xFE
+        c.step().unwrap();
+        assert_eq!(c.pc, 0xC010);
+    }
+}
+
+#[test]
+fn wrap_sp() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    // Pushing at 0x0000 wraps to 0xFFFF
+    c.reset();
+    c.sp = 0x0000;
+    c.b  = 0x13;
+    c.c  = 0x37;
+    c.pc = 0x0100;
+    c.mmu.write8(0x0100, 0xC5);     // 0100 PUSH BC
+    c.mmu.write8(0x0101, 0xD1);     // 0101 POP DE
+    c.mmu.write8(0x0102, 0xCD);     // 0102 CALL 0x0200
+    c.mmu.write16(0x0103, 0x0200);
+    c.step().unwrap();
+    assert_eq!(c.sp, 0xFFFE);
+    assert_eq!(c.mmu.read8(0xFFFF), 0x13);
+    assert_eq!(c.mmu.read8(0xFFFE), 0x37);
+    c.sp = 0xFFFF;
+    c.step().unwrap();
+    assert_eq!(c.sp, 0x0001);
+    assert_eq!(c.d,  0x00);
+    assert_eq!(c.e,  0x13);
+    c.sp = 0x0001;
+    c.step().unwrap();
+    assert_eq!(c.sp, 0xFFFF);
+    assert_eq!(c.mmu.read16(0xFFFF), 0x0105);
+    assert_eq!(c.pc, 0x0200);
+}
+
+#[test]
+fn wrap_registers() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.b = 0xFF;
+    c.c = 0xFF;
+    c.instr_INC_0x03();             // INC BC
+    assert_eq!(c.b, 0x00);
+    assert_eq!(c.c, 0x00);
+    c.instr_DEC_0x0B();             // DEC BC
+    assert_eq!(c.b, 0xFF);
+    assert_eq!(c.c, 0xFF);
+
+    // (HL+) and (HL-) wrap around the address space
+    c.h = 0xFF;
+    c.l = 0xFF;
+    c.a = 0x42;
+    c.instr_LD_0x22();              // LD (HL+), A
+    assert_eq!(c.mmu.read8(0xFFFF), 0x42);
+    assert_eq!(c.h, 0x00);
+    assert_eq!(c.l, 0x00);
+    c.instr_LD_0x3A();              // LD A, (HL-)
+    assert_eq!(c.h, 0xFF);
+    assert_eq!(c.l, 0xFF);
+
+    // 16 bit additions wrap, and set the carry
+    c.instr_ADD_0x29();             // ADD HL, HL
+    assert_eq!(c.h, 0xFF);
+    assert_eq!(c.l, 0xFE);
+    assert!(c.f & Flag::Carry as u8 != 0);
+}
+
+#[test]
+fn wrap_all() {
+    // No instruction panics at the boundaries of the address space
+    for opcode in 0u32..0x200 {
+        for &(pc, sp) in [(0xFFFFu16, 0x0000u16), (0xFFFE, 0xFFFF), (0x0000, 0x0001)].iter() {
+            let mut c = Cpu::with_bus(FlatBus::new());
+            c.reset();
+            c.pc = pc;
+            c.sp = sp;
+            c.a = 0xFF; c.b = 0xFF; c.c = 0xFF; c.d = 0xFF;
+            c.e = 0xFF; c.h = 0xFF; c.l = 0xFF; c.f = 0xF0;
+            if opcode < 0x100 {
+                c.mmu.write8(pc, opcode as u8);
+                c.mmu.write8(pc.wrapping_add(1), 0xFF);
+            } else {
+                c.mmu.write8(pc, 0xCB);
+                c.mmu.write8(pc.wrapping_add(1), (opcode - 0x100) as u8);
+            }
+            c.mmu.write8(pc.wrapping_add(2), 0xFF);
+            let _ = c.step();
+        }
+    }
+}
+
+#[test]
+fn decode() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0x0100, 0x06);     // 0100 LD B, 0x0A
+    c.mmu.write8(0x0101, 0x0A);     // 0101 ^0x0A
+    c.mmu.write8(0x0102, 0xD3);     // 0102 (illegal)
+    c.pc = 0x0100;
+    let i = c.decode(0x0100).unwrap();
+    assert_eq!(format!("{}", i), "LD B, 0x0A");
+    assert_eq!(i.length, 2);
+    assert!(c.decode(0x0102).is_none());
+    // Decoding does not execute anything
+    assert_eq!(c.pc, 0x0100);
+    assert_eq!(c.b,  0);
+    assert_eq!(c.state(), Ok((String::from_str("LD B, 0x0A"), 2)));
+}
+
+#[test]
+fn decode_matches_execution() {
+    // Decoded length and cycles agree with the instruction handlers
+    for opcode in 0u32..0x200 {
+        let bytes: [u8; 3] = if opcode < 0x100 {
+            [opcode as u8, 0x12, 0x34]
+        } else {
+            [0xCB, (opcode - 0x100) as u8, 0x34]
+        };
+        let i = match instruction::decode(&bytes) {
+            Some(i) => i,
+            None    => continue,
+        };
+        if i.branch_cycles.is_some() {
+            continue;
+        }
+        let mut c = Cpu::with_bus(FlatBus::new());
+        c.reset();
+        c.sp = 0xD000;
+        c.pc = 0xC000;
+        for k in 0..3 {
+            c.mmu.write8(0xC000 + k as u16, bytes[k]);
+        }
+        assert_eq!(c.step(), Ok(i.cycles));
+        match i.mnemonic {
+            "JP" | "JR" | "CALL" | "RET" | "RETI" | "RST" => {},
+            _ => assert_eq!(c.pc, 0xC000 + i.length as u16),
+        }
+    }
+}
+
+#[test]
+fn step_illegal() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0x0100, 0xDD);     // 0100 (illegal)
+    c.pc = 0x0100;
+    let error = CpuError::IllegalOpcode { pc: 0x0100, opcode: 0xDD };
+    assert_eq!(c.step(), Err(error));
+    assert_eq!(c.state(), Err(error));
+    assert_eq!(format!("{}", error), "illegal opcode 0xDD at 0x0100");
+    // Processor state is left untouched
+    assert_eq!(c.pc, 0x0100);
+    assert_eq!(c.cycles(), 0);
+    assert!(!c.locked());
+    assert_eq!(c.step(), Err(error));
+}
+
+#[test]
+fn step_illegal_all() {
+    for opcode in [0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB,
+                   0xEC, 0xED, 0xF4, 0xFC, 0xFD].iter() {
+        let mut c = Cpu::with_bus(FlatBus::new());
+        c.reset();
+        c.mmu.write8(0, *opcode);
+        assert_eq!(c.step(), Err(CpuError::IllegalOpcode { pc: 0, opcode: *opcode }));
+    }
+}
+
+#[test]
+fn step_illegal_lock() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.lock_on_illegal = true;
+    c.ime = true;
+    c.sp = 10;
+    c.mmu.write8(0x0000, 0xFD);     // 0000 (illegal)
+    assert_eq!(c.step(), Ok(4));
+    assert!(c.locked());
+    assert_eq!(c.pc, 0);
+    // Interrupts do not wake the processor up
+    c.mmu.write8(IE, Interrupt::Timer as u8);
+    c.request_interrupt(Interrupt::Timer);
+    assert_eq!(c.step(), Ok(4));
+    assert_eq!(c.step(), Ok(4));
+    assert_eq!(c.pc, 0);
+    assert_eq!(c.sp, 10);
+    assert_eq!(c.cycles(), 12);
+    // Only a reset does
+    c.reset();
+    assert!(!c.locked());
+    assert!(c.lock_on_illegal);
+}
+
+#[test]
+fn run_illegal() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.mmu.write8(0, 0x00);          // 00 NOP
+    c.mmu.write8(1, 0xE4);          // 01 (illegal)
+    assert_eq!(c.run(), Err(CpuError::IllegalOpcode { pc: 1, opcode: 0xE4 }));
+
+    // In lock up mode, run returns once locked
+    c.reset();
+    c.lock_on_illegal = true;
+    assert_eq!(c.run(), Ok(()));
+    assert!(c.locked());
+    assert_eq!(c.pc, 1);
+}
+
+#[test]
+fn hooks() {
+    // Mmu hooks are given the address of the accessing instruction
+    let mut c = Cpu::new();
+    c.reset();
+    c.mmu.poke(0x0100, 0x3E);       // 0100 LD A, 0x42
+    c.mmu.poke(0x0101, 0x42);       // 0101 ^0x42
+    c.mmu.poke(0x0102, 0xEA);       // 0102 LD (0xC000), A
+    c.mmu.poke(0x0103, 0x00);       // 0103 ^0x00
+    c.mmu.poke(0x0104, 0xC0);       // 0104 ^0xC0
+    c.mmu.poke(0x0105, 0x10);       // 0105 STOP
+    c.pc = 0x0100;
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let (l0, l1) = (log.clone(), log.clone());
+    c.mmu.add_hook(mmu::Access::Write, 0xC000, 0xDFFF, Box::new(move |address, data, pc| {
+        l0.borrow_mut().push((mmu::Access::Write, address, data, pc));
+    }));
+    c.mmu.add_hook(mmu::Access::Execute, 0x0000, 0x7FFF, Box::new(move |address, data, pc| {
+        l1.borrow_mut().push((mmu::Access::Execute, address, data, pc));
+    }));
+    c.run().unwrap();
+    assert_eq!(*log.borrow(), vec![
+        (mmu::Access::Execute, 0x0100, 0x3E, 0x0100),
+        (mmu::Access::Execute, 0x0102, 0xEA, 0x0102),
+        (mmu::Access::Write, 0xC000, 0x42, 0x0102),
+        (mmu::Access::Execute, 0x0105, 0x10, 0x0105),
+    ]);
+}
+
+#[test]
+fn trace_line() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+
+    c.reset();
+    c.a  = 0x01;
+    c.f  = 0xB0;
+    c.c  = 0x13;
+    c.e  = 0xD8;
+    c.h  = 0x01;
+    c.l  = 0x4D;
+    c.sp = 0xFFFE;
+    c.pc = 0x0100;
+    c.mmu.write8(0x0100, 0x00);
+    c.mmu.write8(0x0101, 0xC3);
+    c.mmu.write16(0x0102, 0x0150);
+    assert_eq!(c.trace_line(),
+               "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D \
+                SP:FFFE PC:0100 PCMEM:00,C3,50,01");
+}
+
+/// Trace sink whose content can still be read once boxed.
+struct SharedSink(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn trace() {
+    let mut c = Cpu::with_bus(FlatBus::new());
+    let buffer = Rc::new(RefCell::new(Vec::new()));
+
+    c.reset();
+    c.mmu.write8(0, 0x06);          // 00 LD B, 0x02
+    c.mmu.write8(1, 0x02);          // 01 ^0x02
+    c.mmu.write8(2, 0x05);          // 02 DEC B
+    c.mmu.write8(3, 0x10);          // 03 STOP
+    c.set_trace(Some(Box::new(SharedSink(buffer.clone()))));
+    c.run().unwrap();
+    c.set_trace(None);
+    c.reset();
+    c.run().unwrap();
+
+    let trace = String::from_utf8(buffer.borrow().clone()).unwrap();
+    let lines: Vec<&str> = trace.lines().collect();
+    assert_eq!(lines, vec![
+        "A:00 F:00 B:00 C:00 D:00 E:00 H:00 L:00 SP:0000 PC:0000 PCMEM:06,02,05,10",
+        "A:00 F:00 B:02 C:00 D:00 E:00 H:00 L:00 SP:0000 PC:0002 PCMEM:05,10,00,00",
+        "A:00 F:40 B:01 C:00 D:00 E:00 H:00 L:00 SP:0000 PC:0003 PCMEM:10,00,00,00",
+    ]);
+}
+
+fn instruction_log(c: &Cpu<FlatBus>) -> Vec<Access> {
+    //! Take the bus log of `c`, leaving out interrupt registers, for
+    //! they are polled and acknowledged outside of the instruction
+    //! stream.
+    c.mmu.take().into_iter().filter(|access| match *access {
+        Access::Read(address, _) | Access::Write(address, _) =>
+            address != IE && address != IF,
+        Access::Tick(_) => true,
+    }).collect()
+}
+
+fn accurate_cpu(program: &[u8]) -> Cpu<FlatBus> {
+    //! Create a cycle accurate `Cpu` running `program` at 0xC000,
+    //! with its stack at 0xD000.
+    let mut c = Cpu::with_bus(FlatBus::logging());
+    c.reset();
+    c.cycle_accurate = true;
+    c.pc = 0xC000;
+    c.sp = 0xD000;
+    for (i, byte) in program.iter().enumerate() {
+        c.mmu.memory[0xC000 + i] = *byte;
+    }
+    c
+}
+
+#[test]
+fn cycle_accurate_all() {
+    // Every access is followed by an M-cycle, and every M-cycle is
+    // ticked as it happens, for both outcomes of conditional branches
+    for opcode in 0u32..0x200 {
+        for f in [0x00, 0xF0].iter() {
+            let program: [u8; 3] = if opcode < 0x100 {
+                [opcode as u8, 0x12, 0x34]
+            } else {
+                [0xCB, (opcode - 0x100) as u8, 0x34]
+            };
+            if instruction::decode(&program).is_none() {
+                continue;
+            }
+            let mut c = accurate_cpu(&program);
+            c.f = *f;
+            let cycles = c.step().unwrap();
+            let log = instruction_log(&c);
+
+            assert_eq!(c.ticked, cycles);
+            let mut ticks: u8 = 0;
+            for (i, access) in log.iter().enumerate() {
+                match *access {
+                    Access::Tick(n) => { assert_eq!(n, 4); ticks += n; },
+                    _ => assert_eq!(log[i + 1], Access::Tick(4)),
+                }
+            }
+            assert_eq!(ticks, cycles);
+        }
+    }
+}
+
+#[test]
+fn cycle_accurate_push() {
+    let mut c = accurate_cpu(&[0xC5]); // PUSH BC
+    c.b = 0x12;
+    c.c = 0x34;
+    assert_eq!(c.step(), Ok(16));
+    assert_eq!(instruction_log(&c), vec![
+        Access::Read(0xC000, 0xC5), Access::Tick(4),
+                                    Access::Tick(4),
+        Access::Write(0xCFFF, 0x12), Access::Tick(4),
+        Access::Write(0xCFFE, 0x34), Access::Tick(4),
+    ]);
+}
+
+#[test]
+fn cycle_accurate_call_ret() {
+    let mut c = accurate_cpu(&[0xCD, 0x00, 0xC1]); // CALL 0xC100
+    c.mmu.memory[0xC100] = 0xC9;                   // RET
+    assert_eq!(c.step(), Ok(24));
+    assert_eq!(instruction_log(&c), vec![
+        Access::Read(0xC000, 0xCD), Access::Tick(4),
+        Access::Read(0xC001, 0x00), Access::Tick(4),
+        Access::Read(0xC002, 0xC1), Access::Tick(4),
+                                    Access::Tick(4),
+        Access::Write(0xCFFF, 0xC0), Access::Tick(4),
+        Access::Write(0xCFFE, 0x03), Access::Tick(4),
+    ]);
+    assert_eq!(c.step(), Ok(16));
+    assert_eq!(instruction_log(&c), vec![
+        Access::Read(0xC100, 0xC9), Access::Tick(4),
+        Access::Read(0xCFFE, 0x03), Access::Tick(4),
+        Access::Read(0xCFFF, 0xC0), Access::Tick(4),
+                                    Access::Tick(4),
+    ]);
+    assert_eq!(c.pc, 0xC003);
+}
+
+#[test]
+fn cycle_accurate_inc16() {
+    let mut c = accurate_cpu(&[0x03]); // INC BC
+    assert_eq!(c.step(), Ok(8));
+    assert_eq!(instruction_log(&c), vec![
+        Access::Read(0xC000, 0x03), Access::Tick(4),
+                                    Access::Tick(4),
+    ]);
+}
+
+#[test]
+fn cycle_accurate_interrupt() {
+    let mut c = accurate_cpu(&[0x00]);
+    c.ime = true;
+    c.mmu.memory[IE as usize] = Interrupt::VBlank as u8;
+    c.mmu.memory[IF as usize] = Interrupt::VBlank as u8;
+    assert_eq!(c.step(), Ok(20));
+    assert_eq!(instruction_log(&c), vec![
+                                    Access::Tick(4),
+                                    Access::Tick(4),
+        Access::Write(0xCFFF, 0xC0), Access::Tick(4),
+        Access::Write(0xCFFE, 0x00), Access::Tick(4),
+                                    Access::Tick(4),
+    ]);
+    assert_eq!(c.pc, 0x40);
+}
+
+#[test]
+fn cycle_accurate_disabled() {
+    // The bus is ticked once per instruction by default
+    let mut c = accurate_cpu(&[0xC5]); // PUSH BC
+    c.cycle_accurate = false;
+    assert_eq!(c.step(), Ok(16));
+    assert_eq!(instruction_log(&c), vec![
+        Access::Read(0xC000, 0xC5),
+        Access::Write(0xCFFF, 0x00),
+        Access::Write(0xCFFE, 0x00),
+        Access::Tick(16),
+    ]);
+}
+
+#[bench]
+fn bench_sum_ints(b: &mut Bencher) {
+    let mut c = Cpu::with_bus(FlatBus::new());
+    let program = include_bytes!("../../../samples/sum_integers.z80");
+    for (i, byte) in program.iter().enumerate() {
+        c.mmu.write8(i as u16, *byte);
+    }
+    // Sum integers from 1 to 255 instead of 10, for a longer loop
+    c.mmu.write8(1, 0xFF);
+    b.iter(|| {
+        c.reset();
+        c.run().unwrap();
+    });
+}
@@ -1 +1,2 @@
 mod cpu;
+mod sm83;
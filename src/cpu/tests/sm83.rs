@@ -0,0 +1,464 @@
+//! Runner for the community "SM83 single step tests": one JSON file per
+//! opcode (`00.json`, ..., `cb ff.json`), each holding an array of cases
+//! with an initial state, a final state, and the bus activity of every
+//! M-cycle.
+//!
+//! Vectors are loaded from the directory named by the `SM83_TESTS`
+//! environment variable, and the runner is skipped when it is unset:
+//!
+//!     $ SM83_TESTS=path/to/sm83/v1 cargo test sm83 -- --nocapture
+//!
+//! Vectors model the overlap of execution and opcode fetch: `pc` points
+//! after the opcode, which was fetched by the previous instruction, and
+//! the last M-cycle fetches the next opcode. Cases are therefore run
+//! from `pc - 1`, and bus activity is compared with the first M-cycle
+//! of `Cpu::step` dropped instead of the last one.
+use super::super::*;
+use bus::tests::{Access, FlatBus};
+use std::collections::BTreeMap;
+use std::os;
+use std::old_io::File;
+use std::old_io::fs;
+
+// ==============================================
+// JSON
+// ==============================================
+
+/// Just enough JSON to read test vectors.
+#[derive(Clone, PartialEq, Debug)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref fields) => {
+                fields.iter().find(|&&(ref k, _)| k == key).map(|&(_, ref v)| v)
+            },
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&Vec<Json>> {
+        match *self {
+            Json::Array(ref values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn as_u16(&self) -> Option<u16> {
+        match *self {
+            Json::Number(n) if n >= 0.0 && n <= 65535.0 => Some(n as u16),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match *self {
+            Json::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+fn parse(text: &str) -> Result<Json, String> {
+    //! Parse a whole JSON document.
+    let mut p = Parser { bytes: text.as_bytes(), index: 0 };
+    let value = try!(p.value());
+    p.whitespace();
+    if p.index != p.bytes.len() {
+        return Err(format!("trailing characters at {}", p.index));
+    }
+    Ok(value)
+}
+
+impl<'a> Parser<'a> {
+    fn whitespace(&mut self) {
+        while self.index < self.bytes.len() {
+            match self.bytes[self.index] {
+                b' ' | b'\t' | b'\n' | b'\r' => self.index += 1,
+                _ => break,
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Result<u8, String> {
+        self.whitespace();
+        if self.index < self.bytes.len() {
+            Ok(self.bytes[self.index])
+        } else {
+            Err(String::from_str("unexpected end of input"))
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        if try!(self.peek()) == byte {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(format!("expected '{}' at {}", byte as char, self.index))
+        }
+    }
+
+    fn literal(&mut self, text: &str, value: Json) -> Result<Json, String> {
+        if self.bytes[self.index..].starts_with(text.as_bytes()) {
+            self.index += text.len();
+            Ok(value)
+        } else {
+            Err(format!("invalid literal at {}", self.index))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        match try!(self.peek()) {
+            b'n' => self.literal("null", Json::Null),
+            b't' => self.literal("true", Json::Bool(true)),
+            b'f' => self.literal("false", Json::Bool(false)),
+            b'"' => Ok(Json::String(try!(self.string()))),
+            b'[' => self.array(),
+            b'{' => self.object(),
+            _    => self.number(),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.index;
+        while self.index < self.bytes.len() {
+            match self.bytes[self.index] {
+                b'0'...b'9' | b'-' | b'+' | b'.' | b'e' | b'E' => self.index += 1,
+                _ => break,
+            }
+        }
+        let text = String::from_utf8_lossy(&self.bytes[start..self.index]).into_owned();
+        match text.parse::<f64>() {
+            Ok(n)  => Ok(Json::Number(n)),
+            Err(_) => Err(format!("invalid number at {}", start)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        try!(self.expect(b'"'));
+        let mut result: Vec<u8> = Vec::new();
+        while self.index < self.bytes.len() {
+            let byte = self.bytes[self.index];
+            self.index += 1;
+            match byte {
+                b'"'  => return Ok(String::from_utf8_lossy(&result).into_owned()),
+                b'\\' => {
+                    if self.index == self.bytes.len() {
+                        break;
+                    }
+                    let escaped = self.bytes[self.index];
+                    self.index += 1;
+                    result.push(match escaped {
+                        b'n' => b'\n',
+                        b't' => b'\t',
+                        b'r' => b'\r',
+                        other => other,
+                    });
+                },
+                _ => result.push(byte),
+            }
+        }
+        Err(String::from_str("unterminated string"))
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        try!(self.expect(b'['));
+        let mut values: Vec<Json> = Vec::new();
+        if try!(self.peek()) == b']' {
+            self.index += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(try!(self.value()));
+            match try!(self.peek()) {
+                b',' => self.index += 1,
+                b']' => { self.index += 1; return Ok(Json::Array(values)); },
+                _    => return Err(format!("expected ',' or ']' at {}", self.index)),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        try!(self.expect(b'{'));
+        let mut fields: Vec<(String, Json)> = Vec::new();
+        if try!(self.peek()) == b'}' {
+            self.index += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            try!(self.peek());
+            let key = try!(self.string());
+            try!(self.expect(b':'));
+            fields.push((key, try!(self.value())));
+            match try!(self.peek()) {
+                b',' => self.index += 1,
+                b'}' => { self.index += 1; return Ok(Json::Object(fields)); },
+                _    => return Err(format!("expected ',' or '}}' at {}", self.index)),
+            }
+        }
+    }
+}
+
+// ==============================================
+// Runner
+// ==============================================
+
+fn m_cycles(log: &[Access]) -> Vec<Option<Access>> {
+    //! Turn a bus log into the access performed by every M-cycle, if
+    //! any. In cycle accurate mode, this is the access right before
+    //! each tick; earlier ones are interrupt polling.
+    let mut cycles: Vec<Option<Access>> = Vec::new();
+    let mut last: Option<Access> = None;
+    for access in log.iter() {
+        match *access {
+            Access::Tick(n) => for _ in 0..n / 4 {
+                cycles.push(last);
+                last = None;
+            },
+            _ => last = Some(*access),
+        }
+    }
+    cycles
+}
+
+fn field(state: &Json, name: &str) -> Result<u16, String> {
+    match state.get(name).and_then(|v| v.as_u16()) {
+        Some(v) => Ok(v),
+        None    => Err(format!("missing field \"{}\"", name)),
+    }
+}
+
+fn load(state: &Json) -> Result<Cpu<FlatBus>, String> {
+    //! Create a cycle accurate `Cpu` in the initial state of a case.
+    let mut c = Cpu::with_bus(FlatBus::logging());
+    c.reset();
+    c.stop = false;
+    c.cycle_accurate = true;
+    c.a  = try!(field(state, "a")) as u8;
+    c.b  = try!(field(state, "b")) as u8;
+    c.c  = try!(field(state, "c")) as u8;
+    c.d  = try!(field(state, "d")) as u8;
+    c.e  = try!(field(state, "e")) as u8;
+    c.f  = try!(field(state, "f")) as u8;
+    c.h  = try!(field(state, "h")) as u8;
+    c.l  = try!(field(state, "l")) as u8;
+    c.sp = try!(field(state, "sp"));
+    c.pc = try!(field(state, "pc")).wrapping_sub(1);
+    c.ime = field(state, "ime").unwrap_or(0) != 0;
+    if let Ok(ie) = field(state, "ie") {
+        c.mmu.memory[IE as usize] = ie as u8;
+    }
+    for cell in try!(ram(state)).iter() {
+        c.mmu.memory[cell.0 as usize] = cell.1;
+    }
+    Ok(c)
+}
+
+fn ram(state: &Json) -> Result<Vec<(u16, u8)>, String> {
+    let cells = match state.get("ram").and_then(|v| v.as_array()) {
+        Some(cells) => cells,
+        None        => return Err(String::from_str("missing field \"ram\"")),
+    };
+    let mut result: Vec<(u16, u8)> = Vec::new();
+    for cell in cells.iter() {
+        let pair = cell.as_array();
+        match pair.map(|p| (p.len(), p[0].as_u16(), p[1].as_u16())) {
+            Some((2, Some(address), Some(data))) => result.push((address, data as u8)),
+            _ => return Err(String::from_str("invalid \"ram\" entry")),
+        }
+    }
+    Ok(result)
+}
+
+fn expected_cycle(cycle: &Json) -> Result<Option<Access>, String> {
+    //! Read an M-cycle of the `cycles` array: `[address, data, pins]`,
+    //! pins being `r-m` for reads, `-wm` for writes, and `---` (or the
+    //! whole entry being `null`) for internal delays.
+    let entry = match cycle.as_array() {
+        Some(entry) if entry.len() == 3 => entry,
+        _ => return Ok(None),
+    };
+    let pins = entry[2].as_str().unwrap_or("---").as_bytes();
+    let address = entry[0].as_u16().unwrap_or(0);
+    let data = entry[1].as_u16().unwrap_or(0) as u8;
+    match (pins.get(0), pins.get(1)) {
+        (Some(&b'r'), _) => Ok(Some(Access::Read(address, data))),
+        (_, Some(&b'w')) => Ok(Some(Access::Write(address, data))),
+        _ => Ok(None),
+    }
+}
+
+fn run_case(case: &Json) -> Result<(), String> {
+    //! Run a single case, and return the first mismatch, if any.
+    let initial = try!(case.get("initial").ok_or(String::from_str("missing \"initial\"")));
+    let expected = try!(case.get("final").ok_or(String::from_str("missing \"final\"")));
+
+    let mut c = try!(load(initial));
+    let opcode = c.mmu.memory[c.pc as usize];
+    c.mmu.take();
+    if let Err(e) = c.step() {
+        return Err(format!("{}", e));
+    }
+
+    // Registers
+    let registers: [(&str, u16); 10] = [
+        ("a", c.a as u16), ("b", c.b as u16), ("c", c.c as u16),
+        ("d", c.d as u16), ("e", c.e as u16), ("f", c.f as u16),
+        ("h", c.h as u16), ("l", c.l as u16),
+        ("sp", c.sp), ("pc", c.pc.wrapping_add(1)),
+    ];
+    for &(name, value) in registers.iter() {
+        let want = try!(field(expected, name));
+        if value != want {
+            return Err(format!("{}: got 0x{:X}, expected 0x{:X}", name, value, want));
+        }
+    }
+    if let Ok(ime) = field(expected, "ime") {
+        if c.ime != (ime != 0) {
+            return Err(format!("ime: got {}, expected {}", c.ime, ime != 0));
+        }
+    }
+
+    // Vectors carry no halted or stopped state, but only HALT and STOP
+    // may leave the processor in either
+    if c.halt && opcode != 0x76 {
+        return Err(String::from_str("halted"));
+    }
+    if c.stop && opcode != 0x10 {
+        return Err(String::from_str("stopped"));
+    }
+
+    // Memory
+    for cell in try!(ram(expected)).iter() {
+        let value = c.mmu.memory[cell.0 as usize];
+        if value != cell.1 {
+            return Err(format!("(0x{:0>4.4X}): got 0x{:X}, expected 0x{:X}",
+                               cell.0, value, cell.1));
+        }
+    }
+
+    // Bus activity, without the opcode fetch on both sides
+    let cycles = match case.get("cycles").and_then(|v| v.as_array()) {
+        Some(cycles) => cycles,
+        None         => return Ok(()),
+    };
+    let got = m_cycles(&c.mmu.take());
+    if got.len() != cycles.len() {
+        return Err(format!("got {} M-cycles, expected {}", got.len(), cycles.len()));
+    }
+    for i in 1..got.len() {
+        let want = try!(expected_cycle(&cycles[i - 1]));
+        if got[i] != want {
+            return Err(format!("M-cycle {}: got {:?}, expected {:?}", i, got[i], want));
+        }
+    }
+    Ok(())
+}
+
+fn run_file(text: &str) -> Result<(usize, Vec<String>), String> {
+    //! Run all cases of a vector file. Return the number of cases, and
+    //! a message for each failed one.
+    let json = try!(parse(text));
+    let cases = match json.as_array() {
+        Some(cases) => cases,
+        None        => return Err(String::from_str("expected an array of cases")),
+    };
+    let mut failures: Vec<String> = Vec::new();
+    for case in cases.iter() {
+        if let Err(e) = run_case(case) {
+            let name = case.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+            failures.push(format!("{}: {}", name, e));
+        }
+    }
+    Ok((cases.len(), failures))
+}
+
+// ==============================================
+// Tests
+// ==============================================
+
+#[test]
+fn json() {
+    let json = parse(r#" {"a": [1, -2.5, "x\"y"], "b": {}, "c": [], "d": null, "e": true} "#).unwrap();
+    assert_eq!(json.get("a"), Some(&Json::Array(vec![
+        Json::Number(1.0), Json::Number(-2.5), Json::String(String::from_str("x\"y")),
+    ])));
+    assert_eq!(json.get("b"), Some(&Json::Object(vec![])));
+    assert_eq!(json.get("c"), Some(&Json::Array(vec![])));
+    assert_eq!(json.get("d"), Some(&Json::Null));
+    assert_eq!(json.get("e"), Some(&Json::Bool(true)));
+    assert!(parse("[1, 2").is_err());
+    assert!(parse("[1] 2").is_err());
+}
+
+#[test]
+fn sm83_case() {
+    // LD A, (BC) followed by the fetch of the next opcode
+    let pass = r#"[{
+        "name": "0a 0000",
+        "initial": {"a": 0, "b": 192, "c": 0, "d": 0, "e": 0, "f": 176, "h": 0, "l": 0,
+                    "pc": 257, "sp": 65534, "ime": 0,
+                    "ram": [[256, 10], [257, 0], [49152, 66]]},
+        "final":   {"a": 66, "b": 192, "c": 0, "d": 0, "e": 0, "f": 176, "h": 0, "l": 0,
+                    "pc": 258, "sp": 65534, "ime": 0,
+                    "ram": [[256, 10], [257, 0], [49152, 66]]},
+        "cycles":  [[49152, 66, "r-m"], [257, 0, "r-m"]]
+    }]"#;
+    assert_eq!(run_file(pass), Ok((1, vec![])));
+
+    // Same case, expecting a write instead of the read
+    let fail = pass.replace(r#"[[49152, 66, "r-m"]"#, r#"[[49152, 66, "-wm"]"#);
+    let (count, failures) = run_file(&fail).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].starts_with("0a 0000: M-cycle 1"));
+}
+
+#[test]
+fn sm83() {
+    let directory = match os::getenv("SM83_TESTS") {
+        Some(directory) => directory,
+        None            => return,
+    };
+    let mut paths: Vec<Path> = fs::readdir(&Path::new(directory)).unwrap()
+        .into_iter()
+        .filter(|path| path.extension_str() == Some("json"))
+        .collect();
+    paths.sort();
+
+    // Failures by opcode
+    let mut report: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut total: usize = 0;
+    let mut failed: usize = 0;
+    for path in paths.iter() {
+        let opcode = String::from_str(path.filestem_str().unwrap());
+        let text = File::open(path).read_to_string().unwrap();
+        let failures = match run_file(&text) {
+            Ok((count, failures)) => { total += count; failures },
+            Err(e)                => vec![e],
+        };
+        if !failures.is_empty() {
+            failed += failures.len();
+            report.insert(opcode, failures);
+        }
+    }
+
+    for (opcode, failures) in report.iter() {
+        println!("{}: {} failed, first: {}", opcode, failures.len(), failures[0]);
+    }
+    println!("sm83: {} of {} cases passed", total - failed, total);
+    assert!(report.is_empty(), "sm83: {} opcodes failed", report.len());
+}
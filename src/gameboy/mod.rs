@@ -3,22 +3,108 @@
 #![allow(missing_copy_implementations)]
 
 use super::cpu::Cpu;
+use super::cartridge::Cartridge;
 
 #[cfg(test)]
 mod tests;
 
+/// Hardware model emulated by a `GameBoy`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Model {
+    /// Original Game Boy
+    Dmg,
+    /// Game Boy Color
+    Cgb,
+}
+
 pub struct GameBoy {
-    cpu: Cpu,
+    pub cpu: Cpu,
+    model: Model,
 }
 
+/// IO registers as left by the DMG boot ROM.
+const IO_DEFAULTS: [(u16, u8); 33] = [
+    (0xFF00, 0xCF), (0xFF01, 0x00), (0xFF02, 0x7E), (0xFF04, 0xAB),
+    (0xFF05, 0x00), (0xFF06, 0x00), (0xFF07, 0xF8), (0xFF0F, 0xE1),
+    (0xFF10, 0x80), (0xFF11, 0xBF), (0xFF12, 0xF3), (0xFF14, 0xBF),
+    (0xFF16, 0x3F), (0xFF17, 0x00), (0xFF19, 0xBF), (0xFF1A, 0x7F),
+    (0xFF1B, 0xFF), (0xFF1C, 0x9F), (0xFF1E, 0xBF), (0xFF20, 0xFF),
+    (0xFF21, 0x00), (0xFF22, 0x00), (0xFF23, 0xBF), (0xFF24, 0x77),
+    (0xFF25, 0xF3), (0xFF26, 0xF1), (0xFF40, 0x91), (0xFF41, 0x85),
+    (0xFF42, 0x00), (0xFF43, 0x00), (0xFF45, 0x00), (0xFF47, 0xFC),
+    (0xFFFF, 0x00),
+];
+
 // ==============================================
 // Implementation
 // ==============================================
 
 impl GameBoy {
     pub fn new() -> GameBoy {
-        GameBoy {
-            cpu: Cpu::new(),
+        //! Create a new DMG `GameBoy`, without boot ROM.
+        GameBoy::with_model(Model::Dmg, None)
+    }
+
+    pub fn with_model(model: Model, boot: Option<&[u8]>) -> GameBoy {
+        //! Create a new `GameBoy` of the given `model`. With a `boot`
+        //! ROM, execution starts at 0x0000 in the boot ROM. Without
+        //! one, the machine starts at 0x0100 in the state the boot ROM
+        //! of `model` leaves it in.
+        let mut gb = GameBoy {
+            cpu:   Cpu::new(),
+            model: model,
+        };
+        gb.cpu.reset();
+        gb.cpu.mmu.set_cgb(model == Model::Cgb);
+
+        match boot {
+            Some(rom) => gb.cpu.mmu.map_boot_rom(rom),
+            None      => gb.skip_boot(),
+        }
+
+        gb
+    }
+
+    pub fn model(&self) -> Model {
+        self.model
+    }
+
+    pub fn load(&mut self, rom: &[u8]) {
+        //! Insert a cartridge holding `rom`, under the boot ROM if one
+        //! is mapped.
+        self.cpu.mmu.insert(Cartridge::from_bytes(rom));
+    }
+
+    fn skip_boot(&mut self) {
+        //! Set registers and IO to their documented post-boot values
+        let mut r = self.cpu.registers();
+        match self.model {
+            Model::Dmg => {
+                r.set_af(0x01B0);
+                r.set_bc(0x0013);
+                r.set_de(0x00D8);
+                r.set_hl(0x014D);
+            },
+            Model::Cgb => {
+                r.set_af(0x1180);
+                r.set_bc(0x0000);
+                r.set_de(0xFF56);
+                r.set_hl(0x000D);
+            },
+        }
+        r.set_sp(0xFFFE);
+        r.set_pc(0x0100);
+        self.cpu.set_registers(&r);
+
+        // Poked, as some bits are read-only
+        for &(address, data) in IO_DEFAULTS.iter() {
+            self.cpu.mmu.poke(address, data);
+        }
+        if self.model == Model::Cgb {
+            // Serial transfer control has a clock speed bit on CGB
+            self.cpu.mmu.poke(0xFF02, 0x7F);
         }
+        // Boot ROM is done
+        self.cpu.mmu.write8(0xFF50, 0x01);
     }
 }
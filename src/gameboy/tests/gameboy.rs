@@ -1,6 +1,92 @@
-//use super::super::*;
+use super::super::*;
 
 #[test]
 fn new() {
-    //let gb = GameBoy::new();
+    // Without boot ROM, start in the post-boot state
+    let gb = GameBoy::new();
+    let r = gb.cpu.registers();
+
+    assert_eq!(gb.model(), Model::Dmg);
+    assert_eq!(r.af(), 0x01B0);
+    assert_eq!(r.bc(), 0x0013);
+    assert_eq!(r.de(), 0x00D8);
+    assert_eq!(r.hl(), 0x014D);
+    assert_eq!(r.sp(), 0xFFFE);
+    assert_eq!(r.pc(), 0x0100);
+    assert_eq!(gb.cpu.mmu.read8(0xFF40), 0x91);
+    assert_eq!(gb.cpu.mmu.read8(0xFF41), 0x85);
+    assert_eq!(gb.cpu.mmu.read8(0xFF47), 0xFC);
+    assert!(!gb.cpu.mmu.boot_rom_mapped());
+    assert!(!gb.cpu.mmu.cgb());
+}
+
+#[test]
+fn new_cgb() {
+    let gb = GameBoy::with_model(Model::Cgb, None);
+    let r = gb.cpu.registers();
+
+    assert_eq!(r.af(), 0x1180);
+    assert_eq!(r.bc(), 0x0000);
+    assert_eq!(r.de(), 0xFF56);
+    assert_eq!(r.hl(), 0x000D);
+    assert_eq!(r.pc(), 0x0100);
+    assert_eq!(gb.cpu.mmu.read8(0xFF02), 0x7F);
+    assert!(gb.cpu.mmu.cgb());
+    assert_eq!(gb.cpu.mmu.read8(0xFF70), 0xF8);
+}
+
+#[test]
+fn boot_rom() {
+    // The boot ROM is executed from 0x0000, over the cartridge
+    let bios = include_bytes!("../../../samples/bios.z80");
+    let mut gb = GameBoy::with_model(Model::Dmg, Some(bios));
+    gb.load(&[0xC3, 0x50, 0x01]);
+    assert_eq!(gb.cpu.pc, 0x0000);
+    assert!(gb.cpu.mmu.boot_rom_mapped());
+
+    gb.cpu.step().unwrap();                     // 0000 LD SP, 0xFFFE
+    assert_eq!(gb.cpu.registers().sp(), 0xFFFE);
+
+    // Last boot ROM instruction unmaps it, the cartridge shows through
+    gb.cpu.pc = 0x00FC;
+    gb.cpu.step().unwrap();                     // 00FC LD A, 0x01
+    gb.cpu.step().unwrap();                     // 00FE LDH (0x50), A
+    assert_eq!(gb.cpu.pc, 0x0100);
+    assert!(!gb.cpu.mmu.boot_rom_mapped());
+    assert_eq!(gb.cpu.mmu.read8(0x0000), 0xC3);
+}
+
+#[test]
+fn dma() {
+    // Sprites are copied by a routine waiting in high RAM
+    let mut gb = GameBoy::new();
+    gb.load(&[0; 0x0100]);
+    let program = [0x3E, 0xC0,                  // 0100 LD A, 0xC0
+                   0xCD, 0x80, 0xFF];           // 0102 CALL 0xFF80
+    let routine = [0xE0, 0x46,                  // FF80 LDH (0x46), A
+                   0x3E, 0x28,                  // FF82 LD A, 0x28
+                   0x3D,                        // FF84 DEC A
+                   0x20, 0xFD,                  // FF85 JR NZ, 0xFF84
+                   0xC9];                       // FF87 RET
+    for (i, data) in program.iter().enumerate() {
+        gb.cpu.mmu.poke(0x0100 + i as u16, *data);
+    }
+    for (i, data) in routine.iter().enumerate() {
+        gb.cpu.mmu.poke(0xFF80 + i as u16, *data);
+    }
+    for i in 0..0xA0 {
+        gb.cpu.mmu.poke(0xC000 + i, i as u8);
+    }
+
+    for _ in 0..100 {
+        gb.cpu.step().unwrap();
+        if gb.cpu.pc == 0x0105 {
+            break;
+        }
+    }
+    assert_eq!(gb.cpu.pc, 0x0105);
+    assert!(!gb.cpu.mmu.dma_active());
+    for i in 0..0xA0 {
+        assert_eq!(gb.cpu.mmu.read8(0xFE00 + i), i as u8);
+    }
 }
@@ -0,0 +1,240 @@
+#![allow(dead_code)]
+#![allow(missing_copy_implementations)]
+
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// Logo the boot ROM displays, and compares with 0x0104-0x0133 before
+/// starting the cartridge.
+pub const NINTENDO_LOGO: [u8; 48] = [
+    0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73, 0x00, 0x83,
+    0x00, 0x0C, 0x00, 0x0D, 0x00, 0x08, 0x11, 0x1F, 0x88, 0x89, 0x00, 0x0E,
+    0xDC, 0xCC, 0x6E, 0xE6, 0xDD, 0xDD, 0xD9, 0x99, 0xBB, 0xBB, 0x67, 0x63,
+    0x6E, 0x0E, 0xEC, 0xCC, 0xDD, 0xDC, 0x99, 0x9F, 0xBB, 0xB9, 0x33, 0x3E,
+];
+
+/// Size of a ROM image holding a complete header
+pub const HEADER_END: usize = 0x0150;
+
+/// Problems found in a cartridge header. None of them prevents parsing.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeaderWarning {
+    /// ROM image is smaller than the header, missing bytes read as 0.
+    Truncated { size: usize },
+    /// Logo differs from `NINTENDO_LOGO`. The boot ROM locks up.
+    BadLogo,
+    /// Header checksum at 0x014D does not match. The boot ROM locks up.
+    HeaderChecksum { expected: u8, actual: u8 },
+    /// Global checksum at 0x014E-0x014F does not match. Unchecked by
+    /// hardware.
+    GlobalChecksum { expected: u16, actual: u16 },
+    /// Title holds bytes other than printable ASCII, read as `?`.
+    TitleNotAscii,
+    /// ROM size code at 0x0148 is unknown.
+    UnknownRomSize(u8),
+    /// RAM size code at 0x0149 is unknown.
+    UnknownRamSize(u8),
+}
+
+/// Cartridge header, found at 0x0100-0x014F of a ROM image.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CartridgeHeader {
+    /// Title, without its zero padding
+    pub title: String,
+    /// Manufacturer code, ending the title area of newer cartridges
+    pub manufacturer: Option<String>,
+    /// CGB support flag (0x0143): 0x80 for CGB enhanced cartridges,
+    /// 0xC0 for CGB only ones.
+    pub cgb_flag: u8,
+    /// SGB support flag (0x0146), 0x03 for SGB enhanced cartridges
+    pub sgb_flag: u8,
+    /// Cartridge type (0x0147), telling the mapper and extra hardware
+    pub cartridge_type: u8,
+    /// ROM size code (0x0148)
+    pub rom_size: u8,
+    /// RAM size code (0x0149)
+    pub ram_size: u8,
+    /// Destination code (0x014A), 0x00 for Japan
+    pub destination: u8,
+    /// Old licensee code (0x014B)
+    pub old_licensee: u8,
+    /// New licensee code (0x0144-0x0145), used when the old licensee
+    /// code is 0x33
+    pub new_licensee: Option<String>,
+    /// Mask ROM version (0x014C)
+    pub version: u8,
+    /// Header checksum, as stored
+    pub header_checksum: u8,
+    /// Global checksum, as stored
+    pub global_checksum: u16,
+    /// Problems found while parsing
+    pub warnings: Vec<HeaderWarning>,
+}
+
+// ==============================================
+// Implementation
+// ==============================================
+
+impl CartridgeHeader {
+    pub fn parse(rom: &[u8]) -> CartridgeHeader {
+        //! Parse the header of a ROM image. Problems are reported in
+        //! `warnings`, parsing never fails.
+        let mut warnings: Vec<HeaderWarning> = Vec::new();
+        let mut header: [u8; HEADER_END] = [0; HEADER_END];
+        for (i, byte) in rom.iter().take(HEADER_END).enumerate() {
+            header[i] = *byte;
+        }
+        if rom.len() < HEADER_END {
+            warnings.push(HeaderWarning::Truncated { size: rom.len() });
+        }
+
+        if header[0x0104..0x0134] != NINTENDO_LOGO[..] {
+            warnings.push(HeaderWarning::BadLogo);
+        }
+
+        // Title area is 16 bytes on DMG cartridges, 15 when followed by
+        // the CGB flag, and 11 when followed by a manufacturer code,
+        // which is told apart by its 4 upper case letters or digits.
+        let cgb_flag: u8 = header[0x0143];
+        let code = &header[0x013F..0x0143];
+        let mut manufacturer: Option<String> = None;
+        let title_end: usize = if cgb_flag & 0x80 == 0 {
+            0x0144
+        } else if code.iter().all(|&b| (b >= b'A' && b <= b'Z') || (b >= b'0' && b <= b'9')) {
+            manufacturer = Some(ascii(code));
+            0x013F
+        } else {
+            0x0143
+        };
+        let title_len: usize = header[0x0134..title_end].iter().take_while(|&&b| b != 0).count();
+        let title = &header[0x0134..0x0134 + title_len];
+        if title.iter().any(|&b| b < 0x20 || b > 0x7E) {
+            warnings.push(HeaderWarning::TitleNotAscii);
+        }
+
+        let old_licensee: u8 = header[0x014B];
+        let new_licensee: Option<String> = match old_licensee {
+            0x33 => Some(ascii(&header[0x0144..0x0146])),
+            _    => None,
+        };
+
+        let header_checksum: u8 = header[0x014D];
+        let expected: u8 = checksum(&header);
+        if header_checksum != expected {
+            warnings.push(HeaderWarning::HeaderChecksum {
+                expected: expected,
+                actual:   header_checksum,
+            });
+        }
+
+        let global_checksum: u16 = ((header[0x014E] as u16) << 8) + header[0x014F] as u16;
+        let expected: u16 = global(rom);
+        if global_checksum != expected {
+            warnings.push(HeaderWarning::GlobalChecksum {
+                expected: expected,
+                actual:   global_checksum,
+            });
+        }
+
+        let mut parsed = CartridgeHeader {
+            title:           ascii(title),
+            manufacturer:    manufacturer,
+            cgb_flag:        cgb_flag,
+            sgb_flag:        header[0x0146],
+            cartridge_type:  header[0x0147],
+            rom_size:        header[0x0148],
+            ram_size:        header[0x0149],
+            destination:     header[0x014A],
+            old_licensee:    old_licensee,
+            new_licensee:    new_licensee,
+            version:         header[0x014C],
+            header_checksum: header_checksum,
+            global_checksum: global_checksum,
+            warnings:        warnings,
+        };
+        if parsed.rom_banks().is_none() {
+            parsed.warnings.push(HeaderWarning::UnknownRomSize(parsed.rom_size));
+        }
+        if parsed.ram_bytes().is_none() {
+            parsed.warnings.push(HeaderWarning::UnknownRamSize(parsed.ram_size));
+        }
+        parsed
+    }
+
+    pub fn rom_banks(&self) -> Option<usize> {
+        //! Number of 16K ROM banks given by the ROM size code
+        match self.rom_size {
+            0x00...0x08 => Some(2 << self.rom_size as usize),
+            _           => None,
+        }
+    }
+
+    pub fn ram_bytes(&self) -> Option<usize> {
+        //! Size of cartridge RAM given by the RAM size code
+        match self.ram_size {
+            0x00 => Some(0),
+            0x01 => Some(0x0800),
+            0x02 => Some(0x2000),
+            0x03 => Some(0x8000),
+            0x04 => Some(0x20000),
+            0x05 => Some(0x10000),
+            _    => None,
+        }
+    }
+
+    pub fn cgb_only(&self) -> bool {
+        self.cgb_flag == 0xC0
+    }
+
+    pub fn sgb(&self) -> bool {
+        self.sgb_flag == 0x03
+    }
+
+    pub fn japanese(&self) -> bool {
+        self.destination == 0x00
+    }
+}
+
+fn ascii(bytes: &[u8]) -> String {
+    //! Convert bytes to a string, replacing non printable ASCII by `?`
+    bytes.iter().map(|&b| if b >= 0x20 && b <= 0x7E { b as char } else { '?' }).collect()
+}
+
+fn checksum(header: &[u8]) -> u8 {
+    //! Compute the header checksum over 0x0134-0x014C
+    header[0x0134..0x014D].iter().fold(0u8, |x, &b| x.wrapping_sub(b).wrapping_sub(1))
+}
+
+fn global(rom: &[u8]) -> u16 {
+    //! Compute the global checksum, over the whole image but itself
+    rom.iter().enumerate()
+       .filter(|&(i, _)| i != 0x014E && i != 0x014F)
+       .fold(0u16, |x, (_, &b)| x.wrapping_add(b as u16))
+}
+
+// ==============================================
+// Traits
+// ==============================================
+
+impl fmt::Display for HeaderWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HeaderWarning::Truncated { size } =>
+                write!(f, "image is truncated to {} bytes", size),
+            HeaderWarning::BadLogo =>
+                write!(f, "logo does not match"),
+            HeaderWarning::HeaderChecksum { expected, actual } =>
+                write!(f, "header checksum is 0x{:0>2.2X}, expected 0x{:0>2.2X}", actual, expected),
+            HeaderWarning::GlobalChecksum { expected, actual } =>
+                write!(f, "global checksum is 0x{:0>4.4X}, expected 0x{:0>4.4X}", actual, expected),
+            HeaderWarning::TitleNotAscii =>
+                write!(f, "title is not printable ASCII"),
+            HeaderWarning::UnknownRomSize(code) =>
+                write!(f, "unknown ROM size code 0x{:0>2.2X}", code),
+            HeaderWarning::UnknownRamSize(code) =>
+                write!(f, "unknown RAM size code 0x{:0>2.2X}", code),
+        }
+    }
+}
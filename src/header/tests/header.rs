@@ -0,0 +1,93 @@
+use super::super::*;
+use super::super::{checksum, global};
+
+fn rom() -> Vec<u8> {
+    //! 32K image with a valid header, titled `TEST`
+    let mut rom = vec![0; 0x8000];
+    for (i, byte) in NINTENDO_LOGO.iter().enumerate() {
+        rom[0x0104 + i] = *byte;
+    }
+    for (i, byte) in b"TEST".iter().enumerate() {
+        rom[0x0134 + i] = *byte;
+    }
+    fix(&mut rom);
+    rom
+}
+
+fn fix(rom: &mut Vec<u8>) {
+    //! Update both checksums
+    rom[0x014D] = checksum(&rom);
+    let sum: u16 = global(&rom);
+    rom[0x014E] = (sum >> 8) as u8;
+    rom[0x014F] = sum as u8;
+}
+
+#[test]
+fn tetris() {
+    let h = CartridgeHeader::parse(include_bytes!("../../../tetris.gb"));
+    assert_eq!(h.title, "TETRIS");
+    assert_eq!(h.manufacturer, None);
+    assert_eq!(h.cartridge_type, 0x00);
+    assert_eq!(h.rom_banks(), Some(2));
+    assert_eq!(h.ram_bytes(), Some(0));
+    assert_eq!(h.old_licensee, 0x01);
+    assert_eq!(h.new_licensee, None);
+    assert_eq!(h.version, 0x01);
+    assert_eq!(h.header_checksum, 0x0A);
+    assert_eq!(h.global_checksum, 0x16BF);
+    assert!(h.japanese());
+    assert!(h.warnings.is_empty());
+}
+
+#[test]
+fn cgb() {
+    // CGB cartridges end their title with a manufacturer code
+    let mut rom = rom();
+    for (i, byte) in b"POKEMON GLDAAUE\x80".iter().enumerate() {
+        rom[0x0134 + i] = *byte;
+    }
+    rom[0x0144] = b'0';
+    rom[0x0145] = b'1';
+    rom[0x0146] = 0x03;
+    rom[0x014B] = 0x33;
+    fix(&mut rom);
+    let h = CartridgeHeader::parse(&rom);
+    assert_eq!(h.title, "POKEMON GLD");
+    assert_eq!(h.manufacturer, Some(String::from_str("AAUE")));
+    assert_eq!(h.new_licensee, Some(String::from_str("01")));
+    assert!(!h.cgb_only());
+    assert!(h.sgb());
+    assert!(h.warnings.is_empty());
+
+    // Or with a 15 characters title
+    rom[0x0143] = 0xC0;
+    rom[0x013F] = b' ';
+    fix(&mut rom);
+    let h = CartridgeHeader::parse(&rom);
+    assert_eq!(h.title, "POKEMON GLD AUE");
+    assert_eq!(h.manufacturer, None);
+    assert!(h.cgb_only());
+}
+
+#[test]
+fn warnings() {
+    let mut rom = rom();
+    rom[0x0104] = 0x00;
+    rom[0x0135] = 0xC3;
+    rom[0x0148] = 0x09;
+    rom[0x0149] = 0x06;
+    let h = CartridgeHeader::parse(&rom);
+    assert_eq!(h.title, "T?ST");
+    assert_eq!(h.warnings, vec![
+        HeaderWarning::BadLogo,
+        HeaderWarning::TitleNotAscii,
+        HeaderWarning::HeaderChecksum { expected: 0x1A, actual: 0xA7 },
+        HeaderWarning::GlobalChecksum { expected: 0x16EC, actual: 0x172D },
+        HeaderWarning::UnknownRomSize(0x09),
+        HeaderWarning::UnknownRamSize(0x06),
+    ]);
+    assert_eq!(format!("{}", h.warnings[2]), "header checksum is 0xA7, expected 0x1A");
+
+    let h = CartridgeHeader::parse(&rom[..0x0140]);
+    assert_eq!(h.warnings[0], HeaderWarning::Truncated { size: 0x0140 });
+}
@@ -0,0 +1,423 @@
+#![allow(dead_code)]
+#![allow(missing_copy_implementations)]
+
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// 8 bit registers, as found in instruction operands.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Register {
+    A, B, C, D, E, H, L,
+}
+
+/// 16 bit registers and register pairs, as found in
+/// instruction operands.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RegisterPair {
+    AF, BC, DE, HL, SP,
+}
+
+/// Conditions of conditional jumps, calls and returns.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Condition {
+    /// Zero flag unset
+    NZ,
+    /// Zero flag set
+    Z,
+    /// Carry flag unset
+    NC,
+    /// Carry flag set
+    C,
+}
+
+/// All different kinds of instruction operands.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operand {
+    /// 8 bit register, as in `INC B`
+    Register(Register),
+    /// 16 bit register, as in `INC BC`
+    RegisterPair(RegisterPair),
+    /// Memory pointed to by a register pair, as in `LD A, (BC)`
+    Indirect(RegisterPair),
+    /// Memory pointed to by hl, which is then incremented: `(HL+)`
+    IndirectIncrement,
+    /// Memory pointed to by hl, which is then decremented: `(HL-)`
+    IndirectDecrement,
+    /// High memory at 0xFF00 plus c: `(C)`
+    HighIndirect,
+    /// 8 bit immediate value, as in `LD B, d8`
+    Immediate8(u8),
+    /// 16 bit immediate value, as in `LD BC, d16`
+    Immediate16(u16),
+    /// Signed 8 bit offset, as in `JR r8`
+    Offset(i8),
+    /// Stack pointer plus a signed 8 bit offset: `SP+r8`
+    StackOffset(i8),
+    /// Jump or call target, as in `JP a16`
+    Address(u16),
+    /// Memory at a 16 bit address, as in `LD A, (a16)`
+    Memory(u16),
+    /// High memory at 0xFF00 plus an 8 bit offset, as in `LDH A, (a8)`
+    HighMemory(u8),
+    /// Branch condition, as in `JP NZ, a16`
+    Condition(Condition),
+    /// Bit index, as in `BIT 3, A`
+    Bit(u8),
+    /// Restart vector, as in `RST 38H`
+    Vector(u8),
+}
+
+/// Effect of an instruction on a single flag.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FlagEffect {
+    /// Flag is left untouched
+    Preserved,
+    /// Flag is forced to 0
+    Reset,
+    /// Flag is forced to 1
+    Set,
+    /// Flag depends on the result of the operation
+    Affected,
+}
+
+/// This struct represents a single decoded instruction.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Instruction {
+    /// Opcode, prefix included for `0xCBxx` opcodes
+    pub opcode: u16,
+    /// Mnemonic, such as `LD` or `JR`
+    pub mnemonic: &'static str,
+    /// Operands, destination first
+    pub operands: Vec<Operand>,
+    /// Size in bytes, opcode included
+    pub length: u8,
+    /// Clock cycles, when the branch is not taken for conditional
+    /// instructions
+    pub cycles: u8,
+    /// Clock cycles when the branch is taken, for conditional
+    /// instructions only
+    pub branch_cycles: Option<u8>,
+    /// Effects on flags `Z`, `N`, `H` and `C`, in that order
+    pub flags: [FlagEffect; 4],
+}
+
+// ==============================================
+// Implementation
+// ==============================================
+
+use self::FlagEffect::{Preserved, Reset, Set, Affected};
+
+const NO_FLAGS: [FlagEffect; 4] = [Preserved, Preserved, Preserved, Preserved];
+
+const R8: [Operand; 8] = [
+    Operand::Register(Register::B),
+    Operand::Register(Register::C),
+    Operand::Register(Register::D),
+    Operand::Register(Register::E),
+    Operand::Register(Register::H),
+    Operand::Register(Register::L),
+    Operand::Indirect(RegisterPair::HL),
+    Operand::Register(Register::A),
+];
+
+const R16: [RegisterPair; 4] = [
+    RegisterPair::BC, RegisterPair::DE, RegisterPair::HL, RegisterPair::SP,
+];
+
+const R16_STACK: [RegisterPair; 4] = [
+    RegisterPair::BC, RegisterPair::DE, RegisterPair::HL, RegisterPair::AF,
+];
+
+const CONDITIONS: [Condition; 4] = [
+    Condition::NZ, Condition::Z, Condition::NC, Condition::C,
+];
+
+const ACCUMULATOR: Operand = Operand::Register(Register::A);
+
+/// Illegal opcodes, that are not part of the instruction set.
+pub const ILLEGAL_OPCODES: [u8; 11] = [
+    0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+];
+
+impl Instruction {
+    fn new(opcode: u16, mnemonic: &'static str, operands: Vec<Operand>,
+           length: u8, cycles: u8, flags: [FlagEffect; 4]) -> Instruction {
+        Instruction {
+            opcode:        opcode,
+            mnemonic:      mnemonic,
+            operands:      operands,
+            length:        length,
+            cycles:        cycles,
+            branch_cycles: None,
+            flags:         flags,
+        }
+    }
+
+    fn branch(mut self, cycles: u8) -> Instruction {
+        self.branch_cycles = Some(cycles);
+        self
+    }
+}
+
+pub fn decode(bytes: &[u8]) -> Option<Instruction> {
+    //! Decode the instruction at the start of `bytes`. Return `None`
+    //! for illegal opcodes, or when `bytes` is too short to hold
+    //! the whole instruction.
+    if bytes.is_empty() {
+        return None;
+    }
+    let opcode: u8 = bytes[0];
+    let instruction = if opcode == 0xCB {
+        if bytes.len() < 2 {
+            return None;
+        }
+        decode_cb(bytes[1])
+    } else {
+        match decode_base(opcode, bytes) {
+            Some(i) => i,
+            None    => return None,
+        }
+    };
+    if (instruction.length as usize) > bytes.len() {
+        return None;
+    }
+    Some(instruction)
+}
+
+fn imm8(bytes: &[u8]) -> u8 {
+    //! Return the 8 bit immediate following the opcode, or 0 if it is
+    //! missing. Length is checked afterwards by `decode`.
+    if bytes.len() > 1 { bytes[1] } else { 0 }
+}
+
+fn imm16(bytes: &[u8]) -> u16 {
+    //! Return the 16 bit little-endian immediate following the opcode,
+    //! or 0 if it is missing. Length is checked afterwards by `decode`.
+    if bytes.len() > 2 {
+        ((bytes[2] as u16) << 8) + bytes[1] as u16
+    } else {
+        0
+    }
+}
+
+fn decode_base(opcode: u8, bytes: &[u8]) -> Option<Instruction> {
+    //! Decode a non prefixed opcode.
+    let op: u16 = opcode as u16;
+    let x: usize = (opcode >> 6) as usize;
+    let y: usize = ((opcode >> 3) & 0x07) as usize;
+    let z: usize = (opcode & 0x07) as usize;
+    let hl_y: bool = y == 6;
+    let hl_z: bool = z == 6;
+
+    let instruction = match opcode {
+        // Misc and 16 bit loads
+        0x00 => Instruction::new(op, "NOP", vec![], 1, 4, NO_FLAGS),
+        0x08 => Instruction::new(op, "LD",
+                    vec![Operand::Memory(imm16(bytes)), Operand::RegisterPair(RegisterPair::SP)],
+                    3, 20, NO_FLAGS),
+        // STOP is executed as a single byte instruction, the trailing
+        // 0x00 is then decoded as a NOP.
+        0x10 => Instruction::new(op, "STOP", vec![], 1, 4, NO_FLAGS),
+        0x18 => Instruction::new(op, "JR", vec![Operand::Offset(imm8(bytes) as i8)], 2, 12, NO_FLAGS),
+        0x20 | 0x28 | 0x30 | 0x38 =>
+                Instruction::new(op, "JR",
+                    vec![Operand::Condition(CONDITIONS[y - 4]), Operand::Offset(imm8(bytes) as i8)],
+                    2, 8, NO_FLAGS).branch(12),
+        0x01 | 0x11 | 0x21 | 0x31 =>
+                Instruction::new(op, "LD",
+                    vec![Operand::RegisterPair(R16[y >> 1]), Operand::Immediate16(imm16(bytes))],
+                    3, 12, NO_FLAGS),
+        0x09 | 0x19 | 0x29 | 0x39 =>
+                Instruction::new(op, "ADD",
+                    vec![Operand::RegisterPair(RegisterPair::HL), Operand::RegisterPair(R16[y >> 1])],
+                    1, 8, [Preserved, Reset, Affected, Affected]),
+        // Indirect loads
+        0x02 => Instruction::new(op, "LD", vec![Operand::Indirect(RegisterPair::BC), ACCUMULATOR], 1, 8, NO_FLAGS),
+        0x12 => Instruction::new(op, "LD", vec![Operand::Indirect(RegisterPair::DE), ACCUMULATOR], 1, 8, NO_FLAGS),
+        0x22 => Instruction::new(op, "LD", vec![Operand::IndirectIncrement, ACCUMULATOR], 1, 8, NO_FLAGS),
+        0x32 => Instruction::new(op, "LD", vec![Operand::IndirectDecrement, ACCUMULATOR], 1, 8, NO_FLAGS),
+        0x0A => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::Indirect(RegisterPair::BC)], 1, 8, NO_FLAGS),
+        0x1A => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::Indirect(RegisterPair::DE)], 1, 8, NO_FLAGS),
+        0x2A => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::IndirectIncrement], 1, 8, NO_FLAGS),
+        0x3A => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::IndirectDecrement], 1, 8, NO_FLAGS),
+        // 16 bit increments and decrements
+        0x03 | 0x13 | 0x23 | 0x33 =>
+                Instruction::new(op, "INC", vec![Operand::RegisterPair(R16[y >> 1])], 1, 8, NO_FLAGS),
+        0x0B | 0x1B | 0x2B | 0x3B =>
+                Instruction::new(op, "DEC", vec![Operand::RegisterPair(R16[y >> 1])], 1, 8, NO_FLAGS),
+        // 8 bit increments, decrements and immediate loads
+        _ if x == 0 && z == 4 =>
+                Instruction::new(op, "INC", vec![R8[y]], 1, if hl_y { 12 } else { 4 },
+                    [Affected, Reset, Affected, Preserved]),
+        _ if x == 0 && z == 5 =>
+                Instruction::new(op, "DEC", vec![R8[y]], 1, if hl_y { 12 } else { 4 },
+                    [Affected, Set, Affected, Preserved]),
+        _ if x == 0 && z == 6 =>
+                Instruction::new(op, "LD", vec![R8[y], Operand::Immediate8(imm8(bytes))],
+                    2, if hl_y { 12 } else { 8 }, NO_FLAGS),
+        // Accumulator rotations and flag operations
+        0x07 => Instruction::new(op, "RLCA", vec![], 1, 4, [Reset, Reset, Reset, Affected]),
+        0x0F => Instruction::new(op, "RRCA", vec![], 1, 4, [Reset, Reset, Reset, Affected]),
+        0x17 => Instruction::new(op, "RLA", vec![], 1, 4, [Reset, Reset, Reset, Affected]),
+        0x1F => Instruction::new(op, "RRA", vec![], 1, 4, [Reset, Reset, Reset, Affected]),
+        0x27 => Instruction::new(op, "DAA", vec![], 1, 4, [Affected, Preserved, Reset, Affected]),
+        0x2F => Instruction::new(op, "CPL", vec![], 1, 4, [Preserved, Set, Set, Preserved]),
+        0x37 => Instruction::new(op, "SCF", vec![], 1, 4, [Preserved, Reset, Reset, Set]),
+        0x3F => Instruction::new(op, "CCF", vec![], 1, 4, [Preserved, Reset, Reset, Affected]),
+        // 8 bit register loads
+        0x76 => Instruction::new(op, "HALT", vec![], 1, 4, NO_FLAGS),
+        _ if x == 1 =>
+                Instruction::new(op, "LD", vec![R8[y], R8[z]], 1, if hl_y || hl_z { 8 } else { 4 }, NO_FLAGS),
+        // 8 bit arithmetic and logic
+        _ if x == 2 => alu(op, y, R8[z], 1, if hl_z { 8 } else { 4 }),
+        _ if x == 3 && z == 6 => alu(op, y, Operand::Immediate8(imm8(bytes)), 2, 8),
+        // Calls, jumps and returns
+        0xC0 | 0xC8 | 0xD0 | 0xD8 =>
+                Instruction::new(op, "RET", vec![Operand::Condition(CONDITIONS[y])], 1, 8, NO_FLAGS).branch(20),
+        0xC2 | 0xCA | 0xD2 | 0xDA =>
+                Instruction::new(op, "JP",
+                    vec![Operand::Condition(CONDITIONS[y]), Operand::Address(imm16(bytes))],
+                    3, 12, NO_FLAGS).branch(16),
+        0xC4 | 0xCC | 0xD4 | 0xDC =>
+                Instruction::new(op, "CALL",
+                    vec![Operand::Condition(CONDITIONS[y]), Operand::Address(imm16(bytes))],
+                    3, 12, NO_FLAGS).branch(24),
+        0xC9 => Instruction::new(op, "RET", vec![], 1, 16, NO_FLAGS),
+        0xD9 => Instruction::new(op, "RETI", vec![], 1, 16, NO_FLAGS),
+        0xC3 => Instruction::new(op, "JP", vec![Operand::Address(imm16(bytes))], 3, 16, NO_FLAGS),
+        0xE9 => Instruction::new(op, "JP", vec![Operand::Indirect(RegisterPair::HL)], 1, 4, NO_FLAGS),
+        0xCD => Instruction::new(op, "CALL", vec![Operand::Address(imm16(bytes))], 3, 24, NO_FLAGS),
+        _ if x == 3 && z == 7 =>
+                Instruction::new(op, "RST", vec![Operand::Vector((y * 8) as u8)], 1, 16, NO_FLAGS),
+        // Stack
+        0xC1 | 0xD1 | 0xE1 =>
+                Instruction::new(op, "POP", vec![Operand::RegisterPair(R16_STACK[y >> 1])], 1, 12, NO_FLAGS),
+        0xF1 => Instruction::new(op, "POP", vec![Operand::RegisterPair(RegisterPair::AF)], 1, 12,
+                    [Affected, Affected, Affected, Affected]),
+        0xC5 | 0xD5 | 0xE5 | 0xF5 =>
+                Instruction::new(op, "PUSH", vec![Operand::RegisterPair(R16_STACK[y >> 1])], 1, 16, NO_FLAGS),
+        0xE8 => Instruction::new(op, "ADD",
+                    vec![Operand::RegisterPair(RegisterPair::SP), Operand::Offset(imm8(bytes) as i8)],
+                    2, 16, [Reset, Reset, Affected, Affected]),
+        0xF8 => Instruction::new(op, "LD",
+                    vec![Operand::RegisterPair(RegisterPair::HL), Operand::StackOffset(imm8(bytes) as i8)],
+                    2, 12, [Reset, Reset, Affected, Affected]),
+        0xF9 => Instruction::new(op, "LD",
+                    vec![Operand::RegisterPair(RegisterPair::SP), Operand::RegisterPair(RegisterPair::HL)],
+                    1, 8, NO_FLAGS),
+        // High memory and absolute loads
+        0xE0 => Instruction::new(op, "LDH", vec![Operand::HighMemory(imm8(bytes)), ACCUMULATOR], 2, 12, NO_FLAGS),
+        0xF0 => Instruction::new(op, "LDH", vec![ACCUMULATOR, Operand::HighMemory(imm8(bytes))], 2, 12, NO_FLAGS),
+        0xE2 => Instruction::new(op, "LD", vec![Operand::HighIndirect, ACCUMULATOR], 1, 8, NO_FLAGS),
+        0xF2 => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::HighIndirect], 1, 8, NO_FLAGS),
+        0xEA => Instruction::new(op, "LD", vec![Operand::Memory(imm16(bytes)), ACCUMULATOR], 3, 16, NO_FLAGS),
+        0xFA => Instruction::new(op, "LD", vec![ACCUMULATOR, Operand::Memory(imm16(bytes))], 3, 16, NO_FLAGS),
+        // Interrupts
+        0xF3 => Instruction::new(op, "DI", vec![], 1, 4, NO_FLAGS),
+        0xFB => Instruction::new(op, "EI", vec![], 1, 4, NO_FLAGS),
+        // 0xCB is handled by `decode`, everything left is illegal
+        _ => return None,
+    };
+    Some(instruction)
+}
+
+fn alu(opcode: u16, operation: usize, operand: Operand, length: u8, cycles: u8) -> Instruction {
+    //! Build one of the eight arithmetic and logic operations on a.
+    //! `ADD`, `ADC` and `SBC` name a explicitly, the others do not.
+    let (mnemonic, flags) = match operation {
+        0 => ("ADD", [Affected, Reset, Affected, Affected]),
+        1 => ("ADC", [Affected, Reset, Affected, Affected]),
+        2 => ("SUB", [Affected, Set, Affected, Affected]),
+        3 => ("SBC", [Affected, Set, Affected, Affected]),
+        4 => ("AND", [Affected, Reset, Set, Reset]),
+        5 => ("XOR", [Affected, Reset, Reset, Reset]),
+        6 => ("OR",  [Affected, Reset, Reset, Reset]),
+        _ => ("CP",  [Affected, Set, Affected, Affected]),
+    };
+    let operands = match operation {
+        0 | 1 | 3 => vec![ACCUMULATOR, operand],
+        _         => vec![operand],
+    };
+    Instruction::new(opcode, mnemonic, operands, length, cycles, flags)
+}
+
+fn decode_cb(opcode: u8) -> Instruction {
+    //! Decode the byte following a `0xCB` prefix.
+    let op: u16 = 0xCB00 + opcode as u16;
+    let y: usize = ((opcode >> 3) & 0x07) as usize;
+    let z: usize = (opcode & 0x07) as usize;
+    let hl: bool = z == 6;
+
+    match opcode >> 6 {
+        0 => {
+            let mnemonic = ["RLC", "RRC", "RL", "RR", "SLA", "SRA", "SWAP", "SRL"][y];
+            let carry = if y == 6 { Reset } else { Affected };
+            Instruction::new(op, mnemonic, vec![R8[z]], 2, if hl { 16 } else { 8 },
+                [Affected, Reset, Reset, carry])
+        },
+        1 => Instruction::new(op, "BIT", vec![Operand::Bit(y as u8), R8[z]], 2, if hl { 12 } else { 8 },
+                [Affected, Reset, Set, Preserved]),
+        2 => Instruction::new(op, "RES", vec![Operand::Bit(y as u8), R8[z]], 2, if hl { 16 } else { 8 },
+                NO_FLAGS),
+        _ => Instruction::new(op, "SET", vec![Operand::Bit(y as u8), R8[z]], 2, if hl { 16 } else { 8 },
+                NO_FLAGS),
+    }
+}
+
+// ==============================================
+// Traits
+// ==============================================
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for RegisterPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Operand::Register(r)     => write!(f, "{}", r),
+            Operand::RegisterPair(r) => write!(f, "{}", r),
+            Operand::Indirect(r)     => write!(f, "({})", r),
+            Operand::IndirectIncrement => f.write_str("(HL+)"),
+            Operand::IndirectDecrement => f.write_str("(HL-)"),
+            Operand::HighIndirect    => f.write_str("(C)"),
+            Operand::Immediate8(v)   => write!(f, "0x{:0>2.2X}", v),
+            Operand::Immediate16(v)  => write!(f, "0x{:0>4.4X}", v),
+            Operand::Offset(v)       => write!(f, "0x{:0>2.2X}", v as u8),
+            Operand::StackOffset(v)  => write!(f, "SP+0x{:0>2.2X}", v as u8),
+            Operand::Address(v)      => write!(f, "0x{:0>4.4X}", v),
+            Operand::Memory(v)       => write!(f, "(0x{:0>4.4X})", v),
+            Operand::HighMemory(v)   => write!(f, "(0x{:0>2.2X})", v),
+            Operand::Condition(c)    => write!(f, "{}", c),
+            Operand::Bit(b)          => write!(f, "{}", b),
+            Operand::Vector(v)       => write!(f, "{:0>2.2X}H", v),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str(self.mnemonic));
+        for (i, operand) in self.operands.iter().enumerate() {
+            try!(f.write_str(if i == 0 { " " } else { ", " }));
+            try!(write!(f, "{}", operand));
+        }
+        Ok(())
+    }
+}
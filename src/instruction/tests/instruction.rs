@@ -0,0 +1,135 @@
+use super::super::*;
+
+#[test]
+fn decode_empty() {
+    assert_eq!(decode(&[]), None);
+}
+
+#[test]
+fn decode_illegal() {
+    for opcode in ILLEGAL_OPCODES.iter() {
+        assert_eq!(decode(&[*opcode, 0x00, 0x00]), None);
+    }
+}
+
+#[test]
+fn decode_truncated() {
+    // Operands missing
+    assert_eq!(decode(&[0x06]), None);
+    assert_eq!(decode(&[0xC3, 0x34]), None);
+    assert_eq!(decode(&[0xCB]), None);
+    // Trailing bytes are ignored
+    assert_eq!(decode(&[0x00, 0xC3]).unwrap().length, 1);
+}
+
+#[test]
+fn decode_all() {
+    // Every legal opcode decodes to an instruction of consistent length
+    for opcode in 0u32..0x100 {
+        let opcode = opcode as u8;
+        let i = decode(&[opcode, 0x12, 0x34]);
+        if ILLEGAL_OPCODES.contains(&opcode) {
+            assert!(i.is_none());
+            continue;
+        }
+        let i = i.unwrap();
+        if opcode == 0xCB {
+            assert_eq!(i.opcode, 0xCB12);
+            assert_eq!(i.length, 2);
+        } else {
+            assert_eq!(i.opcode, opcode as u16);
+            assert!(i.length >= 1 && i.length <= 3);
+        }
+    }
+    for opcode in 0u32..0x100 {
+        let i = decode(&[0xCB, opcode as u8]).unwrap();
+        assert_eq!(i.opcode, 0xCB00 + opcode as u16);
+        assert_eq!(i.length, 2);
+    }
+}
+
+#[test]
+fn decode_ld_d16() {
+    let i = decode(&[0x21, 0x34, 0x12]).unwrap();
+    assert_eq!(i.opcode, 0x21);
+    assert_eq!(i.mnemonic, "LD");
+    assert_eq!(i.operands, vec![Operand::RegisterPair(RegisterPair::HL),
+                                Operand::Immediate16(0x1234)]);
+    assert_eq!(i.length, 3);
+    assert_eq!(i.cycles, 12);
+    assert_eq!(i.branch_cycles, None);
+    assert_eq!(i.flags, [FlagEffect::Preserved, FlagEffect::Preserved,
+                         FlagEffect::Preserved, FlagEffect::Preserved]);
+}
+
+#[test]
+fn decode_jr_cc() {
+    let i = decode(&[0x20, 0xFE]).unwrap();
+    assert_eq!(i.mnemonic, "JR");
+    assert_eq!(i.operands, vec![Operand::Condition(Condition::NZ),
+                                Operand::Offset(-2)]);
+    assert_eq!(i.length, 2);
+    assert_eq!(i.cycles, 8);
+    assert_eq!(i.branch_cycles, Some(12));
+}
+
+#[test]
+fn decode_alu() {
+    let i = decode(&[0x96]).unwrap();
+    assert_eq!(i.mnemonic, "SUB");
+    assert_eq!(i.operands, vec![Operand::Indirect(RegisterPair::HL)]);
+    assert_eq!(i.cycles, 8);
+    assert_eq!(i.flags, [FlagEffect::Affected, FlagEffect::Set,
+                         FlagEffect::Affected, FlagEffect::Affected]);
+
+    let i = decode(&[0xE6, 0x0F]).unwrap();
+    assert_eq!(i.mnemonic, "AND");
+    assert_eq!(i.operands, vec![Operand::Immediate8(0x0F)]);
+    assert_eq!(i.flags, [FlagEffect::Affected, FlagEffect::Reset,
+                         FlagEffect::Set, FlagEffect::Reset]);
+}
+
+#[test]
+fn decode_cb() {
+    let i = decode(&[0xCB, 0x7E]).unwrap();
+    assert_eq!(i.opcode, 0xCB7E);
+    assert_eq!(i.mnemonic, "BIT");
+    assert_eq!(i.operands, vec![Operand::Bit(7),
+                                Operand::Indirect(RegisterPair::HL)]);
+    assert_eq!(i.length, 2);
+    assert_eq!(i.cycles, 12);
+
+    let i = decode(&[0xCB, 0x37]).unwrap();
+    assert_eq!(i.mnemonic, "SWAP");
+    assert_eq!(i.operands, vec![Operand::Register(Register::A)]);
+    assert_eq!(i.cycles, 8);
+    assert_eq!(i.flags, [FlagEffect::Affected, FlagEffect::Reset,
+                         FlagEffect::Reset, FlagEffect::Reset]);
+}
+
+#[test]
+fn display() {
+    let cases: [(&[u8], &str); 18] = [
+        (&[0x00],             "NOP"),
+        (&[0x01, 0x34, 0x12], "LD BC, 0x1234"),
+        (&[0x08, 0x34, 0x12], "LD (0x1234), SP"),
+        (&[0x10],             "STOP"),
+        (&[0x20, 0xFE],       "JR NZ, 0xFE"),
+        (&[0x22],             "LD (HL+), A"),
+        (&[0x3A],             "LD A, (HL-)"),
+        (&[0x36, 0x12],       "LD (HL), 0x12"),
+        (&[0x80],             "ADD A, B"),
+        (&[0xAF],             "XOR A"),
+        (&[0xC4, 0x34, 0x12], "CALL NZ, 0x1234"),
+        (&[0xE0, 0x44],       "LDH (0x44), A"),
+        (&[0xE2],             "LD (C), A"),
+        (&[0xE8, 0x12],       "ADD SP, 0x12"),
+        (&[0xF8, 0x12],       "LD HL, SP+0x12"),
+        (&[0xFA, 0x34, 0x12], "LD A, (0x1234)"),
+        (&[0xFF],             "RST 38H"),
+        (&[0xCB, 0xC6],       "SET 0, (HL)"),
+    ];
+    for &(bytes, text) in cases.iter() {
+        assert_eq!(format!("{}", decode(bytes).unwrap()), text);
+    }
+}
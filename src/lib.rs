@@ -4,9 +4,10 @@
 //! own emulator.
 #![feature(core)]
 #![feature(io)]
+#![cfg_attr(test, feature(test))]
 
-#[macro_use]
-extern crate lazy_static;
+#[cfg(test)]
+extern crate test;
 
 // ==============================================
 // Inject rgb::*::* names in rgb::*
@@ -14,12 +15,40 @@ extern crate lazy_static;
 // cpu
 pub use self::cpu::Cpu;
 pub use self::cpu::Flag;
+pub use self::cpu::Interrupt;
+pub use self::cpu::CpuError;
+// instruction
+pub use self::instruction::Instruction;
+pub use self::instruction::Operand;
+pub use self::instruction::Register;
+pub use self::instruction::RegisterPair;
+pub use self::instruction::Condition;
+pub use self::instruction::FlagEffect;
+pub use self::instruction::decode;
+// opcodes
+pub use self::opcodes::Opcode;
+// bus
+pub use self::bus::Bus;
+pub use self::bus::IoDevice;
+// registers
+pub use self::registers::Registers;
 // mmu
 pub use self::mmu::Mmu;
+pub use self::mmu::Access;
 // cartridge
 pub use self::cartridge::Cartridge;
+pub use self::cartridge::CartridgeError;
+// mapper
+pub use self::mapper::Mapper;
+pub use self::mapper::RomOnly;
+pub use self::mapper::Mbc1;
+pub use self::mapper::Mbc2;
+// header
+pub use self::header::CartridgeHeader;
+pub use self::header::HeaderWarning;
 // gameboy
 pub use self::gameboy::GameBoy;
+pub use self::gameboy::Model;
 
 // ==============================================
 // Import generated configuration
@@ -33,7 +62,13 @@ include!(concat!(env!("OUT_DIR"), "/config.rs"));
 // to be available to subsequent modules.
 #[macro_use]
 mod util;
+mod bus;
 mod cpu;
+mod instruction;
+pub mod opcodes;
+mod registers;
 mod mmu;
+mod header;
+mod mapper;
 mod cartridge;
 mod gameboy;
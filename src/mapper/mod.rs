@@ -0,0 +1,359 @@
+#![allow(dead_code)]
+#![allow(missing_copy_implementations)]
+
+use std::iter;
+use super::cartridge::ROM_BANK;
+use super::header::NINTENDO_LOGO;
+
+#[cfg(test)]
+mod tests;
+
+/// This trait models the memory bank controller of a cartridge. It owns
+/// ROM and RAM, and handles accesses to 0x0000-0x7FFF and 0xA000-0xBFFF,
+/// writes to ROM usually setting its banking registers. Mappers are
+/// picked by `Cartridge` from the header, or plugged in through
+/// `Cartridge::with_mapper`.
+pub trait Mapper {
+    /// Read a byte of ROM, at 0x0000-0x7FFF
+    fn read_rom(&self, address: u16) -> u8;
+
+    /// Handle a write to 0x0000-0x7FFF
+    fn write_rom(&mut self, address: u16, data: u8);
+
+    /// Patch the byte of ROM mapped at `address`, in 0x0000-0x7FFF
+    fn poke_rom(&mut self, address: u16, data: u8);
+
+    /// Read a byte of RAM, at 0xA000-0xBFFF
+    fn read_ram(&self, address: u16) -> u8;
+
+    /// Write a byte of RAM, at 0xA000-0xBFFF
+    fn write_ram(&mut self, address: u16, data: u8);
+
+    /// Whole ROM image
+    fn rom(&self) -> &[u8];
+
+    /// Whole RAM, as stored in save files
+    fn ram(&self) -> &[u8];
+
+    /// Whole RAM, to restore save files
+    fn ram_mut(&mut self) -> &mut [u8];
+
+    fn load_ram(&mut self, save: &[u8]) {
+        //! Restore RAM from a save file. Extra bytes are ignored,
+        //! missing ones left untouched.
+        for (cell, byte) in self.ram_mut().iter_mut().zip(save.iter()) {
+            *cell = *byte;
+        }
+    }
+}
+
+pub fn supported(cartridge_type: u8) -> bool {
+    //! Whether a cartridge type, as given by the header, has a mapper
+    match cartridge_type {
+        // ROM, ROM+RAM, ROM+RAM+BATTERY
+        0x00 | 0x08 | 0x09 => true,
+        // MBC1, MBC1+RAM, MBC1+RAM+BATTERY
+        0x01...0x03        => true,
+        // MBC2, MBC2+BATTERY
+        0x05 | 0x06        => true,
+        _                  => false,
+    }
+}
+
+pub fn for_type(cartridge_type: u8, rom: Vec<u8>, ram: Vec<u8>) -> Box<Mapper> {
+    //! Create the mapper of a cartridge type, as given by the header,
+    //! owning `rom` and `ram`. Unsupported types get `RomOnly`, and
+    //! MBC2 its own RAM.
+    match cartridge_type {
+        0x01...0x03 => Box::new(Mbc1::new(rom, ram)) as Box<Mapper>,
+        0x05 | 0x06 => Box::new(Mbc2::new(rom)) as Box<Mapper>,
+        _           => Box::new(RomOnly::new(rom, ram)) as Box<Mapper>,
+    }
+}
+
+fn whole_banks(rom: Vec<u8>) -> Vec<u8> {
+    //! Pad `rom` with 0 to whole banks, and at least 2 of them, so that
+    //! banking never maps past its end.
+    let mut rom = rom;
+    let banks: usize = (rom.len() + ROM_BANK - 1) / ROM_BANK;
+    let size: usize = if banks < 2 { 2 * ROM_BANK } else { banks * ROM_BANK };
+    let missing: usize = size - rom.len();
+    rom.extend(iter::repeat(0).take(missing));
+    rom
+}
+
+// ==============================================
+// ROM only
+// ==============================================
+
+/// Cartridge without a mapper: 32K of ROM, and up to 8K of RAM.
+pub struct RomOnly {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+}
+
+impl RomOnly {
+    pub fn new(rom: Vec<u8>, ram: Vec<u8>) -> RomOnly {
+        //! Create a mapper owning `rom`, padded with 0 to whole banks
+        //! and at least 2 of them, and `ram`.
+        RomOnly {
+            rom: whole_banks(rom),
+            ram: ram,
+        }
+    }
+}
+
+impl Mapper for RomOnly {
+    fn read_rom(&self, address: u16) -> u8 {
+        self.rom[address as usize]
+    }
+
+    fn write_rom(&mut self, address: u16, data: u8) {
+        // ROM is read-only, and there is no register
+        let _ = (address, data);
+    }
+
+    fn poke_rom(&mut self, address: u16, data: u8) {
+        self.rom[address as usize] = data;
+    }
+
+    fn read_ram(&self, address: u16) -> u8 {
+        // No RAM reads 0xFF, smaller RAM is mirrored
+        match self.ram.len() {
+            0    => 0xFF,
+            size => self.ram[(address & 0x1FFF) as usize % size],
+        }
+    }
+
+    fn write_ram(&mut self, address: u16, data: u8) {
+        match self.ram.len() {
+            0    => (),
+            size => self.ram[(address & 0x1FFF) as usize % size] = data,
+        }
+    }
+
+    fn rom(&self) -> &[u8] {
+        &self.rom[..]
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram[..]
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram[..]
+    }
+}
+
+// ==============================================
+// MBC1
+// ==============================================
+
+/// MBC1, for up to 2M of ROM and 32K of RAM. Its registers are:
+///
+/// - 0000-1FFF: RAM enable, 0x0A in the low nibble enabling it
+/// - 2000-3FFF: 5 bits ROM bank at 4000-7FFF, 0 selecting 1
+/// - 4000-5FFF: 2 bits upper ROM bank, or RAM bank
+/// - 6000-7FFF: banking mode, 1 applying the upper bits to 0000-3FFF
+///   and selecting the RAM bank
+///
+/// Multicarts (MBC1M) wire the upper bits one bit lower, to switch
+/// between 4 games of 256K, and are told apart by the logo of a second
+/// game at bank 0x10.
+pub struct Mbc1 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    /// Lower ROM bank register, 5 bits
+    bank1: u8,
+    /// Upper ROM or RAM bank register, 2 bits
+    bank2: u8,
+    /// Banking mode register
+    mode:  bool,
+    /// Whether the cartridge is a multicart
+    multicart: bool,
+}
+
+impl Mbc1 {
+    pub fn new(rom: Vec<u8>, ram: Vec<u8>) -> Mbc1 {
+        //! Create a MBC1 owning `rom`, padded with 0 to whole banks and
+        //! at least 2 of them, and `ram`
+        let rom = whole_banks(rom);
+        let logo: usize = 0x10 * ROM_BANK + 0x0104;
+        let multicart: bool = rom.len() == 0x40 * ROM_BANK
+                           && rom[logo..logo + NINTENDO_LOGO.len()] == NINTENDO_LOGO[..];
+        Mbc1 {
+            rom: rom,
+            ram: ram,
+            ram_enabled: false,
+            bank1: 1,
+            bank2: 0,
+            mode:  false,
+            multicart: multicart,
+        }
+    }
+
+    pub fn multicart(&self) -> bool {
+        self.multicart
+    }
+
+    fn rom_index(&self, address: u16) -> usize {
+        //! Index in `rom` of a ROM address, in the mapped bank
+        let (shift, mask) = if self.multicart { (4, 0x0F) } else { (5, 0x1F) };
+        let upper: usize = (self.bank2 as usize) << shift;
+        let bank: usize = match address {
+            0x0000...0x3FFF if self.mode => upper,
+            0x0000...0x3FFF              => 0,
+            _                            => upper | (self.bank1 & mask) as usize,
+        };
+        let banks: usize = self.rom.len() / ROM_BANK;
+        (bank % banks) * ROM_BANK + (address & 0x3FFF) as usize
+    }
+
+    fn ram_index(&self, address: u16) -> usize {
+        //! Index in `ram` of a RAM address, in the mapped bank
+        let bank: usize = if self.mode { self.bank2 as usize } else { 0 };
+        (bank * 0x2000 + (address & 0x1FFF) as usize) % self.ram.len()
+    }
+}
+
+impl Mapper for Mbc1 {
+    fn read_rom(&self, address: u16) -> u8 {
+        self.rom[self.rom_index(address)]
+    }
+
+    fn write_rom(&mut self, address: u16, data: u8) {
+        match address {
+            0x0000...0x1FFF => self.ram_enabled = data & 0x0F == 0x0A,
+            0x2000...0x3FFF => self.bank1 = match data & 0x1F { 0 => 1, bank => bank },
+            0x4000...0x5FFF => self.bank2 = data & 0x03,
+            _               => self.mode = data & 0x01 != 0,
+        }
+    }
+
+    fn poke_rom(&mut self, address: u16, data: u8) {
+        let index: usize = self.rom_index(address);
+        self.rom[index] = data;
+    }
+
+    fn read_ram(&self, address: u16) -> u8 {
+        if !self.ram_enabled || self.ram.is_empty() {
+            return 0xFF;
+        }
+        self.ram[self.ram_index(address)]
+    }
+
+    fn write_ram(&mut self, address: u16, data: u8) {
+        if self.ram_enabled && !self.ram.is_empty() {
+            let index: usize = self.ram_index(address);
+            self.ram[index] = data;
+        }
+    }
+
+    fn rom(&self) -> &[u8] {
+        &self.rom[..]
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram[..]
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram[..]
+    }
+}
+
+// ==============================================
+// MBC2
+// ==============================================
+
+/// MBC2, for up to 256K of ROM, with 512 cells of 4 bits of RAM built
+/// in. Its registers are in 0000-3FFF, told apart by address bit 8:
+///
+/// - Bit 8 clear: RAM enable, 0x0A in the low nibble enabling it
+/// - Bit 8 set: 4 bits ROM bank at 4000-7FFF, 0 selecting 1
+///
+/// RAM is mirrored across A000-BFFF, its upper nibbles reading as 1.
+/// It is stored a cell per byte, upper nibble cleared.
+pub struct Mbc2 {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+    ram_enabled: bool,
+    /// ROM bank register, 4 bits
+    bank: u8,
+}
+
+impl Mbc2 {
+    pub fn new(rom: Vec<u8>) -> Mbc2 {
+        //! Create a MBC2 owning `rom`, padded with 0 to whole banks and
+        //! at least 2 of them
+        Mbc2 {
+            rom: whole_banks(rom),
+            ram: vec![0; 0x0200],
+            ram_enabled: false,
+            bank: 1,
+        }
+    }
+
+    fn rom_index(&self, address: u16) -> usize {
+        //! Index in `rom` of a ROM address, in the mapped bank
+        let bank: usize = match address {
+            0x0000...0x3FFF => 0,
+            _               => self.bank as usize,
+        };
+        let banks: usize = self.rom.len() / ROM_BANK;
+        (bank % banks) * ROM_BANK + (address & 0x3FFF) as usize
+    }
+}
+
+impl Mapper for Mbc2 {
+    fn read_rom(&self, address: u16) -> u8 {
+        self.rom[self.rom_index(address)]
+    }
+
+    fn write_rom(&mut self, address: u16, data: u8) {
+        match address {
+            0x0000...0x3FFF if address & 0x0100 == 0 => {
+                self.ram_enabled = data & 0x0F == 0x0A;
+            },
+            0x0000...0x3FFF => self.bank = match data & 0x0F { 0 => 1, bank => bank },
+            _               => (),
+        }
+    }
+
+    fn poke_rom(&mut self, address: u16, data: u8) {
+        let index: usize = self.rom_index(address);
+        self.rom[index] = data;
+    }
+
+    fn read_ram(&self, address: u16) -> u8 {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+        0xF0 | self.ram[(address & 0x01FF) as usize]
+    }
+
+    fn write_ram(&mut self, address: u16, data: u8) {
+        if self.ram_enabled {
+            self.ram[(address & 0x01FF) as usize] = data & 0x0F;
+        }
+    }
+
+    fn rom(&self) -> &[u8] {
+        &self.rom[..]
+    }
+
+    fn ram(&self) -> &[u8] {
+        &self.ram[..]
+    }
+
+    fn ram_mut(&mut self) -> &mut [u8] {
+        &mut self.ram[..]
+    }
+
+    fn load_ram(&mut self, save: &[u8]) {
+        for (cell, byte) in self.ram.iter_mut().zip(save.iter()) {
+            *cell = *byte & 0x0F;
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::super::*;
+use header::NINTENDO_LOGO;
+
+#[test]
+fn rom_only() {
+    let mut rom = vec![0; 0x8000];
+    rom[0x4000] = 0x11;
+    let mut m = RomOnly::new(rom, vec![0; 0x2000]);
+    assert_eq!(m.read_rom(0x4000), 0x11);
+    m.write_rom(0x2000, 0x02);
+    m.write_rom(0x4000, 0x22);
+    assert_eq!(m.read_rom(0x4000), 0x11);
+    m.poke_rom(0x4000, 0x22);
+    assert_eq!(m.read_rom(0x4000), 0x22);
+    assert_eq!(m.rom()[0x4000], 0x22);
+
+    m.write_ram(0xBFFF, 0x33);
+    assert_eq!(m.read_ram(0xBFFF), 0x33);
+    assert_eq!(m.ram()[0x1FFF], 0x33);
+    m.ram_mut()[0] = 0x44;
+    assert_eq!(m.read_ram(0xA000), 0x44);
+}
+
+#[test]
+fn rom_only_padded() {
+    // Short images are padded to 2 banks
+    let m = RomOnly::new(vec![0x31], Vec::new());
+    assert_eq!(m.rom().len(), 0x8000);
+    assert_eq!(m.read_rom(0x7FFF), 0x00);
+    assert_eq!(m.read_ram(0xA000), 0xFF);
+}
+
+#[test]
+fn types() {
+    assert!(supported(0x00));
+    assert!(supported(0x09));
+    assert!(!supported(0xFC));
+    let m = for_type(0x08, vec![0x31], vec![0; 0x2000]);
+    assert_eq!(m.read_rom(0x0000), 0x31);
+    assert_eq!(m.ram().len(), 0x2000);
+}
+
+/// Image of `banks` banks, each filled with its number.
+fn banked(banks: usize) -> Vec<u8> {
+    (0..banks * 0x4000).map(|i| (i / 0x4000) as u8).collect()
+}
+
+#[test]
+fn mbc1_rom() {
+    let mut m = Mbc1::new(banked(128), Vec::new());
+    assert_eq!(m.read_rom(0x0000), 0);
+    assert_eq!(m.read_rom(0x4000), 1);
+    m.write_rom(0x2000, 0x05);
+    assert_eq!(m.read_rom(0x7FFF), 5);
+
+    // Bank 0 selects bank 1, only 5 bits are used
+    m.write_rom(0x3FFF, 0x00);
+    assert_eq!(m.read_rom(0x4000), 1);
+    m.write_rom(0x2000, 0xE0);
+    assert_eq!(m.read_rom(0x4000), 1);
+    m.write_rom(0x2000, 0x1F);
+    assert_eq!(m.read_rom(0x4000), 0x1F);
+
+    // Upper bits, applied to 0000-3FFF in mode 1
+    m.write_rom(0x4000, 0x03);
+    assert_eq!(m.read_rom(0x4000), 0x7F);
+    assert_eq!(m.read_rom(0x0000), 0x00);
+    m.write_rom(0x6000, 0x01);
+    assert_eq!(m.read_rom(0x0000), 0x60);
+    m.write_rom(0x2000, 0x00);
+    assert_eq!(m.read_rom(0x4000), 0x61);
+
+    // Banks wrap around the ROM size
+    let mut m = Mbc1::new(banked(4), Vec::new());
+    m.write_rom(0x2000, 0x06);
+    assert_eq!(m.read_rom(0x4000), 2);
+    m.poke_rom(0x4000, 0x42);
+    assert_eq!(m.rom()[0x8000], 0x42);
+}
+
+#[test]
+fn mbc1_ram() {
+    let mut m = Mbc1::new(banked(4), vec![0; 0x8000]);
+    m.write_ram(0xA000, 0x11);
+    assert_eq!(m.read_ram(0xA000), 0xFF);
+
+    // Enabled by 0x0A in the low nibble
+    m.write_rom(0x0000, 0x1A);
+    m.write_ram(0xA000, 0x11);
+    assert_eq!(m.read_ram(0xA000), 0x11);
+
+    // RAM banks are switched in mode 1 only
+    m.write_rom(0x4000, 0x02);
+    assert_eq!(m.read_ram(0xA000), 0x11);
+    m.write_rom(0x6000, 0x01);
+    assert_eq!(m.read_ram(0xA000), 0x00);
+    m.write_ram(0xBFFF, 0x22);
+    assert_eq!(m.ram()[0x5FFF], 0x22);
+
+    m.write_rom(0x1FFF, 0x00);
+    assert_eq!(m.read_ram(0xBFFF), 0xFF);
+}
+
+#[test]
+fn mbc1_multicart() {
+    // Second game is found by its logo at bank 0x10
+    let mut rom = banked(64);
+    for (i, byte) in NINTENDO_LOGO.iter().enumerate() {
+        rom[0x40104 + i] = *byte;
+    }
+    assert!(!Mbc1::new(banked(64), Vec::new()).multicart());
+    let mut m = Mbc1::new(rom, Vec::new());
+    assert!(m.multicart());
+
+    // Upper bits select a game of 16 banks
+    m.write_rom(0x4000, 0x01);
+    m.write_rom(0x2000, 0x12);
+    assert_eq!(m.read_rom(0x4000), 0x12);
+    m.write_rom(0x2000, 0x10);
+    assert_eq!(m.read_rom(0x4000), 0x10);
+    m.write_rom(0x6000, 0x01);
+    assert_eq!(m.read_rom(0x0000), 0x10);
+    m.write_rom(0x4000, 0x03);
+    assert_eq!(m.read_rom(0x0000), 0x30);
+}
+
+#[test]
+fn mbc2_rom() {
+    // Registers are told apart by address bit 8
+    let mut m = Mbc2::new(banked(16));
+    assert_eq!(m.read_rom(0x4000), 1);
+    m.write_rom(0x2000, 0x05);
+    assert_eq!(m.read_rom(0x4000), 1);
+    m.write_rom(0x2100, 0x05);
+    assert_eq!(m.read_rom(0x4000), 5);
+    assert_eq!(m.read_rom(0x0000), 0);
+    m.write_rom(0x3FFF, 0xF0);
+    assert_eq!(m.read_rom(0x7FFF), 1);
+    m.write_rom(0x0100, 0x0F);
+    assert_eq!(m.read_rom(0x7FFF), 0x0F);
+    m.write_rom(0x4100, 0x02);
+    assert_eq!(m.read_rom(0x7FFF), 0x0F);
+}
+
+#[test]
+fn mbc_padded() {
+    // Short or partial images are padded to whole banks, at least 2
+    let mut m = Mbc1::new(vec![0x31], Vec::new());
+    assert_eq!(m.rom().len(), 0x8000);
+    m.write_rom(0x2000, 0x03);
+    assert_eq!(m.read_rom(0x4000), 0x00);
+    assert_eq!(m.read_rom(0x0000), 0x31);
+
+    let mut m = Mbc2::new(vec![0x31; 0x8001]);
+    assert_eq!(m.rom().len(), 0xC000);
+    m.write_rom(0x2100, 0x02);
+    assert_eq!(m.read_rom(0x4000), 0x31);
+    assert_eq!(m.read_rom(0x4001), 0x00);
+    m.write_rom(0x2100, 0x03);
+    assert_eq!(m.read_rom(0x4000), 0x31);
+}
+
+#[test]
+fn mbc2_ram() {
+    let mut m = Mbc2::new(banked(2));
+    assert_eq!(m.ram().len(), 0x0200);
+    m.write_ram(0xA000, 0x12);
+    assert_eq!(m.read_ram(0xA000), 0xFF);
+
+    // 4 bits cells, mirrored every 512 bytes
+    m.write_rom(0x0000, 0x0A);
+    m.write_rom(0x2100, 0x0A);
+    m.write_ram(0xA000, 0x12);
+    assert_eq!(m.read_ram(0xA000), 0xF2);
+    assert_eq!(m.read_ram(0xBE00), 0xF2);
+    m.write_ram(0xBFFF, 0x34);
+    assert_eq!(m.read_ram(0xA1FF), 0xF4);
+    assert_eq!(m.ram()[0x01FF], 0x04);
+
+    m.write_rom(0x0000, 0x00);
+    assert_eq!(m.read_ram(0xA000), 0xFF);
+
+    // Save files only restore the low nibbles
+    m.load_ram(&[0xAB, 0xCD]);
+    assert_eq!(m.ram()[0x0000], 0x0B);
+    assert_eq!(m.ram()[0x0001], 0x0D);
+    assert_eq!(m.ram()[0x0002], 0x00);
+}
@@ -3,18 +3,164 @@
 #![allow(missing_copy_implementations)]
 
 use std::fmt;
+use super::bus::{Bus, IoDevice};
+use super::cartridge::Cartridge;
 
 #[cfg(test)]
 mod tests;
 
+/// Address of the interrupt flag register (IF)
+pub const IF: u16 = 0xFF0F;
+/// Address of the interrupt enable register (IE)
+pub const IE: u16 = 0xFFFF;
+/// Address of the boot ROM disable register. Writing a non-zero value
+/// unmaps the boot ROM.
+pub const BOOT: u16 = 0xFF50;
+/// Address of the LCD control register (LCDC)
+pub const LCDC: u16 = 0xFF40;
+/// Address of the LCD status register (STAT). Its bits 0-1 hold the
+/// PPU mode, and bits 0-2 are read-only.
+pub const STAT: u16 = 0xFF41;
+/// Address of the OAM DMA register. Writing XX starts copying
+/// XX00-XX9F to OAM.
+pub const DMA: u16 = 0xFF46;
+/// Address of the CGB video RAM bank register (VBK)
+pub const VBK: u16 = 0xFF4F;
+/// Address of the CGB work RAM bank register (SVBK)
+pub const SVBK: u16 = 0xFF70;
+/// Addresses of the CGB VRAM DMA source (HDMA1, HDMA2) and destination
+/// (HDMA3, HDMA4) registers
+pub const HDMA1: u16 = 0xFF51;
+pub const HDMA2: u16 = 0xFF52;
+pub const HDMA3: u16 = 0xFF53;
+pub const HDMA4: u16 = 0xFF54;
+/// Address of the CGB VRAM DMA length, mode and start register (HDMA5)
+pub const HDMA5: u16 = 0xFF55;
+
+/// Bits of IO registers that are not wired, or write-only, and read
+/// as 1. Registers that do not exist read as 0xFF.
+const IO_UNUSED: [u8; 0x80] = [
+    // P1    SB    SC          DIV   TIMA  TMA   TAC
+    0xC0, 0x00, 0x7E, 0xFF, 0x00, 0x00, 0x00, 0xF8,
+    //                                           IF
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE0,
+    // NR10  NR11  NR12  NR13  NR14        NR21  NR22
+    0x80, 0x3F, 0x00, 0xFF, 0xBF, 0xFF, 0x3F, 0x00,
+    // NR23  NR24  NR30  NR31  NR32  NR33  NR34
+    0xFF, 0xBF, 0x7F, 0xFF, 0x9F, 0xFF, 0xBF, 0xFF,
+    // NR41  NR42  NR43  NR44  NR50  NR51  NR52
+    0xFF, 0x00, 0x00, 0xBF, 0x00, 0x00, 0x70, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    // Wave pattern RAM
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // LCDC  STAT  SCY   SCX   LY    LYC   DMA   BGP
+    0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // OBP0  OBP1  WY    WX
+    0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+    // BOOT
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+];
+
+/// Owner of IO registers no device is attached to
+const NO_DEVICE: u8 = 0xFF;
+
+/// Kind of memory access a hook is run on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Access {
+    Read,
+    Write,
+    /// Opcode fetch
+    Execute,
+}
+
+/// Callback run on memory accesses, given the address, the value read
+/// or written, and the address of the instruction performing the
+/// access.
+pub type HookFn = Box<Fn(u16, u8, u16)>;
+
+struct Hook {
+    access:   Access,
+    start:    u16,
+    end:      u16,
+    callback: HookFn,
+}
+
 /// This struct models a memory management unit. A small piece
 /// of hardware that acts as proxy between the processor and the
 /// actual memory. This allows easy address space translation (for
 /// memory banking, etc.).
+///
+/// The DMG memory map is decoded as follows:
+///
+/// - 0000-7FFF: cartridge ROM
+/// - 8000-9FFF: video RAM
+/// - A000-BFFF: cartridge RAM
+/// - C000-DFFF: work RAM
+/// - E000-FDFF: echo of work RAM
+/// - FE00-FE9F: object attribute memory (OAM)
+/// - FEA0-FEFF: unusable, reads 0x00 and ignores writes
+/// - FF00-FF7F: IO registers, or the `IoDevice` attached to them
+/// - FF80-FFFE: high RAM
+/// - FFFF:      interrupt enable register
+///
+/// While the LCD is on, the PPU holds video RAM in mode 3, and OAM in
+/// modes 2 and 3: processor reads return 0xFF, and writes are dropped.
+/// The mode is taken from STAT, usually owned by the PPU device. This
+/// can be turned off for debugging with `set_ppu_blocking`.
+///
+/// In CGB mode, video RAM has 2 banks selected by VBK, and D000-DFFF
+/// maps one of 7 work RAM banks selected by SVBK. HDMA1-HDMA5 copy
+/// blocks of 16 bytes to video RAM, either all at once or one block
+/// per HBlank.
+///
+/// During an OAM DMA transfer, the processor can only use IO registers
+/// and high RAM. OAM reads 0xFF, and accesses to the bus the DMA is
+/// reading from (video RAM, or anything else outside of OAM) see the
+/// byte being transferred, writes being dropped.
 pub struct Mmu {
-    /// For now, the `Mmu` just uses a single memory array
-    /// of 2^16 bytes.
-    pub memory: [u8; 0x10000],
+    /// Cartridge, owning ROM and external RAM
+    cartridge: Cartridge,
+    /// Video RAM, 2 banks of 8K
+    vram: [u8; 0x4000],
+    /// Work RAM, 8 banks of 4K
+    wram: [u8; 0x8000],
+    oam:  [u8; 0xA0],
+    io:   [u8; 0x80],
+    hram: [u8; 0x7F],
+    ie:   u8,
+    /// Devices attached to IO registers
+    devices:   Vec<Box<IoDevice>>,
+    /// Index in `devices` of the owner of each IO register
+    io_owner:  [u8; 0x80],
+    /// Bits of each IO register that read as 1
+    io_unused: [u8; 0x80],
+    /// Boot ROM mapped over the start of memory, until a write to
+    /// `BOOT` unmaps it.
+    boot: Option<Vec<u8>>,
+    /// Hooks, by id. Removed hooks are left as `None`.
+    hooks:  Vec<Option<Hook>>,
+    /// Whether any hook is installed
+    hooked: bool,
+    /// Address of the last fetched instruction
+    pc:     u16,
+    /// Source address of the next byte of the running OAM DMA transfer
+    dma:    Option<u16>,
+    /// Whether CGB registers and banks are enabled
+    cgb:       bool,
+    /// Selected video RAM bank
+    vram_bank: usize,
+    /// Work RAM bank mapped at D000-DFFF
+    wram_bank: usize,
+    /// Whether a VRAM DMA transfer waits for HBlanks
+    hdma:      bool,
+    /// Whether the PPU mode blocks video RAM and OAM accesses
+    ppu_blocking: bool,
 }
 
 // ==============================================
@@ -23,31 +169,420 @@ pub struct Mmu {
 
 impl Mmu {
     pub fn new() -> Mmu {
-        //! Create a new `Mmu`. All of its memory is zero initialized.
+        //! Create a new `Mmu`, with a blank cartridge inserted. All of
+        //! its memory is zero initialized.
         Mmu {
-            memory: [0; 0x10000],
+            cartridge: Cartridge::new(),
+            vram: [0; 0x4000],
+            wram: [0; 0x8000],
+            oam:  [0; 0xA0],
+            io:   [0; 0x80],
+            hram: [0; 0x7F],
+            ie:   0,
+            devices:   Vec::new(),
+            io_owner:  [NO_DEVICE; 0x80],
+            io_unused: IO_UNUSED,
+            boot: None,
+            hooks:  Vec::new(),
+            hooked: false,
+            pc:     0,
+            dma:    None,
+            cgb:       false,
+            vram_bank: 0,
+            wram_bank: 1,
+            hdma:      false,
+            ppu_blocking: true,
         }
     }
 
+    pub fn insert(&mut self, cartridge: Cartridge) {
+        //! Insert `cartridge`, replacing the current one
+        self.cartridge = cartridge;
+    }
+
+    pub fn cartridge(&self) -> &Cartridge {
+        &self.cartridge
+    }
+
+    pub fn attach(&mut self, device: Box<IoDevice>, registers: &[(u16, u8)]) {
+        //! Attach `device` to IO `registers`, given as pairs of an
+        //! address in 0xFF00-0xFF7F and the mask of its bits that read
+        //! as 1. The device then handles every access to these
+        //! registers, in place of any device attached before.
+        //!
+        //! Registers the `Mmu` implements itself cannot be attached:
+        //! IF (0xFF0F), which gathers the interrupts devices request on
+        //! `tick`, BOOT (0xFF50), DMA (0xFF46), and the CGB VBK, SVBK
+        //! and HDMA5.
+        assert!(self.devices.len() < NO_DEVICE as usize, "too many IO devices");
+        let index = self.devices.len() as u8;
+        for &(address, unused) in registers.iter() {
+            assert!(address >= 0xFF00 && address <= 0xFF7F,
+                    "0x{:04X} is not an IO register", address);
+            assert!(match address { IF | BOOT | DMA | VBK | SVBK | HDMA5 => false, _ => true },
+                    "0x{:04X} is handled by the Mmu", address);
+            self.io_owner[(address - 0xFF00) as usize]  = index;
+            self.io_unused[(address - 0xFF00) as usize] = unused;
+        }
+        self.devices.push(device);
+    }
+
+    pub fn add_hook(&mut self, access: Access, start: u16, end: u16,
+                    callback: HookFn) -> usize {
+        //! Run `callback` on every `access` to `start`-`end`, bounds
+        //! included, by the program. Writes dropped while OAM DMA or the
+        //! PPU hold the bus are not reported. Return an id for
+        //! `remove_hook`.
+        self.hooks.push(Some(Hook {
+            access:   access,
+            start:    start,
+            end:      end,
+            callback: callback,
+        }));
+        self.hooked = true;
+        self.hooks.len() - 1
+    }
+
+    pub fn remove_hook(&mut self, id: usize) {
+        //! Remove the hook `id` returned by `add_hook`
+        if id < self.hooks.len() {
+            self.hooks[id] = None;
+        }
+        self.hooked = self.hooks.iter().any(|hook| hook.is_some());
+    }
+
+    pub fn map_boot_rom(&mut self, rom: &[u8]) {
+        //! Map `rom` over 0x0000-0x00FF, hiding whatever is below
+        //! until the program writes to `BOOT`. A CGB boot ROM is larger
+        //! and also covers 0x0200-0x08FF, leaving the cartridge header
+        //! visible.
+        self.boot = Some(rom.to_vec());
+    }
+
+    pub fn boot_rom_mapped(&self) -> bool {
+        self.boot.is_some()
+    }
+
+    pub fn set_cgb(&mut self, cgb: bool) {
+        //! Enable or disable CGB mode: VRAM and WRAM banks, and VRAM
+        //! DMA. Disabling it selects the first banks again.
+        self.cgb = cgb;
+        self.vram_bank = 0;
+        self.wram_bank = 1;
+        self.hdma = false;
+        let (vbk, svbk, hdma5) = if cgb { (0xFE, 0xF8, 0x00) } else { (0xFF, 0xFF, 0xFF) };
+        self.io[(VBK - 0xFF00) as usize] = 0;
+        self.io[(SVBK - 0xFF00) as usize] = 0;
+        self.io[(HDMA5 - 0xFF00) as usize] = 0xFF;
+        self.io_unused[(VBK - 0xFF00) as usize] = vbk;
+        self.io_unused[(SVBK - 0xFF00) as usize] = svbk;
+        self.io_unused[(HDMA5 - 0xFF00) as usize] = hdma5;
+    }
+
+    pub fn cgb(&self) -> bool {
+        self.cgb
+    }
+
+    pub fn set_ppu_blocking(&mut self, enabled: bool) {
+        //! Enable or disable blocking of video RAM and OAM accesses
+        //! depending on the PPU mode. Enabled by default, like real
+        //! hardware.
+        self.ppu_blocking = enabled;
+    }
+
     pub fn write8(&mut self, address:u16, data: u8) {
         //! Write a single byte to memory
-        self.memory[address as usize] = data;
+        // Dropped while OAM DMA or the PPU hold the bus, unseen by hooks
+        if self.dma_conflict(address).is_some() || self.ppu_blocked(address) {
+            return;
+        }
+        if self.hooked {
+            self.hook(Access::Write, address, data);
+        }
+        match address {
+            0x0000...0x7FFF => self.cartridge.write_rom(address, data),
+            0xA000...0xBFFF => self.cartridge.write_ram(address, data),
+            BOOT => {
+                if data != 0 {
+                    self.boot = None;
+                }
+                self.io[(address - 0xFF00) as usize] = data;
+            },
+            DMA => {
+                self.dma = Some((data as u16) << 8);
+                self.io[(address - 0xFF00) as usize] = data;
+            },
+            STAT if self.io_owner[(address - 0xFF00) as usize] == NO_DEVICE => {
+                let i = (address - 0xFF00) as usize;
+                self.io[i] = (data & 0xF8) | (self.io[i] & 0x07);
+            },
+            VBK if self.cgb => {
+                self.vram_bank = (data & 0x01) as usize;
+                self.io[(address - 0xFF00) as usize] = data & 0x01;
+            },
+            SVBK if self.cgb => {
+                self.wram_bank = match data & 0x07 { 0 => 1, bank => bank as usize };
+                self.io[(address - 0xFF00) as usize] = data & 0x07;
+            },
+            HDMA5 if self.cgb => self.start_hdma(data),
+            _ => self.poke(address, data),
+        }
+    }
+
+    pub fn poke(&mut self, address: u16, data: u8) {
+        //! Write a single byte to memory, bypassing hardware behavior.
+        //! Unlike `write8`, this patches cartridge ROM. IO registers
+        //! owned by a device are still written through it. Meant to
+        //! load programs and for debuggers.
+        match address {
+            0x0000...0x7FFF => self.cartridge.poke_rom(address, data),
+            0x8000...0x9FFF => self.vram[self.vram_index(address)] = data,
+            0xA000...0xBFFF => self.cartridge.write_ram(address, data),
+            0xC000...0xFDFF => self.wram[self.wram_index(address)] = data,
+            0xFE00...0xFE9F => self.oam[(address - 0xFE00) as usize]  = data,
+            0xFEA0...0xFEFF => (),
+            0xFF00...0xFF7F => self.write_io(address, data),
+            0xFF80...0xFFFE => self.hram[(address - 0xFF80) as usize] = data,
+            _               => self.ie = data,
+        }
     }
 
     pub fn write16(&mut self, address: u16, data: u16) {
-        //! Write a single word to memory
-        self.memory[address as usize+1] = (data >> 8) as u8;
-        self.memory[address as usize]   = data as u8;
+        //! Write a single word to memory. The high byte wraps around
+        //! to 0x0000 when writing at 0xFFFF.
+        self.write8(address.wrapping_add(1), (data >> 8) as u8);
+        self.write8(address, data as u8);
     }
 
     pub fn read8(&self, address: u16) -> u8 {
         //! Read a single byte from memory
-        self.memory[address as usize]
+        let data: u8 = self.cpu_read(address);
+        if self.hooked {
+            self.hook(Access::Read, address, data);
+        }
+        data
+    }
+
+    pub fn fetch8(&mut self, address: u16) -> u8 {
+        //! Read the opcode of the instruction starting at `address`.
+        //! Runs execute hooks, not read ones.
+        self.pc = address;
+        let data: u8 = self.cpu_read(address);
+        if self.hooked {
+            self.hook(Access::Execute, address, data);
+        }
+        data
+    }
+
+    pub fn peek8(&self, address: u16) -> u8 {
+        //! Read a single byte from memory, without running hooks nor
+        //! seeing OAM DMA bus conflicts and PPU blocking
+        match address {
+            0x0000...0x7FFF => match self.boot {
+                Some(ref boot) if (address as usize) < boot.len()
+                               && (address < 0x0100 || address >= 0x0200) => {
+                    boot[address as usize]
+                },
+                _ => self.cartridge.read_rom(address),
+            },
+            0x8000...0x9FFF => self.vram[self.vram_index(address)],
+            0xA000...0xBFFF => self.cartridge.read_ram(address),
+            0xC000...0xFDFF => self.wram[self.wram_index(address)],
+            0xFE00...0xFE9F => self.oam[(address - 0xFE00) as usize],
+            0xFEA0...0xFEFF => 0x00,
+            0xFF00...0xFF7F => self.read_io(address),
+            0xFF80...0xFFFE => self.hram[(address - 0xFF80) as usize],
+            _               => self.ie,
+        }
     }
 
     pub fn read16(&self, address: u16) -> u16 {
-        //! Read a single word from memory
-        ((self.memory[address as usize+1] as u16) << 8) + self.memory[address as usize] as u16
+        //! Read a single word from memory. The high byte wraps around
+        //! to 0x0000 when reading at 0xFFFF.
+        ((self.read8(address.wrapping_add(1)) as u16) << 8) + self.read8(address) as u16
+    }
+
+    pub fn dma_active(&self) -> bool {
+        self.dma.is_some()
+    }
+
+    pub fn hdma_active(&self) -> bool {
+        self.hdma
+    }
+
+    pub fn hblank(&mut self) {
+        //! Signal the start of an HBlank period, copying a block of a
+        //! running HBlank VRAM DMA transfer.
+        if self.hdma {
+            self.hdma_block();
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u8) {
+        //! Advance attached devices by `cycles` clock cycles, and
+        //! request the interrupts they raise in IF. A running OAM DMA
+        //! transfer copies a byte every 4 cycles.
+        for _ in 0..cycles / 4 {
+            match self.dma {
+                Some(source) => self.dma_step(source),
+                None         => break,
+            }
+        }
+        for device in self.devices.iter_mut() {
+            let interrupts: u8 = device.tick(cycles);
+            self.io[(IF - 0xFF00) as usize] |= interrupts;
+        }
+    }
+
+    fn hook(&self, access: Access, address: u16, data: u8) {
+        //! Run hooks matching an access
+        for hook in self.hooks.iter() {
+            if let Some(ref hook) = *hook {
+                if hook.access == access && address >= hook.start && address <= hook.end {
+                    (hook.callback)(address, data, self.pc);
+                }
+            }
+        }
+    }
+
+    fn cpu_read(&self, address: u16) -> u8 {
+        //! Read a byte as the processor sees it, with OAM DMA bus
+        //! conflicts and PPU blocking
+        match self.dma_conflict(address) {
+            Some(data)                          => data,
+            None if self.ppu_blocked(address)   => 0xFF,
+            None                                => self.peek8(address),
+        }
+    }
+
+    fn ppu_blocked(&self, address: u16) -> bool {
+        //! Whether the PPU holds `address`, in video RAM or OAM
+        if !self.ppu_blocking || address < 0x8000 || (address >= 0xA000 && address < 0xFE00)
+                              || address >= 0xFF00 {
+            return false;
+        }
+        if self.read_io(LCDC) & 0x80 == 0 {
+            return false;
+        }
+        match (address, self.read_io(STAT) & 0x03) {
+            (0x8000...0x9FFF, 3)      => true,
+            (0xFE00...0xFEFF, 2...3)  => true,
+            _                         => false,
+        }
+    }
+
+    fn vram_index(&self, address: u16) -> usize {
+        //! Index in `vram` of a video RAM address, in the selected bank
+        self.vram_bank * 0x2000 + (address & 0x1FFF) as usize
+    }
+
+    fn wram_index(&self, address: u16) -> usize {
+        //! Index in `wram` of a work RAM address, or its echo
+        match address & 0x1FFF {
+            offset @ 0x0000...0x0FFF => offset as usize,
+            offset                   => self.wram_bank * 0x1000 + (offset - 0x1000) as usize,
+        }
+    }
+
+    fn start_hdma(&mut self, data: u8) {
+        //! Handle a write to HDMA5. Bit 7 selects an HBlank transfer,
+        //! or a general purpose one copying everything at once. Writing
+        //! it cleared during an HBlank transfer cancels it.
+        let i = (HDMA5 - 0xFF00) as usize;
+        if self.hdma && data & 0x80 == 0 {
+            self.hdma = false;
+            self.io[i] |= 0x80;
+            return;
+        }
+        self.io[i] = data & 0x7F;
+        if data & 0x80 != 0 {
+            self.hdma = true;
+        } else {
+            while self.io[i] != 0xFF {
+                self.hdma_block();
+            }
+        }
+    }
+
+    fn hdma_block(&mut self) {
+        //! Copy a block of 16 bytes of a VRAM DMA transfer, and advance
+        //! the source, destination and remaining length registers.
+        let source: u16 = ((self.io[(HDMA1 - 0xFF00) as usize] as u16) << 8)
+                        | (self.io[(HDMA2 - 0xFF00) as usize] & 0xF0) as u16;
+        let dest: u16 = ((self.io[(HDMA3 - 0xFF00) as usize] as u16 & 0x1F) << 8)
+                      | (self.io[(HDMA4 - 0xFF00) as usize] & 0xF0) as u16;
+        for i in 0..0x10 {
+            let data: u8 = self.peek8(source.wrapping_add(i));
+            let index: usize = self.vram_index(0x8000 | ((dest + i) & 0x1FFF));
+            self.vram[index] = data;
+        }
+
+        let (source, dest) = (source.wrapping_add(0x10), (dest + 0x10) & 0x1FFF);
+        self.io[(HDMA1 - 0xFF00) as usize] = (source >> 8) as u8;
+        self.io[(HDMA2 - 0xFF00) as usize] = source as u8;
+        self.io[(HDMA3 - 0xFF00) as usize] = (dest >> 8) as u8;
+        self.io[(HDMA4 - 0xFF00) as usize] = dest as u8;
+
+        // Remaining length wraps to 0xFF once done
+        let i = (HDMA5 - 0xFF00) as usize;
+        self.io[i] = self.io[i].wrapping_sub(1);
+        if self.io[i] == 0xFF {
+            self.hdma = false;
+        }
+    }
+
+    fn dma_step(&mut self, source: u16) {
+        //! Copy a byte of the running OAM DMA transfer
+        let data: u8 = self.dma_read(source);
+        self.oam[(source & 0xFF) as usize] = data;
+        self.dma = match source & 0xFF {
+            0x9F => None,
+            _    => Some(source + 1),
+        };
+    }
+
+    fn dma_conflict(&self, address: u16) -> Option<u8> {
+        //! Byte seen by the processor accessing `address` while an OAM
+        //! DMA transfer holds its bus, or `None` if it is free.
+        let source = match self.dma {
+            Some(source) => source,
+            None         => return None,
+        };
+        let video = |address: u16| address >= 0x8000 && address <= 0x9FFF;
+        match address {
+            0xFE00...0xFEFF => Some(0xFF),
+            0xFF00...0xFFFF => None,
+            _ if video(address) == video(source) => Some(self.dma_read(source)),
+            _ => None,
+        }
+    }
+
+    fn dma_read(&self, source: u16) -> u8 {
+        //! Read a byte for OAM DMA. Sources past DFFF read the echo of
+        //! work RAM.
+        match source {
+            0xE000...0xFFFF => self.peek8(source - 0x2000),
+            _               => self.peek8(source),
+        }
+    }
+
+    fn read_io(&self, address: u16) -> u8 {
+        //! Read an IO register, from its device if any
+        let i = (address - 0xFF00) as usize;
+        let data: u8 = match self.io_owner[i] {
+            NO_DEVICE => self.io[i],
+            device    => self.devices[device as usize].read(address),
+        };
+        data | self.io_unused[i]
+    }
+
+    fn write_io(&mut self, address: u16, data: u8) {
+        //! Write an IO register, to its device if any
+        let i = (address - 0xFF00) as usize;
+        match self.io_owner[i] {
+            NO_DEVICE => self.io[i] = data,
+            device    => self.devices[device as usize].write(address, data),
+        }
     }
 }
 
@@ -55,9 +590,40 @@ impl Mmu {
 // Traits
 // ==============================================
 
+// Default bus of `Cpu`
+impl Bus for Mmu {
+    fn read8(&self, address: u16) -> u8 {
+        Mmu::read8(self, address)
+    }
+
+    fn write8(&mut self, address: u16, data: u8) {
+        Mmu::write8(self, address, data)
+    }
+
+    fn peek8(&self, address: u16) -> u8 {
+        Mmu::peek8(self, address)
+    }
+
+    fn fetch8(&mut self, address: u16) -> u8 {
+        Mmu::fetch8(self, address)
+    }
+
+    fn read16(&self, address: u16) -> u16 {
+        Mmu::read16(self, address)
+    }
+
+    fn write16(&mut self, address: u16, data: u16) {
+        Mmu::write16(self, address, data)
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        Mmu::tick(self, cycles)
+    }
+}
+
 // Type is formattable to string
 impl fmt::Debug for Mmu {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str("Mmu { memory: [...] }")
+        f.write_str("Mmu { .. }")
     }
 }
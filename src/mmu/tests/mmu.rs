@@ -1,42 +1,50 @@
 use super::super::*;
+use bus::IoDevice;
+use cartridge::Cartridge;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 #[test]
 fn new() {
     let m = Mmu::new();
-    // Ensure memory is zero initialized
+    // Ensure memory is zero initialized. IO registers are checked
+    // by `io_unused`.
     for i in 0us..0x10000 {
-        assert_eq!(m.memory[i], 0);
+        match i {
+            0xFF00...0xFF7F => (),
+            _               => assert_eq!(m.read8(i as u16), 0),
+        }
     }
 }
 
 #[test]
 fn write8() {
     let mut m = Mmu::new();
-    m.write8(10, 0x42);
+    m.write8(0xC00A, 0x42);
     // Ensure there is no overflow on adjacent cells
-    assert_eq!(m.memory[ 9], 0x00);
-    assert_eq!(m.memory[10], 0x42);
-    assert_eq!(m.memory[11], 0x00);
+    assert_eq!(m.read8(0xC009), 0x00);
+    assert_eq!(m.read8(0xC00A), 0x42);
+    assert_eq!(m.read8(0xC00B), 0x00);
 }
 
 #[test]
 fn write16() {
     // GameBoy is little-endian
     let mut m = Mmu::new();
-    m.write16(10, 0x1337);
+    m.write16(0xC00A, 0x1337);
     // Ensure there is no overflow on adjacent cells
-    assert_eq!(m.memory[ 8], 0x00);
-    assert_eq!(m.memory[ 9], 0x00);
-    assert_eq!(m.memory[10], 0x37);
-    assert_eq!(m.memory[11], 0x13);
-    assert_eq!(m.memory[12], 0x00);
-    assert_eq!(m.memory[13], 0x00);
+    assert_eq!(m.read8(0xC008), 0x00);
+    assert_eq!(m.read8(0xC009), 0x00);
+    assert_eq!(m.read8(0xC00A), 0x37);
+    assert_eq!(m.read8(0xC00B), 0x13);
+    assert_eq!(m.read8(0xC00C), 0x00);
+    assert_eq!(m.read8(0xC00D), 0x00);
 }
 
 #[test]
 fn read8() {
     let mut m = Mmu::new();
-    m.memory[10] = 0x42;
+    m.poke(10, 0x42);
     // Ensure value is there
     assert_eq!(m.read8( 9), 0x00);
     assert_eq!(m.read8(10), 0x42);
@@ -47,8 +55,8 @@ fn read8() {
 fn read16() {
     // GameBoy is little-endian
     let mut m = Mmu::new();
-    m.memory[10] = 0x37;
-    m.memory[11] = 0x13;
+    m.poke(10, 0x37);
+    m.poke(11, 0x13);
     // Ensure value is there
     assert_eq!(m.read8( 9), 0x00);
     assert_eq!(m.read8(10), 0x37);
@@ -61,3 +69,514 @@ fn read16() {
     assert_eq!(m.read16(11), 0x0013);
     assert_eq!(m.read16(12), 0x0000);
 }
+
+#[test]
+fn read8_if() {
+    // Upper bits of IF always read as 1
+    let mut m = Mmu::new();
+    assert_eq!(m.read8(0xFF0F), 0xE0);
+    m.write8(0xFF0F, 0x05);
+    assert_eq!(m.read8(0xFF0F), 0xE5);
+}
+
+#[test]
+fn read16_wrap() {
+    // Words at 0xFFFF have their high byte at 0x0000
+    let mut m = Mmu::new();
+    m.poke(0xFFFF, 0x37);
+    m.poke(0x0000, 0x13);
+    assert_eq!(m.read16(0xFFFF), 0x1337);
+}
+
+#[test]
+fn write16_wrap() {
+    // High byte lands in ROM, and is dropped
+    let mut m = Mmu::new();
+    m.write16(0xFFFF, 0x1337);
+    assert_eq!(m.read8(0xFFFF), 0x37);
+    assert_eq!(m.read8(0x0000), 0x00);
+    assert_eq!(m.read8(0x0001), 0x00);
+}
+
+#[test]
+fn rom() {
+    // ROM comes from the cartridge, and is read-only
+    let mut m = Mmu::new();
+    let mut rom = vec![0; 0x8000];
+    rom[0x0000] = 0x11;
+    rom[0x7FFF] = 0x22;
+    m.insert(Cartridge::from_bytes(&rom));
+    assert_eq!(m.read8(0x0000), 0x11);
+    assert_eq!(m.read8(0x7FFF), 0x22);
+    m.write8(0x0000, 0x33);
+    assert_eq!(m.read8(0x0000), 0x11);
+
+    // Unless patched
+    m.poke(0x0000, 0x33);
+    assert_eq!(m.read8(0x0000), 0x33);
+    assert_eq!(m.cartridge().read_rom(0x0000), 0x33);
+}
+
+#[test]
+fn cartridge_ram() {
+    let mut m = Mmu::new();
+    m.write8(0xA000, 0x12);
+    m.write8(0xBFFF, 0x34);
+    assert_eq!(m.read8(0xA000), 0x12);
+    assert_eq!(m.read8(0xBFFF), 0x34);
+    assert_eq!(m.cartridge().read_ram(0xA000), 0x12);
+}
+
+#[test]
+fn regions() {
+    // Each region is backed by its own memory
+    let mut m = Mmu::new();
+    let cells = [0x8000, 0x9FFF, 0xC000, 0xDFFF, 0xFE00, 0xFE9F,
+                 0xFF01, 0xFF3F, 0xFF80, 0xFFFE, 0xFFFF];
+    for (i, &address) in cells.iter().enumerate() {
+        m.write8(address, i as u8 + 1);
+    }
+    for (i, &address) in cells.iter().enumerate() {
+        assert_eq!(m.read8(address), i as u8 + 1);
+    }
+}
+
+#[test]
+fn echo() {
+    // E000-FDFF mirrors C000-DDFF
+    let mut m = Mmu::new();
+    m.write8(0xC000, 0x12);
+    m.write8(0xFDFF, 0x34);
+    assert_eq!(m.read8(0xE000), 0x12);
+    assert_eq!(m.read8(0xDDFF), 0x34);
+    // Work RAM past 0xDDFF has no echo
+    m.write8(0xDFFF, 0x56);
+    assert_eq!(m.read8(0xFDFF), 0x34);
+}
+
+#[test]
+fn unusable() {
+    // FEA0-FEFF reads 0x00 and ignores writes
+    let mut m = Mmu::new();
+    for address in 0xFEA0..0xFF00u16 {
+        m.write8(address, 0xFF);
+        assert_eq!(m.read8(address), 0x00);
+    }
+    assert_eq!(m.read8(0xFE9F), 0x00);
+    assert_eq!(m.read8(0xFF01), 0x00);
+}
+
+#[test]
+fn io_unused() {
+    // Unused bits of IO registers read as 1
+    let mut m = Mmu::new();
+    assert_eq!(m.read8(0xFF00), 0xC0);          // P1
+    assert_eq!(m.read8(0xFF07), 0xF8);          // TAC
+    assert_eq!(m.read8(0xFF41), 0x80);          // STAT
+    assert_eq!(m.read8(0xFF03), 0xFF);          // Nothing
+    assert_eq!(m.read8(0xFF7F), 0xFF);
+    m.write8(0xFF07, 0x05);
+    assert_eq!(m.read8(0xFF07), 0xFD);
+    m.write8(0xFF47, 0xE4);
+    assert_eq!(m.read8(0xFF47), 0xE4);          // BGP
+}
+
+/// Divider register, counting at 16384Hz and reset by any write.
+/// Requests a timer interrupt every time it overflows.
+struct Divider {
+    counter: Rc<Cell<u16>>,
+}
+
+impl IoDevice for Divider {
+    fn read(&self, address: u16) -> u8 {
+        assert_eq!(address, 0xFF04);
+        (self.counter.get() >> 8) as u8
+    }
+
+    fn write(&mut self, address: u16, _: u8) {
+        assert_eq!(address, 0xFF04);
+        self.counter.set(0);
+    }
+
+    fn tick(&mut self, cycles: u8) -> u8 {
+        let before: u16 = self.counter.get();
+        self.counter.set(before.wrapping_add(cycles as u16));
+        if self.counter.get() < before { 0x04 } else { 0x00 }
+    }
+}
+
+#[test]
+fn attach() {
+    let mut m = Mmu::new();
+    let counter = Rc::new(Cell::new(0x1234));
+    m.attach(Box::new(Divider { counter: counter.clone() }), &[(0xFF04, 0x00)]);
+    assert_eq!(m.read8(0xFF04), 0x12);
+
+    // Writes have side effects, and never land in memory
+    m.write8(0xFF04, 0x42);
+    assert_eq!(counter.get(), 0);
+    assert_eq!(m.read8(0xFF04), 0x00);
+    m.poke(0xFF04, 0x42);
+    assert_eq!(m.read8(0xFF04), 0x00);
+
+    // Other registers are left alone
+    m.write8(0xFF05, 0x42);
+    assert_eq!(m.read8(0xFF05), 0x42);
+    assert_eq!(counter.get(), 0);
+}
+
+#[test]
+fn attach_unused() {
+    // Registers take the read mask given on attach
+    let mut m = Mmu::new();
+    let counter = Rc::new(Cell::new(0x0000));
+    m.attach(Box::new(Divider { counter: counter.clone() }), &[(0xFF04, 0xF0)]);
+    assert_eq!(m.read8(0xFF04), 0xF0);
+}
+
+#[test]
+fn attach_tick() {
+    // Devices are ticked, and their interrupts requested in IF
+    let mut m = Mmu::new();
+    let counter = Rc::new(Cell::new(0xFFF8));
+    m.attach(Box::new(Divider { counter: counter.clone() }), &[(0xFF04, 0x00)]);
+    m.tick(4);
+    assert_eq!(counter.get(), 0xFFFC);
+    assert_eq!(m.read8(IF), 0xE0);
+    m.tick(4);
+    assert_eq!(counter.get(), 0x0000);
+    assert_eq!(m.read8(IF), 0xE4);
+}
+
+#[test]
+fn hooks() {
+    // Hooks see accesses in their range only
+    let mut m = Mmu::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let l = log.clone();
+    m.add_hook(Access::Write, 0xC000, 0xC0FF, Box::new(move |address, data, _| {
+        l.borrow_mut().push((address, data));
+    }));
+    m.write8(0xC000, 0x12);
+    m.write8(0xC0FF, 0x34);
+    m.write8(0xC100, 0x56);
+    m.write16(0xBFFF, 0x789A);
+    assert_eq!(m.read8(0xC000), 0x78);
+    assert_eq!(*log.borrow(), vec![(0xC000, 0x12), (0xC0FF, 0x34), (0xC000, 0x78)]);
+}
+
+#[test]
+fn hooks_dma() {
+    // Writes dropped during OAM DMA never reach write hooks
+    let mut m = Mmu::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let l = log.clone();
+    m.add_hook(Access::Write, 0x0000, 0xFFFF, Box::new(move |address, data, _| {
+        l.borrow_mut().push((address, data));
+    }));
+    m.write8(0xFF46, 0xC0);
+    m.write8(0xC000, 0x12);
+    m.write8(0xFF80, 0x34);
+    assert_eq!(m.peek8(0xC000), 0x00);
+    assert_eq!(*log.borrow(), vec![(0xFF46, 0xC0), (0xFF80, 0x34)]);
+}
+
+#[test]
+fn hooks_access() {
+    // Reads, writes and fetches run their own hooks
+    let mut m = Mmu::new();
+    let reads = Rc::new(Cell::new(0));
+    let fetches = Rc::new(Cell::new(0));
+    let (r, f) = (reads.clone(), fetches.clone());
+    m.add_hook(Access::Read, 0x0000, 0xFFFF, Box::new(move |_, _, _| r.set(r.get() + 1)));
+    m.add_hook(Access::Execute, 0x0000, 0xFFFF, Box::new(move |_, _, _| f.set(f.get() + 1)));
+    m.read8(0x0100);
+    m.read16(0x0100);
+    m.fetch8(0x0100);
+    m.write8(0x0100, 0x00);
+    m.peek8(0x0100);
+    assert_eq!(reads.get(), 3);
+    assert_eq!(fetches.get(), 1);
+}
+
+#[test]
+fn hooks_pc() {
+    // Hooks are given the address of the last fetched instruction
+    let mut m = Mmu::new();
+    let pc = Rc::new(Cell::new(0));
+    let p = pc.clone();
+    m.add_hook(Access::Read, 0xC000, 0xC000, Box::new(move |_, _, pc| p.set(pc)));
+    m.fetch8(0x0150);
+    m.read8(0xC000);
+    assert_eq!(pc.get(), 0x0150);
+}
+
+#[test]
+fn remove_hook() {
+    let mut m = Mmu::new();
+    let count = Rc::new(Cell::new(0));
+    let (c0, c1) = (count.clone(), count.clone());
+    let h0 = m.add_hook(Access::Write, 0xC000, 0xC000, Box::new(move |_, _, _| c0.set(c0.get() + 1)));
+    let h1 = m.add_hook(Access::Write, 0xC000, 0xC000, Box::new(move |_, _, _| c1.set(c1.get() + 10)));
+    m.write8(0xC000, 0);
+    assert_eq!(count.get(), 11);
+    m.remove_hook(h0);
+    m.write8(0xC000, 0);
+    assert_eq!(count.get(), 21);
+    m.remove_hook(h1);
+    m.write8(0xC000, 0);
+    assert_eq!(count.get(), 21);
+}
+
+#[test]
+fn boot_rom() {
+    // The boot ROM hides the cartridge until BOOT is written
+    let mut m = Mmu::new();
+    m.poke(0x0000, 0x11);
+    m.poke(0x0100, 0x22);
+    m.map_boot_rom(&[0x31, 0xFE, 0xFF]);
+    assert!(m.boot_rom_mapped());
+    assert_eq!(m.read16(0x0001), 0xFFFE);
+    assert_eq!(m.read8(0x0000), 0x31);
+    assert_eq!(m.read8(0x0003), 0x00);
+    assert_eq!(m.read8(0x0100), 0x22);
+
+    // Writing 0 has no effect
+    m.write8(0xFF50, 0x00);
+    assert!(m.boot_rom_mapped());
+    m.write8(0xFF50, 0x01);
+    assert!(!m.boot_rom_mapped());
+    assert_eq!(m.read8(0x0000), 0x11);
+}
+
+#[test]
+fn boot_rom_cgb() {
+    // A CGB boot ROM leaves the cartridge header visible
+    let mut m = Mmu::new();
+    let mut rom = vec![0xAA; 0x900];
+    rom[0x0200] = 0xBB;
+    m.poke(0x0104, 0xCE);
+    m.map_boot_rom(&rom);
+    assert_eq!(m.read8(0x00FF), 0xAA);
+    assert_eq!(m.read8(0x0104), 0xCE);
+    assert_eq!(m.read8(0x0200), 0xBB);
+    assert_eq!(m.read8(0x08FF), 0xAA);
+    assert_eq!(m.read8(0x0900), 0x00);
+}
+
+#[test]
+fn dma() {
+    // A write to DMA copies 160 bytes to OAM, one per M-cycle
+    let mut m = Mmu::new();
+    for i in 0..0xA0 {
+        m.poke(0xC000 + i, i as u8 ^ 0x55);
+    }
+    m.write8(0xFF46, 0xC0);
+    assert!(m.dma_active());
+    assert_eq!(m.read8(0xFF46), 0xC0);
+
+    m.tick(4);
+    assert_eq!(m.peek8(0xFE00), 0x55);
+    assert_eq!(m.peek8(0xFE01), 0x00);
+    for _ in 1..0x9F {
+        m.tick(4);
+    }
+    assert!(m.dma_active());
+    assert_eq!(m.peek8(0xFE9F), 0x00);
+    m.tick(4);
+    assert!(!m.dma_active());
+    assert_eq!(m.read8(0xFE9F), 0x9F ^ 0x55);
+
+    // Sources past DFFF read the echo of work RAM
+    m.poke(0xDE00, 0x12);
+    m.write8(0xFF46, 0xFE);
+    m.tick(4);
+    assert_eq!(m.peek8(0xFE00), 0x12);
+}
+
+#[test]
+fn dma_conflicts() {
+    // Only IO and HRAM are reachable while DMA reads work RAM
+    let mut m = Mmu::new();
+    let mut rom = vec![0; 0x10000];
+    rom[0x0147] = 0x03;
+    rom[0x0148] = 0x01;
+    rom[0x0149] = 0x02;
+    rom[0x4000] = 0x01;
+    rom[0xC000] = 0x03;
+    m.insert(Cartridge::load(&rom).unwrap());
+    m.write8(0x0000, 0x0A);
+    m.poke(0x0000, 0x11);
+    m.poke(0x8000, 0x22);
+    m.poke(0xC000, 0x33);
+    m.poke(0xC001, 0x44);
+    m.poke(0xFE00, 0x55);
+    m.write8(0xFF46, 0xC0);
+    assert_eq!(m.read8(0xFE00), 0xFF);
+    assert_eq!(m.read8(0x0000), 0x33);
+    assert_eq!(m.read8(0xD000), 0x33);
+    assert_eq!(m.read8(0x8000), 0x22);
+    m.tick(4);
+    assert_eq!(m.read8(0x0000), 0x44);
+    assert_eq!(m.fetch8(0x0000), 0x44);
+    assert_eq!(m.peek8(0x0000), 0x11);
+
+    m.write8(0xC000, 0x66);
+    m.write8(0xFE00, 0x66);
+    m.write8(0xFF80, 0x66);
+    m.write8(0x8000, 0x66);
+    assert_eq!(m.peek8(0xC000), 0x33);
+    assert_eq!(m.peek8(0xFE00), 0x33);
+    assert_eq!(m.read8(0xFF80), 0x66);
+    assert_eq!(m.read8(0x8000), 0x66);
+
+    // So are cartridge registers and RAM
+    m.write8(0x2000, 0x03);
+    m.write8(0xA000, 0x77);
+    assert_eq!(m.peek8(0x4000), 0x01);
+    assert_eq!(m.peek8(0xA000), 0x00);
+
+    // From video RAM, the other way around
+    m.write8(0xFF46, 0x80);
+    assert_eq!(m.read8(0x8001), 0x66);
+    assert_eq!(m.read8(0xC001), 0x44);
+}
+
+#[test]
+fn cgb_wram() {
+    // SVBK selects the bank at D000, 0 selecting bank 1
+    let mut m = Mmu::new();
+    m.write8(0xFF70, 0x02);
+    m.write8(0xD000, 0x11);
+    assert_eq!(m.read8(0xFF70), 0xFF);
+    assert_eq!(m.read8(0xD000), 0x11);
+
+    m.set_cgb(true);
+    assert_eq!(m.read8(0xFF70), 0xF8);
+    m.write8(0xC000, 0x22);
+    m.write8(0xFF70, 0x02);
+    assert_eq!(m.read8(0xFF70), 0xFA);
+    assert_eq!(m.read8(0xC000), 0x22);
+    assert_eq!(m.read8(0xD000), 0x00);
+    m.write8(0xD000, 0x33);
+    assert_eq!(m.read8(0xF000), 0x33);
+    m.write8(0xFF70, 0x00);
+    assert_eq!(m.read8(0xFF70), 0xF8);
+    assert_eq!(m.read8(0xD000), 0x11);
+    m.write8(0xFF70, 0x0A);
+    assert_eq!(m.read8(0xD000), 0x33);
+}
+
+#[test]
+fn cgb_vram() {
+    // VBK selects one of 2 video RAM banks
+    let mut m = Mmu::new();
+    m.set_cgb(true);
+    m.write8(0x8000, 0x11);
+    assert_eq!(m.read8(0xFF4F), 0xFE);
+    m.write8(0xFF4F, 0x03);
+    assert_eq!(m.read8(0xFF4F), 0xFF);
+    assert_eq!(m.read8(0x8000), 0x00);
+    m.write8(0x9FFF, 0x22);
+    m.write8(0xFF4F, 0x00);
+    assert_eq!(m.read8(0x8000), 0x11);
+    assert_eq!(m.read8(0x9FFF), 0x00);
+}
+
+#[test]
+fn gdma() {
+    // A general purpose transfer copies everything at once
+    let mut m = Mmu::new();
+    m.set_cgb(true);
+    for i in 0..0x20 {
+        m.write8(0xC100 + i, i as u8 + 1);
+    }
+    m.write8(0xFF51, 0xC1);
+    m.write8(0xFF52, 0x0F);
+    m.write8(0xFF53, 0xE8);
+    m.write8(0xFF54, 0x00);
+    m.write8(0xFF55, 0x01);
+    assert!(!m.hdma_active());
+    assert_eq!(m.read8(0xFF55), 0xFF);
+    assert_eq!(m.read8(0xFF51), 0xFF);
+    assert_eq!(m.read8(0x87FF), 0x00);
+    assert_eq!(m.read8(0x8800), 0x01);
+    assert_eq!(m.read8(0x881F), 0x20);
+    assert_eq!(m.read8(0x8820), 0x00);
+}
+
+#[test]
+fn hdma() {
+    // An HBlank transfer copies a block per HBlank, and can be stopped
+    let mut m = Mmu::new();
+    m.set_cgb(true);
+    for i in 0..0x40 {
+        m.write8(0xC000 + i, 0xAA);
+    }
+    m.write8(0xFF4F, 0x01);
+    m.write8(0xFF51, 0xC0);
+    m.write8(0xFF52, 0x00);
+    m.write8(0xFF53, 0x10);
+    m.write8(0xFF54, 0x00);
+    m.write8(0xFF55, 0x83);
+    assert!(m.hdma_active());
+    assert_eq!(m.read8(0xFF55), 0x03);
+    assert_eq!(m.read8(0x9000), 0x00);
+
+    m.hblank();
+    assert_eq!(m.read8(0xFF55), 0x02);
+    assert_eq!(m.read8(0x900F), 0xAA);
+    assert_eq!(m.read8(0x9010), 0x00);
+    m.hblank();
+    assert_eq!(m.read8(0xFF55), 0x01);
+
+    // Cancelled, bit 7 set with the remaining length
+    m.write8(0xFF55, 0x00);
+    assert!(!m.hdma_active());
+    assert_eq!(m.read8(0xFF55), 0x81);
+    m.hblank();
+    assert_eq!(m.read8(0x9020), 0x00);
+    m.write8(0xFF4F, 0x00);
+    assert_eq!(m.read8(0x900F), 0x00);
+}
+
+#[test]
+fn ppu_blocking() {
+    // Video RAM is blocked in mode 3, OAM in modes 2 and 3
+    let mut m = Mmu::new();
+    m.poke(0x8000, 0x11);
+    m.poke(0xFE00, 0x22);
+    m.poke(0xFF40, 0x80);
+    m.poke(0xFF41, 0x03);
+    assert_eq!(m.read8(0x8000), 0xFF);
+    assert_eq!(m.fetch8(0xFE00), 0xFF);
+    assert_eq!(m.peek8(0x8000), 0x11);
+    m.write8(0x8000, 0x33);
+    m.write8(0xFE00, 0x33);
+    assert_eq!(m.peek8(0x8000), 0x11);
+    assert_eq!(m.peek8(0xFE00), 0x22);
+
+    m.poke(0xFF41, 0x02);
+    assert_eq!(m.read8(0x8000), 0x11);
+    assert_eq!(m.read8(0xFE00), 0xFF);
+    m.poke(0xFF41, 0x00);
+    assert_eq!(m.read8(0xFE00), 0x22);
+
+    // Free while the LCD is off, or when turned off
+    m.poke(0xFF41, 0x03);
+    m.poke(0xFF40, 0x00);
+    assert_eq!(m.read8(0x8000), 0x11);
+    m.poke(0xFF40, 0x80);
+    m.set_ppu_blocking(false);
+    m.write8(0xFE00, 0x33);
+    assert_eq!(m.read8(0xFE00), 0x33);
+}
+
+#[test]
+fn stat_mode() {
+    // The PPU mode can't be written by the processor
+    let mut m = Mmu::new();
+    m.poke(0xFF41, 0x03);
+    m.write8(0xFF41, 0x44);
+    assert_eq!(m.read8(0xFF41), 0xC3);
+}
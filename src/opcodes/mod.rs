@@ -0,0 +1,57 @@
+//! Opcode metadata for the whole instruction set, generated at build
+//! time by `build.rs` from `scripts/opcodes.json`, `scripts/opcodes_cb.json`
+//! and their `.doc.txt` descriptions.
+#![allow(dead_code)]
+#![allow(missing_copy_implementations)]
+
+use super::instruction::FlagEffect;
+use super::instruction::FlagEffect::{Preserved, Reset, Set, Affected};
+
+#[cfg(test)]
+mod tests;
+
+/// This struct holds the metadata of a single opcode.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Opcode {
+    /// Opcode, prefix included for `0xCBxx` opcodes
+    pub opcode: u16,
+    /// Mnemonic, such as `LD` or `JR`
+    pub mnemonic: &'static str,
+    /// Operands as written in the opcode table, destination first,
+    /// such as `"A"`, `"(HL+)"` or `"d8"`
+    pub operands: &'static [&'static str],
+    /// Size in bytes, opcode included
+    pub length: u8,
+    /// Clock cycles, when the branch is not taken for conditional
+    /// instructions
+    pub cycles: u8,
+    /// Clock cycles when the branch is taken, for conditional
+    /// instructions only
+    pub branch_cycles: Option<u8>,
+    /// Effects on flags `Z`, `N`, `H` and `C`, in that order
+    pub flags: [FlagEffect; 4],
+    /// What the instruction does, in plain words
+    pub description: &'static str,
+}
+
+// ==============================================
+// Generated tables
+// ==============================================
+
+// Defines `OPCODES`, indexed by opcode and `None` for illegal opcodes,
+// and `OPCODES_CB`, indexed by the byte following the 0xCB prefix.
+include!(concat!(env!("OUT_DIR"), "/opcodes.rs"));
+
+// ==============================================
+// Implementation
+// ==============================================
+
+pub fn lookup(opcode: u16) -> Option<&'static Opcode> {
+    //! Return the metadata of `opcode`, prefix included for `0xCBxx`
+    //! opcodes, or `None` for illegal opcodes.
+    match opcode {
+        0x0000...0x00FF => OPCODES[opcode as usize].as_ref(),
+        0xCB00...0xCBFF => Some(&OPCODES_CB[(opcode & 0xFF) as usize]),
+        _               => None,
+    }
+}
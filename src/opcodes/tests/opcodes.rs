@@ -0,0 +1,67 @@
+use super::super::*;
+use instruction::{decode, ILLEGAL_OPCODES};
+
+#[test]
+fn lookup_base() {
+    let o = lookup(0x3E).unwrap();
+    assert_eq!(o.opcode, 0x3E);
+    assert_eq!(o.mnemonic, "LD");
+    assert_eq!(o.operands, ["A", "d8"]);
+    assert_eq!(o.length, 2);
+    assert_eq!(o.cycles, 8);
+    assert_eq!(o.branch_cycles, None);
+    assert_eq!(o.flags, [Preserved, Preserved, Preserved, Preserved]);
+    assert_eq!(o.description, "Loads * into a.");
+}
+
+#[test]
+fn lookup_branch() {
+    // Conditional calls take longer when taken
+    let o = lookup(0xC4).unwrap();
+    assert_eq!(o.length, 3);
+    assert_eq!(o.cycles, 12);
+    assert_eq!(o.branch_cycles, Some(24));
+}
+
+#[test]
+fn lookup_cb() {
+    let o = lookup(0xCB7C).unwrap();
+    assert_eq!(o.opcode, 0xCB7C);
+    assert_eq!(o.mnemonic, "BIT");
+    assert_eq!(o.operands, ["7", "H"]);
+    assert_eq!(o.length, 2);
+    assert_eq!(o.flags, [Affected, Reset, Set, Preserved]);
+}
+
+#[test]
+fn lookup_illegal() {
+    for &opcode in ILLEGAL_OPCODES.iter() {
+        assert!(lookup(opcode as u16).is_none());
+    }
+    assert!(lookup(0x0100).is_none());
+    assert!(lookup(0xCC00).is_none());
+}
+
+#[test]
+fn lookup_matches_decode() {
+    // The generated table and the decoder agree on every opcode
+    for opcode in (0..0x100u16).chain(0xCB00..0xCC00u16) {
+        if opcode == 0xCB {
+            continue;
+        }
+        let bytes = [(opcode >> 8) as u8, opcode as u8, 0, 0];
+        let bytes = if opcode > 0xFF { &bytes[..] } else { &bytes[1..] };
+        let o = match (lookup(opcode), decode(bytes)) {
+            (Some(o), Some(i)) => {
+                assert_eq!((opcode, o.length), (opcode, i.length));
+                assert_eq!((opcode, o.cycles), (opcode, i.cycles));
+                assert_eq!((opcode, o.branch_cycles), (opcode, i.branch_cycles));
+                assert_eq!((opcode, o.flags), (opcode, i.flags));
+                o
+            },
+            (None, None) => continue,
+            _ => panic!("0x{:X} is only illegal in one of lookup and decode", opcode),
+        };
+        assert_eq!(o.opcode, opcode);
+    }
+}
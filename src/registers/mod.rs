@@ -0,0 +1,137 @@
+#![allow(dead_code)]
+#![allow(missing_copy_implementations)]
+
+use std::fmt;
+use super::cpu::Flag;
+
+#[cfg(test)]
+mod tests;
+
+/// This struct is a snapshot of the `Cpu` registers, as returned
+/// by `Cpu::registers` and applied by `Cpu::set_registers`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    a:  u8,
+    b:  u8,
+    c:  u8,
+    d:  u8,
+    e:  u8,
+    h:  u8,
+    l:  u8,
+    f:  u8,
+    sp: u16,
+    pc: u16,
+}
+
+// ==============================================
+// Implementation
+// ==============================================
+
+/// Bits of register `f` that actually exist. Lower bits always read 0.
+const F_MASK: u8 = 0xF0;
+
+impl Registers {
+    pub fn new() -> Registers {
+        //! Create a new `Registers`, with every register set to 0.
+        Registers {
+            a:  0,
+            b:  0,
+            c:  0,
+            d:  0,
+            e:  0,
+            h:  0,
+            l:  0,
+            f:  Flag::None as u8,
+            sp: 0,
+            pc: 0,
+        }
+    }
+
+    pub fn a(&self) -> u8 { self.a }
+    pub fn b(&self) -> u8 { self.b }
+    pub fn c(&self) -> u8 { self.c }
+    pub fn d(&self) -> u8 { self.d }
+    pub fn e(&self) -> u8 { self.e }
+    pub fn h(&self) -> u8 { self.h }
+    pub fn l(&self) -> u8 { self.l }
+    pub fn f(&self) -> u8 { self.f }
+
+    pub fn set_a(&mut self, value: u8) { self.a = value; }
+    pub fn set_b(&mut self, value: u8) { self.b = value; }
+    pub fn set_c(&mut self, value: u8) { self.c = value; }
+    pub fn set_d(&mut self, value: u8) { self.d = value; }
+    pub fn set_e(&mut self, value: u8) { self.e = value; }
+    pub fn set_h(&mut self, value: u8) { self.h = value; }
+    pub fn set_l(&mut self, value: u8) { self.l = value; }
+
+    pub fn set_f(&mut self, value: u8) {
+        //! Set the flag register. As on hardware, the lower 4 bits
+        //! are discarded.
+        self.f = value & F_MASK;
+    }
+
+    pub fn af(&self) -> u16 { ((self.a as u16) << 8) + self.f as u16 }
+    pub fn bc(&self) -> u16 { ((self.b as u16) << 8) + self.c as u16 }
+    pub fn de(&self) -> u16 { ((self.d as u16) << 8) + self.e as u16 }
+    pub fn hl(&self) -> u16 { ((self.h as u16) << 8) + self.l as u16 }
+    pub fn sp(&self) -> u16 { self.sp }
+    pub fn pc(&self) -> u16 { self.pc }
+
+    pub fn set_af(&mut self, value: u16) {
+        //! Set the accumulator and flag registers. As on hardware,
+        //! the lower 4 bits of the flag register are discarded.
+        self.a = (value >> 8) as u8;
+        self.set_f(value as u8);
+    }
+
+    pub fn set_bc(&mut self, value: u16) {
+        self.b = (value >> 8) as u8;
+        self.c = value as u8;
+    }
+
+    pub fn set_de(&mut self, value: u16) {
+        self.d = (value >> 8) as u8;
+        self.e = value as u8;
+    }
+
+    pub fn set_hl(&mut self, value: u16) {
+        self.h = (value >> 8) as u8;
+        self.l = value as u8;
+    }
+
+    pub fn set_sp(&mut self, value: u16) { self.sp = value; }
+    pub fn set_pc(&mut self, value: u16) { self.pc = value; }
+
+    pub fn flag(&self, flag: Flag) -> bool {
+        //! Return whether `flag` is set. `Flag::None` is set when
+        //! no other flag is.
+        match flag {
+            Flag::None => self.f == Flag::None as u8,
+            _          => self.f & flag as u8 != 0,
+        }
+    }
+
+    pub fn set_flag(&mut self, flag: Flag, value: bool) {
+        //! Set or unset `flag`. Setting `Flag::None` clears all flags,
+        //! unsetting it does nothing.
+        match flag {
+            Flag::None => if value { self.f = Flag::None as u8; },
+            _          => if value {
+                self.f |= flag as u8;
+            } else {
+                self.f &= !(flag as u8);
+            },
+        }
+    }
+}
+
+// ==============================================
+// Traits
+// ==============================================
+impl fmt::Debug for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AF={:0>4.4X} BC={:0>4.4X} DE={:0>4.4X} HL={:0>4.4X} \
+                   SP={:0>4.4X} PC={:0>4.4X}",
+               self.af(), self.bc(), self.de(), self.hl(), self.sp, self.pc)
+    }
+}
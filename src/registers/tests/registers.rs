@@ -0,0 +1,98 @@
+use super::super::*;
+use cpu::{Cpu, Flag};
+
+#[test]
+fn new() {
+    // Check that new registers are zero initialized
+    let r = Registers::new();
+
+    assert_eq!(r.af(), 0);
+    assert_eq!(r.bc(), 0);
+    assert_eq!(r.de(), 0);
+    assert_eq!(r.hl(), 0);
+    assert_eq!(r.sp(), 0);
+    assert_eq!(r.pc(), 0);
+    assert!(r.flag(Flag::None));
+}
+
+#[test]
+fn pairs() {
+    let mut r = Registers::new();
+
+    r.set_bc(0x0102);
+    r.set_de(0x0304);
+    r.set_hl(0x0506);
+    r.set_sp(0x0708);
+    r.set_pc(0x090A);
+    assert_eq!(r.b(), 0x01);
+    assert_eq!(r.c(), 0x02);
+    assert_eq!(r.d(), 0x03);
+    assert_eq!(r.e(), 0x04);
+    assert_eq!(r.h(), 0x05);
+    assert_eq!(r.l(), 0x06);
+    assert_eq!(r.sp(), 0x0708);
+    assert_eq!(r.pc(), 0x090A);
+
+    r.set_h(0xAB);
+    r.set_l(0xCD);
+    assert_eq!(r.hl(), 0xABCD);
+}
+
+#[test]
+fn f_masked() {
+    // Lower 4 bits of f always read 0
+    let mut r = Registers::new();
+
+    r.set_f(0xFF);
+    assert_eq!(r.f(), 0xF0);
+    r.set_af(0x12FF);
+    assert_eq!(r.a(), 0x12);
+    assert_eq!(r.f(), 0xF0);
+    assert_eq!(r.af(), 0x12F0);
+}
+
+#[test]
+fn flags() {
+    let mut r = Registers::new();
+
+    r.set_flag(Flag::Zero, true);
+    r.set_flag(Flag::Carry, true);
+    assert_eq!(r.f(), 0x90);
+    assert!(r.flag(Flag::Zero));
+    assert!(!r.flag(Flag::Operation));
+    assert!(!r.flag(Flag::HalfCarry));
+    assert!(r.flag(Flag::Carry));
+    assert!(!r.flag(Flag::None));
+
+    r.set_flag(Flag::Zero, false);
+    assert_eq!(r.f(), 0x10);
+    r.set_flag(Flag::None, true);
+    assert_eq!(r.f(), 0x00);
+    assert!(r.flag(Flag::None));
+}
+
+#[test]
+fn cpu_roundtrip() {
+    let mut c = Cpu::new();
+    c.reset();
+
+    let mut r = c.registers();
+    r.set_af(0x01B0);
+    r.set_bc(0x0013);
+    r.set_de(0x00D8);
+    r.set_hl(0x014D);
+    r.set_sp(0xFFFE);
+    r.set_pc(0x0100);
+    c.set_registers(&r);
+    assert_eq!(c.pc, 0x0100);
+    assert_eq!(c.registers(), r);
+
+    // Registers reflect executed instructions
+    c.mmu.poke(0x0100, 0x04);     // 0100 INC B
+    c.step().unwrap();
+    let r = c.registers();
+    assert_eq!(r.b(), 0x01);
+    assert_eq!(r.pc(), 0x0101);
+    assert!(!r.flag(Flag::Zero));
+    assert!(r.flag(Flag::Carry));
+}